@@ -1,20 +1,99 @@
 use std::{
+    collections::{HashSet, VecDeque},
     env, fs,
     path::{Component, Path, PathBuf},
     process::{Command, Stdio},
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+use tauri::{Emitter, Manager};
+
 use crate::error::AppError;
 use crate::helpers::{
-    ensure_dir, is_path_under_skills_root, now_iso, slugify, unique_dir, unique_dir_with_timestamp_on_conflict,
+    candidate_home_dirs, check_skill_md_size, check_write_size, csv_field, dir_is_writable,
+    ensure_dir, expand_home, find_executable, github_https_url, is_path_under_skills_root,
+    iso_timestamp, normalize_registry_url, now_iso, parse_git_version, parse_install_link,
+    parse_oauth_scopes, registry_search_url, scrub_secret, skill_folder_name, slugify,
+    stable_path_key, unique_dir, unique_dir_with_timestamp_on_conflict, utf8_chunk_len,
+    validate_git_ref, validate_registry_request, validate_repo_url,
 };
 
+/// Upper bound for scanning a single tool's skills root while building the
+/// dashboard. Keeps an unreachable network mount from stalling every tool.
+const TOOL_SCAN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Default timeout for `git clone` operations. A hostile or oversized
 /// repository shouldn't be able to keep a command worker alive indefinitely.
 const GIT_CLONE_TIMEOUT: Duration = Duration::from_secs(120);
 
+/// How deep `preview_github_install` and `install_all_skills_from_github`
+/// look for skills in a cloned repository, so both see the same skills.
+const REPO_SKILL_SCAN_DEPTH: usize = 6;
+
+/// Timeout for a whole HTTP request made through `http_client`.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long connecting, or waiting on any single read, may take before a
+/// request is abandoned.
+const HTTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+/// Pause before the one retry `send_with_retry` makes.
+const HTTP_RETRY_BACKOFF: Duration = Duration::from_millis(750);
+
+/// HTTP client shared by every network command, so connections are pooled
+/// and all requests carry the same user agent and timeouts.
+fn http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .user_agent("skillsyoga")
+            .connect_timeout(HTTP_CONNECT_TIMEOUT)
+            .read_timeout(HTTP_CONNECT_TIMEOUT)
+            .timeout(HTTP_TIMEOUT)
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+/// `AppError::Network` for a failed request, prefixed with `action`.
+/// Timeouts always say "timed out" so the frontend can tell them apart
+/// from error responses.
+fn network_error(action: &str, err: reqwest::Error) -> AppError {
+    if err.is_timeout() {
+        AppError::Network(format!("{action}: timed out"))
+    } else {
+        AppError::Network(format!("{action}: {err}"))
+    }
+}
+
+/// Send `request` through `http_client`, retrying once after a short pause
+/// when the connection fails. A timeout is only retried for GET and HEAD,
+/// since another request type may already have taken effect.
+async fn send_with_retry(
+    request: reqwest::RequestBuilder,
+    action: &str,
+) -> Result<reqwest::Response, AppError> {
+    let request = request.build().map_err(|e| network_error(action, e))?;
+    let idempotent = matches!(*request.method(), reqwest::Method::GET | reqwest::Method::HEAD);
+    let retry = request.try_clone();
+    match http_client().execute(request).await {
+        Ok(response) => Ok(response),
+        Err(err) => {
+            let transient = err.is_connect() || (idempotent && err.is_timeout());
+            let Some(retry) = retry.filter(|_| transient) else {
+                return Err(network_error(action, err));
+            };
+            tokio::time::sleep(HTTP_RETRY_BACKOFF).await;
+            http_client()
+                .execute(retry)
+                .await
+                .map_err(|e| network_error(action, e))
+        }
+    }
+}
+
 /// RAII guard that removes a temp directory on drop, regardless of which
 /// path an enclosing function takes (success, early return, `?`, panic).
 struct TempDir {
@@ -55,22 +134,141 @@ impl Drop for TempDir {
 
 /// Shallow `git clone` with a hard timeout. Kills the child process if it
 /// exceeds the limit to avoid hanging on hostile or oversized repos.
-fn git_clone_shallow(repo_url: &str, dest: &Path, timeout: Duration) -> Result<(), AppError> {
-    let mut child = Command::new("git")
-        .arg("clone")
-        .arg("--depth")
-        .arg("1")
-        .arg("--single-branch")
-        .arg("--no-tags")
-        .arg("--config")
-        .arg("submodule.recurse=false")
-        .arg(repo_url)
+///
+/// `git_ref` picks a branch or tag via `--branch`. git can't clone a commit
+/// directly, so when that fails for a ref that looks like a SHA the commit
+/// is fetched into a fresh repository and checked out instead. Refs must
+/// pass `validate_git_ref`.
+///
+/// `token`, when given, is sent as an HTTP auth header for GitHub URLs so
+/// private repos can be cloned. It is passed with `-c` rather than in the
+/// URL, so it isn't written to the clone's config, and it is scrubbed from
+/// any error output.
+fn git_clone_shallow(
+    repo_url: &str,
+    dest: &Path,
+    timeout: Duration,
+    token: Option<&str>,
+    git_ref: Option<&str>,
+) -> Result<(), AppError> {
+    use base64::Engine;
+
+    validate_repo_url(repo_url)?;
+    if let Some(git_ref) = git_ref {
+        validate_git_ref(git_ref)?;
+    }
+    ensure_git_available()?;
+    let auth_header = token
+        .filter(|_| repo_url.starts_with("https://github.com/"))
+        .map(|token| {
+            let basic = base64::engine::general_purpose::STANDARD
+                .encode(format!("x-access-token:{token}"));
+            format!("http.https://github.com/.extraHeader=Authorization: Basic {basic}")
+        });
+    let git = || {
+        let mut command = Command::new("git");
+        if let Some(header) = &auth_header {
+            command.arg("-c").arg(header);
+        }
+        // Fail instead of waiting on a credential prompt nobody can answer.
+        command.env("GIT_TERMINAL_PROMPT", "0");
+        command
+    };
+
+    let mut clone = git();
+    clone.args([
+        "clone",
+        "--depth",
+        "1",
+        "--single-branch",
+        "--no-tags",
+        "--config",
+        "submodule.recurse=false",
+    ]);
+    if let Some(git_ref) = git_ref {
+        clone.arg("--branch").arg(git_ref);
+    }
+    clone.arg("--").arg(repo_url).arg(dest);
+    let cloned = run_git(clone, "clone", token, timeout);
+
+    let Some(commit) = git_ref.filter(|r| cloned.is_err() && looks_like_commit(r)) else {
+        return cloned;
+    };
+    if dest.exists() {
+        fs::remove_dir_all(dest)?;
+    }
+    let mut init = git();
+    init.args(["init", "-q"]).arg(dest);
+    run_git(init, "init", token, timeout)?;
+    let mut fetch = git();
+    fetch
+        .arg("-C")
         .arg(dest)
+        .args(["fetch", "--depth", "1", "--no-tags", "--", repo_url, commit]);
+    run_git(fetch, "fetch", token, timeout)?;
+    let mut checkout = git();
+    checkout.arg("-C").arg(dest).args(["checkout", "-q", "FETCH_HEAD"]);
+    run_git(checkout, "checkout", token, timeout)
+}
+
+/// Oldest git supporting every option the clone uses (`git clone
+/// --no-tags` arrived last, in 2.14).
+const MIN_GIT_VERSION: (u32, u32) = (2, 14);
+
+fn git_start_error(err: std::io::Error) -> AppError {
+    if err.kind() == std::io::ErrorKind::NotFound {
+        AppError::Git("git is not installed or not on PATH; install Git and try again".to_string())
+    } else {
+        AppError::Git(format!("Failed to start git: {err}"))
+    }
+}
+
+/// Fail early with an actionable message when git is missing or too old
+/// for shallow clones. Only success is remembered, so installing git while
+/// the app is running takes effect on the next attempt. An unrecognized
+/// version string is let through for the clone itself to judge.
+fn ensure_git_available() -> Result<(), AppError> {
+    static CHECKED: OnceLock<()> = OnceLock::new();
+    if CHECKED.get().is_some() {
+        return Ok(());
+    }
+    let output = Command::new("git")
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(git_start_error)?;
+    if let Some((major, minor)) = parse_git_version(&String::from_utf8_lossy(&output.stdout)) {
+        if (major, minor) < MIN_GIT_VERSION {
+            let (min_major, min_minor) = MIN_GIT_VERSION;
+            return Err(AppError::Git(format!(
+                "git {major}.{minor} is too old for shallow clones; \
+                 install git {min_major}.{min_minor} or newer"
+            )));
+        }
+    }
+    let _ = CHECKED.set(());
+    Ok(())
+}
+
+/// Whether a ref could be an abbreviated or full commit SHA.
+fn looks_like_commit(git_ref: &str) -> bool {
+    (7..=40).contains(&git_ref.len()) && git_ref.chars().all(|ch| ch.is_ascii_hexdigit())
+}
+
+/// Run a prepared `git` command, killing it after `timeout`. On failure the
+/// error carries git's stderr, with `token` scrubbed from it.
+fn run_git(
+    mut command: Command,
+    action: &str,
+    token: Option<&str>,
+    timeout: Duration,
+) -> Result<(), AppError> {
+    let mut child = command
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .map_err(|e| AppError::Git(format!("Failed to start git: {e}")))?;
+        .map_err(git_start_error)?;
 
     let start = Instant::now();
     loop {
@@ -84,8 +282,11 @@ fn git_clone_shallow(repo_url: &str, dest: &Path, timeout: Duration) -> Result<(
                     use std::io::Read;
                     let _ = err.read_to_string(&mut stderr);
                 }
+                if let Some(token) = token {
+                    stderr = scrub_secret(&stderr, token);
+                }
                 return Err(AppError::Git(format!(
-                    "git clone failed: {}",
+                    "git {action} failed: {}",
                     stderr.trim()
                 )));
             }
@@ -94,7 +295,7 @@ fn git_clone_shallow(repo_url: &str, dest: &Path, timeout: Duration) -> Result<(
                     let _ = child.kill();
                     let _ = child.wait();
                     return Err(AppError::Git(format!(
-                        "git clone timed out after {}s",
+                        "git {action} timed out after {}s",
                         timeout.as_secs()
                     )));
                 }
@@ -109,16 +310,45 @@ fn git_clone_shallow(repo_url: &str, dest: &Path, timeout: Duration) -> Result<(
     }
 }
 use crate::models::{
-    CopySkillToToolRequest, CreateGistRequest, CustomToolInput, DashboardData, DashboardStats, DiscoveredSkillsRoot,
-    InstallFromRegistryRequest, InstallSkillRequest, SaveSkillEntryRequest, SaveSkillRequest, SearchSkillResult,
-    SearchSkillsResponse, SkillFileEntry, SkillInfo, UpdateSkillFromGithubRequest,
+    AppState, BudgetEntry, BudgetReport, CopySkillResult, CopySkillToToolRequest, CreateGistRequest,
+    CustomToolInput, CustomToolProbe, DashboardData, DashboardDelta, DashboardStats, DeleteReport,
+    DiscoveredSkillsRoot, EditorSettings, ExternalRef, GithubTokenInfo, InstallFromRegistryRequest,
+    InstallPreview, InstallReport, InstallSkillRequest, InventoryRow, LinkMode, LocalSearchHit,
+    MergeGroup, MiscaseHit, MoveReport, PathFailure, PermissionReport, ProjectRoot, ProvenanceInfo,
+    PublishBundle, RootOverlap, SaveSkillEntryBase64Request, SaveSkillEntryRequest,
+    SaveSkillRequest, SearchSkillResult, SearchSkillsPage, SearchSkillsResponse, SimilarPair,
+    SkillEntryChunk, SkillEntryContent, SkillFileEntry, SkillGist, SkillInfo, SkillIssue,
+    SkillSnapshot, SkillTemplate, SkillTreeNode, SourceGuess, StateDiff, SyncReport, SyncSkip,
+    ToolInfo, ToolPathDetail, ToolRescan, UpdateSkillFromGithubRequest, DEFAULT_REGISTRY_URL,
 };
 use crate::skills::{
-    collect_skills_from_tool, copy_dir_recursive, dir_display_name, discover_skill_dir, discover_skills_roots,
-    discover_skill_dir_by_name, merge_skills, parse_skill_metadata, write_skill_source_meta,
+    body_len, body_shingles, body_word_count, build_skill_tree, cached_skills,
+    collect_skills_from_tool, collect_skills_with_timeout, copy_dir_atomic, copy_dir_recursive,
+    count_skills_in_root, decode_text, diff_snapshots, dir_display_name, discover_all_skill_dirs,
+    discover_skill_dir, discover_skill_dir_by_name, discover_skills_roots, extract_external_links,
+    file_len, fill_skill_template, find_matching_line, fix_manifest_name, frontmatter_name,
+    hardlink_dir_recursive, load_skill_info, looks_binary, merge_groups, merge_skills,
+    miscased_manifest, overlay_dir, parse_skill_metadata, project_skill_roots, read_provenance,
+    read_skill_text, render_skill_readme, render_skill_template, set_frontmatter_field,
+    set_manifest_enabled, shingle_similarity, skill_id, skill_manifest_path, skipped_skills,
+    snapshot_dashboard, symlink_dir, take_encoding_notices, unzip_checked, unzip_into,
+    validate_skill_content, with_frontmatter_name, write_file_atomic, write_skill_source_meta,
+    yaml_inline_list, zip_dir, DashboardSnapshot, SkillIgnore, BINARY_SNIFF_BYTES,
+    DISABLED_SKILL_MD, SKILL_TEMPLATE_IDS, SOURCE_META_FILE,
+};
+#[cfg(unix)]
+use crate::skills::normalize_permissions;
+use crate::state::{
+    app_data_dir, app_state_path, append_log_line, diff_states, load_github_token, load_state,
+    log_path, merge_states, read_state_file, save_state, store_github_token,
+    validate_imported_state, LOG_MAX_BYTES, MAX_WRITE_BYTES_CEILING,
+};
+use crate::tools::{
+    built_in_tools, curated_sources, default_context_budget, find_root_overlaps, find_tool_by_id,
+    find_tool_for_path, is_tool_root, project_tool_input, reconcile_tool_order,
+    resolve_skills_paths, resolve_tools, sanitize_tool_order, tool_input_to_info,
+    tools_overlapping_root,
 };
-use crate::state::{app_data_dir, load_state, save_state};
-use crate::tools::{built_in_tools, curated_sources, find_tool_by_id, resolve_tools, tool_input_to_info};
 
 fn diag_enabled() -> bool {
     env::var("SKILLSYOGA_DIAG")
@@ -132,12 +362,60 @@ fn diag_log(message: &str) {
     }
 }
 
+const LOG_LEVELS: &[&str] = &["debug", "info", "warn", "error"];
+
+/// Repeats of the last logged entry within this window are dropped, so a
+/// component logging on every render doesn't flood the file.
+const LOG_DEBOUNCE: Duration = Duration::from_secs(1);
+
+/// The last entry written to the log file and when. Held while writing so
+/// concurrent calls don't interleave with a rotation.
+fn last_log_entry() -> &'static Mutex<Option<(String, Instant)>> {
+    static LAST: OnceLock<Mutex<Option<(String, Instant)>>> = OnceLock::new();
+    LAST.get_or_init(|| Mutex::new(None))
+}
+
+/// Append a frontend log entry as a JSON line (`timestamp`, `level`,
+/// `message`) to the rotating log file in the app data dir.
 #[tauri::command]
-pub fn debug_log(message: String) -> Result<(), AppError> {
-    diag_log(&format!("[frontend] {message}"));
+pub fn debug_log(app: tauri::AppHandle, level: String, message: String) -> Result<(), AppError> {
+    let level = level.trim().to_ascii_lowercase();
+    if !LOG_LEVELS.contains(&level.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Unknown log level: {level} (expected one of {})",
+            LOG_LEVELS.join(", ")
+        )));
+    }
+    diag_log(&format!("[frontend] [{level}] {message}"));
+
+    let key = format!("{level}\n{message}");
+    let mut last = last_log_entry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if last
+        .as_ref()
+        .is_some_and(|(previous, at)| *previous == key && at.elapsed() < LOG_DEBOUNCE)
+    {
+        return Ok(());
+    }
+    let line = serde_json::json!({
+        "timestamp": now_iso(),
+        "level": level,
+        "message": message,
+    })
+    .to_string();
+    append_log_line(&log_path(&app)?, &line, LOG_MAX_BYTES)?;
+    *last = Some((key, Instant::now()));
     Ok(())
 }
 
+/// Where `debug_log` writes, for attaching to bug reports. The file may not
+/// exist yet if nothing has been logged.
+#[tauri::command]
+pub fn get_log_path(app: tauri::AppHandle) -> Result<String, AppError> {
+    Ok(log_path(&app)?.to_string_lossy().to_string())
+}
+
 fn normalize_relative_path(input: &str) -> Result<PathBuf, AppError> {
     let raw = input.trim();
     if raw.is_empty() {
@@ -187,8 +465,91 @@ fn to_relative_string(skill_root: &Path, child: &Path) -> Option<String> {
         .map(|p| p.to_string_lossy().replace('\\', "/"))
 }
 
+/// Scan every enabled tool's skills root concurrently, skipping (and
+/// reporting) any tool whose scan fails or exceeds `TOOL_SCAN_TIMEOUT`, and
+/// reporting single skills the scan couldn't load.
+/// Skills are returned in tool order, as a serial scan would.
+fn scan_enabled_tools(tools: &[ToolInfo]) -> (Vec<SkillInfo>, Vec<String>) {
+    let enabled: Vec<ToolInfo> = tools.iter().filter(|tool| tool.enabled).cloned().collect();
+    let mut skills = vec![];
+    let mut warnings = vec![];
+    for (tool, result) in enabled
+        .iter()
+        .zip(collect_skills_with_timeout(&enabled, TOOL_SCAN_TIMEOUT))
+    {
+        match result {
+            Ok(mut tool_skills) => {
+                skills.append(&mut tool_skills);
+                for skipped in skipped_skills(tool) {
+                    diag_log(&format!("[backend] skipped skill in {}: {skipped}", tool.id));
+                    warnings.push(format!("Skipped skill in {}: {skipped}", tool.name));
+                }
+            }
+            Err(err) => {
+                diag_log(&format!("[backend] skipped tool {} during scan: {err}", tool.id));
+                warnings.push(format!("Skipped {}: {err}", tool.name));
+            }
+        }
+    }
+    (skills, warnings)
+}
+
+/// Skills the latest scans of the enabled tools couldn't load.
+fn skipped_skill_count(tools: &[ToolInfo]) -> usize {
+    tools
+        .iter()
+        .filter(|tool| tool.enabled)
+        .map(|tool| skipped_skills(tool).len())
+        .sum()
+}
+
+/// How many past dashboard snapshots `dashboard_delta` can diff against.
+/// Older tokens fall back to a full reset.
+const MAX_DASHBOARD_SNAPSHOTS: usize = 8;
+
+/// Recent dashboard snapshots by token, oldest first. Kept in Tauri managed
+/// state (see `run`) so `dashboard_delta` can diff against the response a
+/// token came from.
+#[derive(Default)]
+pub struct DashboardSnapshots {
+    next_token: AtomicU64,
+    entries: Mutex<VecDeque<(u64, DashboardSnapshot)>>,
+}
+
+fn store_dashboard_snapshot(app: &tauri::AppHandle, snapshot: DashboardSnapshot) -> String {
+    let snapshots = app.state::<DashboardSnapshots>();
+    let token = snapshots.next_token.fetch_add(1, Ordering::Relaxed) + 1;
+    let mut entries = snapshots
+        .entries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    entries.push_back((token, snapshot));
+    while entries.len() > MAX_DASHBOARD_SNAPSHOTS {
+        entries.pop_front();
+    }
+    token.to_string()
+}
+
+fn find_dashboard_snapshot(app: &tauri::AppHandle, token: &str) -> Option<DashboardSnapshot> {
+    let token: u64 = token.parse().ok()?;
+    app.state::<DashboardSnapshots>()
+        .entries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .find(|(id, _)| *id == token)
+        .map(|(_, snapshot)| snapshot.clone())
+}
+
 fn dashboard(app: &tauri::AppHandle) -> Result<DashboardData, AppError> {
-    let state = load_state(app)?;
+    dashboard_with_snapshot(app).map(|(data, _)| data)
+}
+
+/// Build the dashboard and record a snapshot of it under a fresh token.
+fn dashboard_with_snapshot(
+    app: &tauri::AppHandle,
+) -> Result<(DashboardData, DashboardSnapshot), AppError> {
+    let mut state = load_state(app)?;
     let mut tools = vec![];
 
     for builtin in built_in_tools() {
@@ -199,6 +560,14 @@ fn dashboard(app: &tauri::AppHandle) -> Result<DashboardData, AppError> {
         tools.push(tool_input_to_info(custom, &state, "custom")?);
     }
 
+    for project in &state.project_roots {
+        tools.push(tool_input_to_info(&project_tool_input(project), &state, "project")?);
+    }
+
+    if reconcile_tool_order(&mut state.tool_order, &tools) {
+        save_state(app, &state)?;
+    }
+
     // Sort by persisted order; tools not in the list go at the end alphabetically
     tools.sort_by(|a, b| {
         let pos_a = state.tool_order.iter().position(|id| id == &a.id);
@@ -211,20 +580,18 @@ fn dashboard(app: &tauri::AppHandle) -> Result<DashboardData, AppError> {
         }
     });
 
-    let mut skills_raw = vec![];
-    for tool in &tools {
-        if !tool.enabled {
-            continue;
-        }
-        let mut tool_skills = collect_skills_from_tool(tool)?;
-        skills_raw.append(&mut tool_skills);
+    let (mut skills_raw, mut warnings) = scan_enabled_tools(&tools);
+    warnings.extend(take_encoding_notices());
+    for skill in &mut skills_raw {
+        skill.pinned = state.pinned_skills.contains(&skill.path);
     }
-    let skills = merge_skills(skills_raw);
+    let skills = merge_skills(skills_raw, &state.skill_merge_mode);
 
     let stats = DashboardStats {
         installed_skills: skills.len(),
         detected_tools: tools.iter().filter(|t| t.detected).count(),
         enabled_tools: tools.iter().filter(|t| t.enabled).count(),
+        skipped_skills: skipped_skill_count(&tools),
     };
 
     let skill_editor_default_mode = if state.skill_editor_default_mode == "edit" {
@@ -233,18 +600,26 @@ fn dashboard(app: &tauri::AppHandle) -> Result<DashboardData, AppError> {
         "view".to_string()
     };
 
-    Ok(DashboardData {
+    let snapshot = snapshot_dashboard(&skills, &tools);
+    let token = store_dashboard_snapshot(app, snapshot.clone());
+
+    let data = DashboardData {
         tools,
         skills,
         sources: curated_sources(),
         stats,
         app_data_dir: app_data_dir(app)?.to_string_lossy().to_string(),
-        has_github_token: state
-            .github_token
-            .as_ref()
-            .is_some_and(|token| !token.trim().is_empty()),
+        has_github_token: load_github_token(app)?.is_some(),
         skill_editor_default_mode,
-    })
+        editor_settings: state.editor_settings,
+        skill_merge_mode: state.skill_merge_mode,
+        default_install_tool_id: state.default_install_tool_id,
+        skill_templates: state.skill_templates,
+        max_skill_md_bytes: state.max_skill_md_bytes,
+        warnings,
+        token,
+    };
+    Ok((data, snapshot))
 }
 
 #[tauri::command]
@@ -252,396 +627,2667 @@ pub fn get_dashboard_data(app: tauri::AppHandle) -> Result<DashboardData, AppErr
     dashboard(&app)
 }
 
+/// Rescan a single tool, e.g. after installing into it, instead of
+/// rebuilding the whole dashboard. The returned skills are unmerged and
+/// empty for a disabled tool; `stats` counts other tools' skills as of
+/// their last scan.
 #[tauri::command]
-pub fn set_tool_enabled(
+pub fn rescan_tool(app: tauri::AppHandle, tool_id: String) -> Result<ToolRescan, AppError> {
+    let tool = find_tool_by_id(&app, &tool_id)?;
+    let state = load_state(&app)?;
+    let tools = resolve_tools(&app)?;
+
+    let mut skills = if tool.enabled {
+        collect_skills_from_tool(&tool)?
+    } else {
+        vec![]
+    };
+    for skill in &mut skills {
+        skill.pinned = state.pinned_skills.contains(&skill.path);
+    }
+
+    let enabled_ids: HashSet<&str> = tools
+        .iter()
+        .filter(|t| t.enabled)
+        .map(|t| t.id.as_str())
+        .collect();
+    let mut all_skills: Vec<SkillInfo> = cached_skills()
+        .into_iter()
+        .filter(|skill| skill.source != tool.id && enabled_ids.contains(skill.source.as_str()))
+        .collect();
+    all_skills.extend(skills.iter().cloned());
+
+    let stats = DashboardStats {
+        installed_skills: merge_skills(all_skills, &state.skill_merge_mode).len(),
+        detected_tools: tools.iter().filter(|t| t.detected).count(),
+        enabled_tools: enabled_ids.len(),
+        skipped_skills: skipped_skill_count(&tools),
+    };
+    Ok(ToolRescan {
+        tool_id: tool.id,
+        skills,
+        stats,
+    })
+}
+
+/// Sum the SKILL.md body sizes of a tool's skills and compare the total,
+/// and each skill on its own, against the tool's context budget.
+#[tauri::command]
+pub fn context_budget_report(
     app: tauri::AppHandle,
     tool_id: String,
-    enabled: bool,
-) -> Result<(), AppError> {
-    let mut state = load_state(&app)?;
-    state.tool_toggles.insert(tool_id, enabled);
-    save_state(&app, &state)
+) -> Result<BudgetReport, AppError> {
+    let tool = find_tool_by_id(&app, &tool_id)?;
+    let state = load_state(&app)?;
+    let budget_bytes = state
+        .context_budgets
+        .get(&tool.id)
+        .copied()
+        .unwrap_or_else(|| default_context_budget(&tool.id));
+
+    let mut skills: Vec<BudgetEntry> = collect_skills_from_tool(&tool)?
+        .into_iter()
+        .map(|skill| {
+            let content =
+                read_skill_text(&skill_manifest_path(Path::new(&skill.path))).unwrap_or_default();
+            let body_bytes = body_len(&content) as u64;
+            BudgetEntry {
+                path: skill.path,
+                name: skill.name,
+                body_bytes,
+                words: body_word_count(&content),
+                over_budget: body_bytes > budget_bytes,
+            }
+        })
+        .collect();
+    skills.sort_by(|a, b| b.body_bytes.cmp(&a.body_bytes).then_with(|| a.path.cmp(&b.path)));
+
+    let total_bytes = skills.iter().map(|s| s.body_bytes).sum();
+    Ok(BudgetReport {
+        tool_id: tool.id,
+        budget_bytes,
+        total_bytes,
+        total_words: skills.iter().map(|s| s.words).sum(),
+        over_budget: total_bytes > budget_bytes,
+        skills,
+    })
 }
 
+/// Which skills the current merge mode collapses into one dashboard entry,
+/// so a skill count lower than the number of folders on disk can be
+/// explained. The dashboard itself is unaffected.
 #[tauri::command]
-pub fn read_skill_file(app: tauri::AppHandle, path: String) -> Result<String, AppError> {
-    let dir = PathBuf::from(&path);
-    is_path_under_skills_root(&dir, &app)?;
-    let skill_file = dir.join("SKILL.md");
-    let content = fs::read_to_string(&skill_file)?;
-    Ok(content)
+pub fn explain_merge(app: tauri::AppHandle) -> Result<Vec<MergeGroup>, AppError> {
+    let state = load_state(&app)?;
+    let tools = resolve_tools(&app)?;
+    let (mut skills, _) = scan_enabled_tools(&tools);
+    for skill in &mut skills {
+        skill.pinned = state.pinned_skills.contains(&skill.path);
+    }
+    Ok(merge_groups(skills, &state.skill_merge_mode))
 }
 
+/// Skills and tools that changed since the dashboard response identified by
+/// `since_token`, for live updates without resending the whole dashboard.
 #[tauri::command]
-pub fn list_skill_files(
-    app: tauri::AppHandle,
-    path: String,
-) -> Result<Vec<SkillFileEntry>, AppError> {
-    let skill_root = PathBuf::from(&path);
-    is_path_under_skills_root(&skill_root, &app)?;
+pub fn dashboard_delta(app: tauri::AppHandle, since_token: String) -> Result<DashboardDelta, AppError> {
+    let previous = find_dashboard_snapshot(&app, &since_token);
+    let (data, snapshot) = dashboard_with_snapshot(&app)?;
+    let DashboardData {
+        tools,
+        skills,
+        stats,
+        warnings,
+        token,
+        ..
+    } = data;
+
+    let Some(previous) = previous else {
+        return Ok(DashboardDelta {
+            token,
+            reset: true,
+            added: skills,
+            changed: vec![],
+            removed: vec![],
+            tools,
+            removed_tools: vec![],
+            stats,
+            warnings,
+        });
+    };
 
-    if !skill_root.exists() {
-        return Err(AppError::NotFound(format!(
-            "Skill path does not exist: {}",
-            skill_root.display()
+    let diff = diff_snapshots(&previous, &snapshot);
+    let pick_skills = |paths: &[String]| -> Vec<SkillInfo> {
+        skills
+            .iter()
+            .filter(|skill| paths.contains(&skill.path))
+            .cloned()
+            .collect()
+    };
+    Ok(DashboardDelta {
+        token,
+        reset: false,
+        added: pick_skills(&diff.added),
+        changed: pick_skills(&diff.changed),
+        removed: diff.removed,
+        tools: tools
+            .into_iter()
+            .filter(|tool| diff.changed_tools.contains(&tool.id))
+            .collect(),
+        removed_tools: diff.removed_tools,
+        stats,
+        warnings,
+    })
+}
+
+/// Export the dashboard's skills as a `json` or `csv` report. List columns
+/// (tags, tools) are `;`-separated in CSV.
+#[tauri::command]
+pub fn export_inventory(app: tauri::AppHandle, format: String) -> Result<String, AppError> {
+    let format = format.trim().to_ascii_lowercase();
+    if format != "json" && format != "csv" {
+        return Err(AppError::Validation(format!(
+            "Unsupported export format '{format}': use json or csv"
         )));
     }
 
-    let mut entries = vec![];
-    let mut stack = vec![skill_root.clone()];
-
-    while let Some(dir) = stack.pop() {
-        for child in fs::read_dir(&dir)? {
-            let child = child?;
-            let child_path = child.path();
+    let rows: Vec<InventoryRow> = dashboard(&app)?
+        .skills
+        .into_iter()
+        .map(|skill| {
+            let word_count = read_skill_text(&skill_manifest_path(Path::new(&skill.path)))
+                .map(|content| body_word_count(&content))
+                .unwrap_or(0);
+            InventoryRow {
+                name: skill.name,
+                description: skill.description,
+                tags: skill.tags,
+                tools: skill.enabled_for,
+                path: skill.path,
+                word_count,
+                updated_at: skill.updated_at,
+            }
+        })
+        .collect();
 
-            let Some(relative) = to_relative_string(&skill_root, &child_path) else {
-                continue;
-            };
+    if format == "json" {
+        return Ok(serde_json::to_string_pretty(&rows)?);
+    }
+    let mut out = String::from("name,description,tags,tools,path,word_count,updated_at\n");
+    for row in &rows {
+        let fields = [
+            csv_field(&row.name),
+            csv_field(&row.description),
+            csv_field(&row.tags.join(";")),
+            csv_field(&row.tools.join(";")),
+            csv_field(&row.path),
+            row.word_count.to_string(),
+            csv_field(&row.updated_at),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    Ok(out)
+}
 
-            if relative.is_empty() {
-                continue;
-            }
+/// Pairs of installed skills (across all enabled tools) whose SKILL.md
+/// bodies have a similarity of at least `threshold` (0.0–1.0), most
+/// similar first. The same directory reached through two tools is only
+/// compared once.
+#[tauri::command]
+pub fn find_similar_skills(app: tauri::AppHandle, threshold: f32) -> Result<Vec<SimilarPair>, AppError> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(AppError::Validation(
+            "Threshold must be between 0 and 1".to_string(),
+        ));
+    }
 
-            let file_name = child.file_name();
-            if file_name.to_string_lossy().starts_with('.') {
-                continue;
-            }
+    let (skills, _) = scan_enabled_tools(&resolve_tools(&app)?);
+    let mut seen = HashSet::new();
+    let mut entries = vec![];
+    for skill in skills {
+        let dir = PathBuf::from(&skill.path);
+        if !seen.insert(dir.canonicalize().unwrap_or_else(|_| dir.clone())) {
+            continue;
+        }
+        if let Ok(content) = read_skill_text(&skill_manifest_path(&dir)) {
+            entries.push((skill, body_shingles(&content)));
+        }
+    }
 
-            let is_dir = child_path.is_dir();
-            entries.push(SkillFileEntry {
-                relative_path: relative,
-                is_dir,
-            });
-            if is_dir {
-                stack.push(child_path);
+    let mut pairs = vec![];
+    for (i, (a, a_shingles)) in entries.iter().enumerate() {
+        for (b, b_shingles) in &entries[i + 1..] {
+            let score = shingle_similarity(a_shingles, b_shingles);
+            if score >= threshold && score > 0.0 {
+                pairs.push(SimilarPair {
+                    a_path: a.path.clone(),
+                    a_name: a.name.clone(),
+                    b_path: b.path.clone(),
+                    b_name: b.name.clone(),
+                    score,
+                });
             }
         }
     }
-
-    entries.sort_by(|a, b| {
-        a.relative_path
-            .cmp(&b.relative_path)
-            .then_with(|| b.is_dir.cmp(&a.is_dir))
-    });
-
-    diag_log(&format!(
-        "[backend] list_skill_files path={} entries={}",
-        skill_root.display(),
-        entries.len()
-    ));
-
-    Ok(entries)
+    pairs.sort_by(|x, y| y.score.total_cmp(&x.score));
+    Ok(pairs)
 }
 
+/// Largest non-manifest file `search_local_skills` reads.
+const MAX_SEARCH_FILE_BYTES: u64 = 256 * 1024;
+
+/// Installed skills across all enabled tools whose SKILL.md contains
+/// `query`, ignoring case. With `include_files`, the skill's other text
+/// files are searched too. Each skill is reported once, for its first
+/// matching line.
 #[tauri::command]
-pub fn read_skill_entry(
+pub fn search_local_skills(
     app: tauri::AppHandle,
-    path: String,
-    relative_path: String,
-) -> Result<String, AppError> {
-    let skill_root = PathBuf::from(&path);
-    is_path_under_skills_root(&skill_root, &app)?;
-
-    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
-    if !target.exists() {
-        return Err(AppError::NotFound(format!(
-            "File does not exist: {}",
-            target.display()
-        )));
+    query: String,
+    include_files: Option<bool>,
+) -> Result<Vec<LocalSearchHit>, AppError> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Err(AppError::Validation("Search query is empty".to_string()));
     }
-    if !target.is_file() {
-        return Err(AppError::Validation(format!(
-            "Path is not a file: {}",
-            relative_path
-        )));
+    let include_files = include_files.unwrap_or(false);
+
+    let (skills, _) = scan_enabled_tools(&resolve_tools(&app)?);
+    let mut seen = HashSet::new();
+    let mut hits = vec![];
+    for skill in skills {
+        let dir = PathBuf::from(&skill.path);
+        if !seen.insert(dir.canonicalize().unwrap_or_else(|_| dir.clone())) {
+            continue;
+        }
+        let manifest = skill_manifest_path(&dir);
+        let mut found = read_skill_text(&manifest).ok().and_then(|text| {
+            let file = to_relative_string(&dir, &manifest)?;
+            find_matching_line(&text, &needle).map(|hit| (file, hit))
+        });
+        if found.is_none() && include_files {
+            found = search_skill_files(&dir, &manifest, &needle);
+        }
+        if let Some((file, (line, snippet))) = found {
+            hits.push(LocalSearchHit {
+                skill,
+                file,
+                line,
+                snippet,
+            });
+        }
     }
+    Ok(hits)
+}
 
-    let content = fs::read_to_string(&target)?;
-    diag_log(&format!(
-        "[backend] read_skill_entry file={} chars={}",
-        target.display(),
-        content.chars().count()
-    ));
-    Ok(content)
+/// The first match in a skill's files other than its manifest, skipping
+/// large and binary files.
+fn search_skill_files(
+    dir: &Path,
+    manifest: &Path,
+    needle: &str,
+) -> Option<(String, (usize, String))> {
+    let entries = collect_skill_entries(dir, &SkillIgnore::default()).ok()?;
+    entries.into_iter().filter(|entry| !entry.is_dir).find_map(|entry| {
+        let path = dir.join(&entry.relative_path);
+        if path == manifest {
+            return None;
+        }
+        if fs::metadata(&path).ok()?.len() > MAX_SEARCH_FILE_BYTES {
+            return None;
+        }
+        let text = String::from_utf8(fs::read(&path).ok()?).ok()?;
+        if text.contains('\0') {
+            return None;
+        }
+        find_matching_line(&text, needle).map(|hit| (entry.relative_path, hit))
+    })
 }
 
+/// Most recently modified skills across all enabled tools, newest first.
 #[tauri::command]
-pub fn save_skill_entry(
-    app: tauri::AppHandle,
-    request: SaveSkillEntryRequest,
-) -> Result<(), AppError> {
-    let skill_root = PathBuf::from(&request.path);
-    is_path_under_skills_root(&skill_root, &app)?;
-
-    let target = resolve_skill_child_path(&skill_root, &request.relative_path)?;
-    if let Some(parent) = target.parent() {
-        ensure_dir(parent)?;
+pub fn recent_skills(app: tauri::AppHandle, limit: usize) -> Result<Vec<SkillInfo>, AppError> {
+    let state = load_state(&app)?;
+    let tools = resolve_tools(&app)?;
+    let (mut skills, _) = scan_enabled_tools(&tools);
+    for skill in &mut skills {
+        skill.pinned = state.pinned_skills.contains(&skill.path);
     }
-    fs::write(target, request.content)?;
-    Ok(())
+    // `updated_at` values share one fixed-width format, so comparing the
+    // strings orders them chronologically.
+    skills.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    skills.truncate(limit);
+    Ok(skills)
 }
 
 #[tauri::command]
-pub fn create_skill_dir(
+pub fn set_tool_enabled(
     app: tauri::AppHandle,
-    path: String,
-    relative_path: String,
+    tool_id: String,
+    enabled: bool,
 ) -> Result<(), AppError> {
-    let skill_root = PathBuf::from(&path);
-    is_path_under_skills_root(&skill_root, &app)?;
+    let mut state = load_state(&app)?;
+    state.tool_toggles.insert(tool_id, enabled);
+    save_state(&app, &state)
+}
 
-    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
-    if target.exists() && !target.is_dir() {
-        return Err(AppError::Validation(format!(
-            "Path exists and is not a directory: {}",
-            relative_path
-        )));
+/// Mark a tool as a CLI or GUI tool. Custom tools are updated in place;
+/// built-ins keep their shipped default and store an override instead.
+#[tauri::command]
+pub fn set_tool_cli(app: tauri::AppHandle, tool_id: String, cli: bool) -> Result<(), AppError> {
+    let mut state = load_state(&app)?;
+    if let Some(custom) = state.custom_tools.iter_mut().find(|t| t.id == tool_id) {
+        custom.cli = cli;
+    } else if built_in_tools().iter().any(|builtin| builtin.id == tool_id) {
+        state.tool_cli_overrides.insert(tool_id, cli);
+    } else {
+        return Err(AppError::NotFound(format!("Tool not found: {tool_id}")));
     }
-    ensure_dir(&target)?;
-    Ok(())
+    save_state(&app, &state)
 }
 
 #[tauri::command]
-pub fn rename_skill_entry(
+pub fn read_skill_file(app: tauri::AppHandle, path: String) -> Result<String, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    read_skill_text(&skill_manifest_path(&dir))
+}
+
+/// Read a skill's SKILL.md from its id (see `skills::skill_id`). Skills
+/// sitting directly in the root the id names are found by joining the
+/// folder name; deeper ones are looked up through a scan of that tool.
+#[tauri::command]
+pub fn read_skill_by_id(app: tauri::AppHandle, skill_id: String) -> Result<String, AppError> {
+    let invalid = || AppError::Validation(format!("Invalid skill id: {skill_id}"));
+    let (tool_ref, dir_name) = skill_id
+        .split_once(':')
+        .filter(|(tool_ref, dir_name)| !tool_ref.is_empty() && !dir_name.is_empty())
+        .ok_or_else(invalid)?;
+    let (tool_id, root_index) = match tool_ref.split_once('#') {
+        Some((tool_id, index)) => (tool_id, index.parse::<usize>().map_err(|_| invalid())?),
+        None => (tool_ref, 0),
+    };
+    let tool = find_tool_by_id(&app, tool_id)?;
+
+    let mut components = Path::new(dir_name).components();
+    let is_plain_name = matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    );
+    let direct = tool
+        .skills_paths
+        .get(root_index)
+        .map(|root| Path::new(root).join(dir_name))
+        .filter(|dir| skill_manifest_path(dir).is_file());
+    let dir = if let Some(direct) = direct.filter(|_| is_plain_name) {
+        direct
+    } else {
+        collect_skills_from_tool(&tool)?
+            .into_iter()
+            .find(|skill| skill.id == skill_id)
+            .map(|skill| PathBuf::from(skill.path))
+            .ok_or_else(|| AppError::NotFound(format!("Skill not found: {skill_id}")))?
+    };
+
+    is_path_under_skills_root(&dir, &app)?;
+    read_skill_text(&skill_manifest_path(&dir))
+}
+
+/// Whether saves into the skill will succeed: the folder must accept new
+/// files and SKILL.md, if present, must not be read-only.
+#[tauri::command]
+pub fn is_skill_writable(app: tauri::AppHandle, path: String) -> Result<bool, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    if !dir.is_dir() {
+        return Err(AppError::NotFound(format!("Skill path does not exist: {path}")));
+    }
+    let skill_file_readonly = fs::metadata(skill_manifest_path(&dir))
+        .map(|meta| meta.permissions().readonly())
+        .unwrap_or(false);
+    Ok(!skill_file_readonly && dir_is_writable(&dir))
+}
+
+/// Reset a skill's file modes to sane defaults (see `normalize_permissions`)
+/// and report what changed. A no-op on platforms without Unix modes.
+#[tauri::command]
+pub fn normalize_skill_permissions(
     app: tauri::AppHandle,
     path: String,
-    old_relative_path: String,
-    new_relative_path: String,
-) -> Result<(), AppError> {
+) -> Result<PermissionReport, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    if !dir.is_dir() {
+        return Err(AppError::NotFound(format!("Skill path does not exist: {path}")));
+    }
+
+    #[cfg(unix)]
+    {
+        Ok(PermissionReport {
+            supported: true,
+            changed: normalize_permissions(&dir)?,
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        Ok(PermissionReport {
+            supported: false,
+            changed: vec![],
+        })
+    }
+}
+
+/// Skill directories in a tool's skills roots (each root itself and its
+/// immediate subfolders) whose manifest is a case variant of `SKILL.md`.
+#[tauri::command]
+pub fn find_miscased_manifests(
+    app: tauri::AppHandle,
+    tool_id: String,
+) -> Result<Vec<MiscaseHit>, AppError> {
+    let tool = find_tool_by_id(&app, &tool_id)?;
+    let mut dirs = vec![];
+    for root in tool.skills_paths.iter().map(PathBuf::from) {
+        if !root.is_dir() {
+            continue;
+        }
+        let mut children = vec![];
+        for entry in fs::read_dir(&root)?.flatten() {
+            let path = entry.path();
+            if path.is_dir() && !entry.file_name().to_string_lossy().starts_with('.') {
+                children.push(path);
+            }
+        }
+        children.sort();
+        dirs.push(root);
+        dirs.extend(children);
+    }
+
+    Ok(dirs
+        .iter()
+        .filter_map(|dir| {
+            let manifest = miscased_manifest(dir)?;
+            Some(MiscaseHit {
+                dir: dir.to_string_lossy().to_string(),
+                file_name: manifest.file_name()?.to_string_lossy().to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Rename the miscased manifest in skill directory `path` to `SKILL.md`.
+/// Returns the new manifest path.
+#[tauri::command]
+pub fn fix_manifest_case(app: tauri::AppHandle, path: String) -> Result<String, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    let manifest = miscased_manifest(&dir)
+        .ok_or_else(|| AppError::NotFound(format!("No miscased SKILL.md in {path}")))?;
+    let fixed = fix_manifest_name(&manifest)?;
+    Ok(fixed.to_string_lossy().to_string())
+}
+
+/// Check a skill's SKILL.md against the Agent Skills spec, returning each
+/// problem instead of falling back the way the scan does. Also warns when
+/// `name` doesn't match the folder name, which the spec requires.
+#[tauri::command]
+pub fn validate_skill(app: tauri::AppHandle, path: String) -> Result<Vec<SkillIssue>, AppError> {
+    let skill_dir = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_dir, &app)?;
+    let skill_file = skill_manifest_path(&skill_dir);
+    if !skill_file.is_file() {
+        return Err(AppError::NotFound(format!("SKILL.md not found in {path}")));
+    }
+
+    let content = read_skill_text(&skill_file)?;
+    let mut issues = validate_skill_content(&content);
+    let folder = dir_display_name(&skill_dir);
+    if let Some(name) = frontmatter_name(&content) {
+        if name.trim() != folder {
+            issues.push(SkillIssue {
+                severity: "warning".to_string(),
+                field: Some("name".to_string()),
+                message: format!(
+                    "`name` ({}) does not match the folder name ({folder})",
+                    name.trim()
+                ),
+            });
+        }
+    }
+    Ok(issues)
+}
+
+/// Every absolute `http(s)://` link in the skill's markdown files (SKILL.md
+/// and any nested `.md`), for auditing what a skill may tell an agent to
+/// fetch. Hidden directories are skipped.
+#[tauri::command]
+pub fn list_skill_external_refs(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<Vec<ExternalRef>, AppError> {
     let skill_root = PathBuf::from(&path);
     is_path_under_skills_root(&skill_root, &app)?;
+    if !skill_root.is_dir() {
+        return Err(AppError::NotFound(format!("Skill path does not exist: {path}")));
+    }
 
-    let old_target = resolve_skill_child_path(&skill_root, &old_relative_path)?;
-    let new_target = resolve_skill_child_path(&skill_root, &new_relative_path)?;
+    let mut refs = vec![];
+    let mut stack = vec![skill_root.clone()];
+    while let Some(dir) = stack.pop() {
+        for entry in fs::read_dir(&dir)?.flatten() {
+            let entry_path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if !entry.file_name().to_string_lossy().starts_with('.') {
+                    stack.push(entry_path);
+                }
+                continue;
+            }
+            let is_markdown = entry_path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+            if !file_type.is_file() || !is_markdown {
+                continue;
+            }
+            let Ok(bytes) = fs::read(&entry_path) else {
+                continue;
+            };
+            let content = String::from_utf8_lossy(&bytes);
+            let relative_path = entry_path
+                .strip_prefix(&skill_root)
+                .unwrap_or(&entry_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            for (line, url, text) in extract_external_links(&content) {
+                refs.push(ExternalRef {
+                    relative_path: relative_path.clone(),
+                    line,
+                    url,
+                    text,
+                });
+            }
+        }
+    }
+    refs.sort_by(|a, b| a.relative_path.cmp(&b.relative_path).then(a.line.cmp(&b.line)));
+    Ok(refs)
+}
 
-    if !old_target.exists() {
+/// Every entry under `root` except hidden ones and what `ignore`
+/// excludes, sorted by relative path with each folder before its contents.
+fn collect_skill_entries(
+    root: &Path,
+    ignore: &SkillIgnore,
+) -> Result<Vec<SkillFileEntry>, AppError> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        ignore: &SkillIgnore,
+        entries: &mut Vec<SkillFileEntry>,
+    ) -> Result<(), AppError> {
+        let nested = ignore.nested(dir);
+        let ignore = nested.as_ref().unwrap_or(ignore);
+        for child in fs::read_dir(dir)? {
+            let child = child?;
+            let child_path = child.path();
+            if child.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let is_dir = child_path.is_dir();
+            if ignore.is_ignored(&child_path, is_dir) {
+                continue;
+            }
+            let Some(relative) = to_relative_string(root, &child_path) else {
+                continue;
+            };
+            if relative.is_empty() {
+                continue;
+            }
+
+            entries.push(SkillFileEntry {
+                relative_path: relative,
+                is_dir,
+            });
+            if is_dir {
+                walk(root, &child_path, ignore, entries)?;
+            }
+        }
+        Ok(())
+    }
+
+    let mut entries = vec![];
+    walk(root, root, ignore, &mut entries)?;
+    entries.sort_by(|a, b| {
+        a.relative_path
+            .cmp(&b.relative_path)
+            .then_with(|| b.is_dir.cmp(&a.is_dir))
+    });
+    Ok(entries)
+}
+
+#[tauri::command]
+pub fn list_skill_files(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<Vec<SkillFileEntry>, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    if !skill_root.exists() {
         return Err(AppError::NotFound(format!(
-            "Path does not exist: {}",
-            old_relative_path
+            "Skill path does not exist: {}",
+            skill_root.display()
         )));
     }
-    if new_target.exists() {
-        return Err(AppError::Validation(format!(
-            "Target path already exists: {}",
-            new_relative_path
+
+    let entries = collect_skill_entries(&skill_root, &SkillIgnore::default())?;
+
+    diag_log(&format!(
+        "[backend] list_skill_files path={} entries={}",
+        skill_root.display(),
+        entries.len()
+    ));
+
+    Ok(entries)
+}
+
+/// A skill's files as a nested tree, with the same entries as
+/// `list_skill_files`.
+#[tauri::command]
+pub fn get_skill_tree(app: tauri::AppHandle, path: String) -> Result<Vec<SkillTreeNode>, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    if !skill_root.exists() {
+        return Err(AppError::NotFound(format!(
+            "Skill path does not exist: {}",
+            skill_root.display()
         )));
     }
-    if let Some(parent) = new_target.parent() {
-        ensure_dir(parent)?;
+
+    let entries = collect_skill_entries(&skill_root, &SkillIgnore::default())?;
+    Ok(build_skill_tree(&entries))
+}
+
+/// The existing file at `relative_path` inside the skill at `path`.
+fn resolve_skill_file(
+    app: &tauri::AppHandle,
+    path: &str,
+    relative_path: &str,
+) -> Result<PathBuf, AppError> {
+    let skill_root = PathBuf::from(path);
+    is_path_under_skills_root(&skill_root, app)?;
+
+    let target = resolve_skill_child_path(&skill_root, relative_path)?;
+    if !target.exists() {
+        return Err(AppError::NotFound(format!(
+            "File does not exist: {}",
+            target.display()
+        )));
     }
-    fs::rename(old_target, new_target)?;
-    Ok(())
+    if !target.is_file() {
+        return Err(AppError::Validation(format!(
+            "Path is not a file: {}",
+            relative_path
+        )));
+    }
+    Ok(target)
 }
 
 #[tauri::command]
-pub fn delete_skill_entry(
+pub fn read_skill_entry(
     app: tauri::AppHandle,
     path: String,
     relative_path: String,
-) -> Result<(), AppError> {
+) -> Result<String, AppError> {
+    let target = resolve_skill_file(&app, &path, &relative_path)?;
+
+    // Only the manifest gets encoding detection; other entries must be
+    // UTF-8 so binary files keep failing to open as text.
+    let is_manifest = target
+        .file_name()
+        .is_some_and(|name| name == "SKILL.md" || name == DISABLED_SKILL_MD);
+    let content = if is_manifest {
+        read_skill_text(&target)?
+    } else {
+        fs::read_to_string(&target)?
+    };
+    diag_log(&format!(
+        "[backend] read_skill_entry file={} chars={}",
+        target.display(),
+        content.chars().count()
+    ));
+    Ok(content)
+}
+
+/// Largest byte range `read_skill_entry_range` returns per call.
+const MAX_READ_CHUNK_BYTES: u64 = 1024 * 1024;
+
+/// Read `length` bytes (clamped to `MAX_READ_CHUNK_BYTES`) of a skill file
+/// starting at `offset`, so large bundled files can be paged through.
+/// Text chunks end on a character boundary; anything that isn't UTF-8 is
+/// returned as base64.
+#[tauri::command]
+pub fn read_skill_entry_range(
+    app: tauri::AppHandle,
+    path: String,
+    relative_path: String,
+    offset: u64,
+    length: u64,
+) -> Result<SkillEntryChunk, AppError> {
+    use base64::Engine;
+    use std::io::{Read, Seek, SeekFrom};
+
     let skill_root = PathBuf::from(&path);
     is_path_under_skills_root(&skill_root, &app)?;
-
     let target = resolve_skill_child_path(&skill_root, &relative_path)?;
-    if !target.exists() {
+    if !target.is_file() {
         return Err(AppError::NotFound(format!(
-            "Path does not exist: {}",
-            relative_path
+            "File does not exist: {relative_path}"
         )));
     }
-    if !target.is_file() {
+
+    let mut file = fs::File::open(&target)?;
+    let total_size = file.metadata()?.len();
+    let offset = offset.min(total_size);
+    let length = length.min(MAX_READ_CHUNK_BYTES).min(total_size - offset);
+    file.seek(SeekFrom::Start(offset))?;
+    let mut bytes = vec![];
+    file.take(length).read_to_end(&mut bytes)?;
+
+    let reaches_eof = offset + bytes.len() as u64 >= total_size;
+    let (content, encoding, used) = match utf8_chunk_len(&bytes, reaches_eof) {
+        Some(len) => (
+            String::from_utf8_lossy(&bytes[..len]).into_owned(),
+            "utf8",
+            len,
+        ),
+        None => (
+            base64::engine::general_purpose::STANDARD.encode(&bytes),
+            "base64",
+            bytes.len(),
+        ),
+    };
+    let used = used as u64;
+    Ok(SkillEntryChunk {
+        content,
+        encoding: encoding.to_string(),
+        offset,
+        length: used,
+        total_size,
+        eof: offset + used >= total_size,
+    })
+}
+
+/// Read a whole skill file along with its size and modified time. Binary
+/// files (see `looks_binary`) come back as base64. Files over
+/// `MAX_READ_CHUNK_BYTES` are only sniffed for binary content and come back
+/// without it; `read_skill_entry_range` reads those piecewise.
+#[tauri::command]
+pub fn read_skill_entry_meta(
+    app: tauri::AppHandle,
+    path: String,
+    relative_path: String,
+) -> Result<SkillEntryContent, AppError> {
+    use base64::Engine;
+    use std::io::Read;
+
+    let target = resolve_skill_file(&app, &path, &relative_path)?;
+    let metadata = fs::metadata(&target)?;
+    let size = metadata.len();
+    let modified_at = metadata.modified().ok().map(iso_timestamp);
+
+    let is_manifest = target.file_name().is_some_and(|name| name == "SKILL.md");
+    let (content, encoding, is_binary) = if is_manifest {
+        (read_skill_text(&target)?, "utf8", false)
+    } else if size > MAX_READ_CHUNK_BYTES {
+        // Sniff only the head so a large file isn't read into memory.
+        let mut head = vec![];
+        fs::File::open(&target)?
+            .take(BINARY_SNIFF_BYTES as u64)
+            .read_to_end(&mut head)?;
+        (String::new(), "none", head.contains(&0))
+    } else {
+        let bytes = fs::read(&target)?;
+        if looks_binary(&bytes) {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            (encoded, "base64", true)
+        } else {
+            (String::from_utf8_lossy(&bytes).into_owned(), "utf8", false)
+        }
+    };
+    diag_log(&format!(
+        "[backend] read_skill_entry_meta file={} size={size} encoding={encoding}",
+        target.display()
+    ));
+    Ok(SkillEntryContent {
+        content,
+        encoding: encoding.to_string(),
+        size,
+        modified_at,
+        is_binary,
+    })
+}
+
+fn write_skill_entry(
+    app: &tauri::AppHandle,
+    path: &str,
+    relative_path: &str,
+    bytes: &[u8],
+) -> Result<(), AppError> {
+    let skill_root = PathBuf::from(path);
+    is_path_under_skills_root(&skill_root, app)?;
+
+    let state = load_state(app)?;
+    check_write_size(bytes.len(), state.max_write_bytes)?;
+
+    let target = resolve_skill_child_path(&skill_root, relative_path)?;
+    if target
+        .file_name()
+        .is_some_and(|name| name == "SKILL.md" || name == DISABLED_SKILL_MD)
+    {
+        check_skill_md_size(bytes.len(), state.max_skill_md_bytes)?;
+    }
+    if let Some(parent) = target.parent() {
+        ensure_dir(parent)?;
+    }
+    write_file_atomic(&target, bytes)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn save_skill_entry(
+    app: tauri::AppHandle,
+    request: SaveSkillEntryRequest,
+) -> Result<(), AppError> {
+    write_skill_entry(
+        &app,
+        &request.path,
+        &request.relative_path,
+        request.content.as_bytes(),
+    )
+}
+
+#[tauri::command]
+pub fn save_skill_entry_base64(
+    app: tauri::AppHandle,
+    request: SaveSkillEntryBase64Request,
+) -> Result<(), AppError> {
+    use base64::Engine;
+
+    // Reject oversized payloads before decoding them into memory; base64
+    // inflates by 4/3, so the decoded size is at most 3/4 of the input.
+    let state = load_state(&app)?;
+    check_write_size(request.content_base64.len() / 4 * 3, state.max_write_bytes)?;
+
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(request.content_base64.trim())
+        .map_err(|e| AppError::Validation(format!("Invalid base64 content: {e}")))?;
+    write_skill_entry(&app, &request.path, &request.relative_path, &bytes)
+}
+
+#[tauri::command]
+pub fn set_max_write_bytes(app: tauri::AppHandle, bytes: u64) -> Result<(), AppError> {
+    if bytes == 0 {
+        return Err(AppError::Validation(
+            "Maximum write size must be greater than zero".to_string(),
+        ));
+    }
+    if bytes > MAX_WRITE_BYTES_CEILING {
         return Err(AppError::Validation(format!(
-            "Path is not a file: {}",
-            relative_path
+            "Maximum write size can be at most {MAX_WRITE_BYTES_CEILING} bytes"
         )));
     }
-    trash::delete(&target).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
-    Ok(())
+    let mut state = load_state(&app)?;
+    state.max_write_bytes = bytes;
+    save_state(&app, &state)
+}
+
+#[tauri::command]
+pub fn set_max_skill_md_bytes(app: tauri::AppHandle, bytes: u64) -> Result<(), AppError> {
+    if bytes == 0 {
+        return Err(AppError::Validation(
+            "Maximum SKILL.md size must be greater than zero".to_string(),
+        ));
+    }
+    if bytes > MAX_WRITE_BYTES_CEILING {
+        return Err(AppError::Validation(format!(
+            "Maximum SKILL.md size can be at most {MAX_WRITE_BYTES_CEILING} bytes"
+        )));
+    }
+    let mut state = load_state(&app)?;
+    state.max_skill_md_bytes = bytes;
+    save_state(&app, &state)
+}
+
+/// Point registry search at `registry_url`; `None` or a blank URL goes
+/// back to skills.sh.
+#[tauri::command]
+pub fn set_registry_url(
+    app: tauri::AppHandle,
+    registry_url: Option<String>,
+) -> Result<(), AppError> {
+    let registry_url = match registry_url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        Some(url) => normalize_registry_url(url)?,
+        None => DEFAULT_REGISTRY_URL.to_string(),
+    };
+    let mut state = load_state(&app)?;
+    state.registry_url = registry_url;
+    save_state(&app, &state)
+}
+
+#[tauri::command]
+pub fn create_skill_dir(
+    app: tauri::AppHandle,
+    path: String,
+    relative_path: String,
+) -> Result<(), AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
+    if target.exists() && !target.is_dir() {
+        return Err(AppError::Validation(format!(
+            "Path exists and is not a directory: {}",
+            relative_path
+        )));
+    }
+    ensure_dir(&target)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn rename_skill_entry(
+    app: tauri::AppHandle,
+    path: String,
+    old_relative_path: String,
+    new_relative_path: String,
+) -> Result<(), AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    let old_target = resolve_skill_child_path(&skill_root, &old_relative_path)?;
+    let new_target = resolve_skill_child_path(&skill_root, &new_relative_path)?;
+
+    if !old_target.exists() {
+        return Err(AppError::NotFound(format!(
+            "Path does not exist: {}",
+            old_relative_path
+        )));
+    }
+    if new_target.exists() {
+        return Err(AppError::Validation(format!(
+            "Target path already exists: {}",
+            new_relative_path
+        )));
+    }
+    if let Some(parent) = new_target.parent() {
+        ensure_dir(parent)?;
+    }
+    fs::rename(old_target, new_target)?;
+    Ok(())
+}
+
+/// Rename a skill's folder to the slug of its frontmatter `name` (or of
+/// `fallback_name` when the frontmatter has none), e.g. to clean up
+/// `skillsyoga-1712345678`-style folders. The provenance sidecar moves with
+/// the folder; the pinned entry and the snapshots are carried over to the
+/// new path.
+#[tauri::command]
+pub fn clean_skill_folder_name(
+    app: tauri::AppHandle,
+    path: String,
+    fallback_name: Option<String>,
+) -> Result<SkillInfo, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    let skill_file = skill_manifest_path(&dir);
+    if !skill_file.is_file() {
+        return Err(AppError::Validation(format!(
+            "Source is not a valid skill (no SKILL.md): {path}"
+        )));
+    }
+    let tool = find_tool_for_path(&app, &dir)?;
+    let parent = dir
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Skill folder has no parent: {path}")))?;
+    if is_tool_root(&tool, &dir) {
+        return Err(AppError::InvalidPath(format!(
+            "Refusing to rename a skills root: {path}"
+        )));
+    }
+
+    let content = read_skill_text(&skill_file)?;
+    let name = frontmatter_name(&content)
+        .or_else(|| fallback_name.filter(|name| !name.trim().is_empty()))
+        .ok_or_else(|| {
+            AppError::Validation(
+                "Skill has no frontmatter name; provide a name to derive the folder from"
+                    .to_string(),
+            )
+        })?;
+    let slug = slugify(&name);
+    if dir_display_name(&dir) == slug {
+        return load_skill_info(&tool, &dir);
+    }
+
+    let snapshots = skill_snapshots_dir(&app, &dir)?;
+    let target = unique_dir(parent, &slug);
+    fs::rename(&dir, &target)?;
+    move_snapshots(&app, &snapshots, &target)?;
+    move_pins(&app, vec![(path, target.to_string_lossy().to_string())])?;
+    load_skill_info(&tool, &target)
+}
+
+/// Rename a skill: its folder becomes the slug of `new_name` (made unique
+/// within the same parent) and the frontmatter `name` is set to match,
+/// leaving the rest of SKILL.md as is. Pins and snapshots follow the folder.
+#[tauri::command]
+pub fn rename_skill(
+    app: tauri::AppHandle,
+    path: String,
+    new_name: String,
+) -> Result<SkillInfo, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    let manifest = skill_manifest_path(&dir);
+    if !manifest.is_file() {
+        return Err(AppError::Validation(format!(
+            "Source is not a valid skill (no SKILL.md): {path}"
+        )));
+    }
+    let tool = find_tool_for_path(&app, &dir)?;
+    let parent = dir
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Skill folder has no parent: {path}")))?;
+    if is_tool_root(&tool, &dir) {
+        return Err(AppError::InvalidPath(format!(
+            "Refusing to rename a skills root: {path}"
+        )));
+    }
+    is_path_under_skills_root(parent, &app)?;
+
+    if !new_name.chars().any(|c| c.is_ascii_alphanumeric()) {
+        return Err(AppError::Validation(
+            "New skill name must contain letters or digits".to_string(),
+        ));
+    }
+    let slug = slugify(&new_name);
+    let target = if dir_display_name(&dir) == slug {
+        dir.clone()
+    } else {
+        let snapshots = skill_snapshots_dir(&app, &dir)?;
+        let target = unique_dir(parent, &slug);
+        fs::rename(&dir, &target)?;
+        move_snapshots(&app, &snapshots, &target)?;
+        move_pins(&app, vec![(path, target.to_string_lossy().to_string())])?;
+        target
+    };
+
+    let target_manifest = target.join(manifest.file_name().unwrap_or_default());
+    let content = read_skill_text(&target_manifest)?;
+    let renamed = set_frontmatter_field(&content, "name", Some(&dir_display_name(&target)));
+    if renamed != content {
+        write_file_atomic(&target_manifest, renamed.as_bytes())?;
+    }
+    load_skill_info(&tool, &target)
+}
+
+#[tauri::command]
+pub fn delete_skill_entry(
+    app: tauri::AppHandle,
+    path: String,
+    relative_path: String,
+) -> Result<(), AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
+    if !target.exists() {
+        return Err(AppError::NotFound(format!(
+            "Path does not exist: {}",
+            relative_path
+        )));
+    }
+    if !target.is_file() {
+        return Err(AppError::Validation(format!(
+            "Path is not a file: {}",
+            relative_path
+        )));
+    }
+    trash::delete(&target).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_skill_empty_dir(
+    app: tauri::AppHandle,
+    path: String,
+    relative_path: String,
+) -> Result<(), AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+
+    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
+    if !target.exists() {
+        return Err(AppError::NotFound(format!(
+            "Path does not exist: {}",
+            relative_path
+        )));
+    }
+    if !target.is_dir() {
+        return Err(AppError::Validation(format!(
+            "Path is not a directory: {}",
+            relative_path
+        )));
+    }
+    let mut iter = fs::read_dir(&target)?;
+    if iter.next().is_some() {
+        return Err(AppError::Validation(format!(
+            "Directory is not empty: {}",
+            relative_path
+        )));
+    }
+    trash::delete(&target).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
+    Ok(())
+}
+
+/// Folder name a new skill called `name` would be saved under (before any
+/// `-1`/`-2` suffix for collisions). Uses the same `slugify` as the save and
+/// install paths so the preview can't drift from what actually happens.
+#[tauri::command]
+pub fn preview_slug(name: String) -> Result<String, AppError> {
+    Ok(slugify(&name))
+}
+
+#[tauri::command]
+pub fn save_skill_file(
+    app: tauri::AppHandle,
+    request: SaveSkillRequest,
+) -> Result<SkillInfo, AppError> {
+    let tool = find_tool_by_id(&app, &request.target_tool_id)?;
+    let state = load_state(&app)?;
+    check_skill_md_size(request.content.len(), state.max_skill_md_bytes)?;
+    let skills_root = PathBuf::from(&tool.skills_path);
+    ensure_dir(&skills_root)?;
+
+    // Parse name/description from the content's frontmatter
+    let meta = parse_skill_metadata(&request.content, "skill");
+
+    let target_dir = if let Some(existing) = request.existing_path {
+        let p = PathBuf::from(&existing);
+        is_path_under_skills_root(&p, &app)?;
+        p
+    } else {
+        // Only an explicit name may pick the folder; `meta.name` can fall
+        // back to arbitrary heading text when frontmatter is missing.
+        let folder_name = frontmatter_name(&request.content)
+            .or_else(|| request.folder_name.filter(|name| !name.trim().is_empty()))
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "SKILL.md has no frontmatter name; provide a folder name".to_string(),
+                )
+            })?;
+        unique_dir(&skills_root, &slugify(&folder_name))
+    };
+
+    ensure_dir(&target_dir)?;
+
+    // Editing a disabled skill keeps it disabled.
+    let skill_file = skill_manifest_path(&target_dir);
+    write_file_atomic(&skill_file, request.content.as_bytes())?;
+
+    Ok(SkillInfo {
+        id: skill_id(&tool, &target_dir),
+        name: meta.name,
+        description: meta.description,
+        path: target_dir.to_string_lossy().to_string(),
+        source: tool.id.clone(),
+        enabled_for: vec![tool.id],
+        updated_at: now_iso(),
+        github_repo_url: None,
+        github_skill_path: None,
+        pinned: false,
+        tags: meta.tags,
+        license: meta.license,
+        version: meta.version,
+        author: meta.author,
+        enabled: !skill_file.ends_with(DISABLED_SKILL_MD),
+        skill_md_bytes: request.content.len() as u64,
+        conflicts: vec![],
+    })
+}
+
+/// Snapshots kept per skill; `snapshot_skill` drops the oldest beyond this.
+const MAX_SKILL_SNAPSHOTS: usize = 10;
+
+/// Folder under app data holding a skill's snapshots, keyed by the skill's
+/// canonical path (see `stable_path_key`).
+fn skill_snapshots_dir(app: &tauri::AppHandle, skill_dir: &Path) -> Result<PathBuf, AppError> {
+    let canonical = skill_dir.canonicalize()?;
+    Ok(app_data_dir(app)?
+        .join("skill-snapshots")
+        .join(stable_path_key(&canonical.to_string_lossy())))
+}
+
+/// Carry a renamed skill's snapshots over to the key of its new folder.
+/// `old_snapshots` is `skill_snapshots_dir` of the skill before the rename.
+fn move_snapshots(
+    app: &tauri::AppHandle,
+    old_snapshots: &Path,
+    new_dir: &Path,
+) -> Result<(), AppError> {
+    let new_snapshots = skill_snapshots_dir(app, new_dir)?;
+    if old_snapshots.is_dir() && !new_snapshots.exists() {
+        fs::rename(old_snapshots, new_snapshots)?;
+    }
+    Ok(())
+}
+
+/// Zip the skill folder into its snapshots area and return the snapshot
+/// id. Ids are zero-padded unix milliseconds, so they sort by age.
+#[tauri::command]
+pub fn snapshot_skill(app: tauri::AppHandle, path: String) -> Result<String, AppError> {
+    let skill_dir = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_dir, &app)?;
+    if !skill_dir.is_dir() {
+        return Err(AppError::NotFound(format!("Skill path does not exist: {path}")));
+    }
+
+    let snapshots_dir = skill_snapshots_dir(&app, &skill_dir)?;
+    ensure_dir(&snapshots_dir)?;
+    let mut millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    while snapshots_dir.join(format!("{millis:013}.zip")).exists() {
+        millis += 1;
+    }
+    let id = format!("{millis:013}");
+
+    let staging = snapshots_dir.join(format!(".{id}.partial"));
+    let result = zip_dir(&skill_dir, &staging, "", &SkillIgnore::default())
+        .and_then(|()| fs::rename(&staging, snapshots_dir.join(format!("{id}.zip"))));
+    if let Err(err) = result {
+        let _ = fs::remove_file(&staging);
+        return Err(err.into());
+    }
+
+    let snapshots = list_snapshot_ids(&snapshots_dir)?;
+    for old in snapshots.iter().skip(MAX_SKILL_SNAPSHOTS) {
+        let _ = fs::remove_file(snapshots_dir.join(format!("{old}.zip")));
+    }
+    Ok(id)
+}
+
+/// Snapshot ids in `snapshots_dir`, newest first.
+fn list_snapshot_ids(snapshots_dir: &Path) -> Result<Vec<String>, AppError> {
+    let Ok(entries) = fs::read_dir(snapshots_dir) else {
+        return Ok(vec![]);
+    };
+    let mut ids: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let id = name.strip_suffix(".zip")?;
+            id.chars().all(|c| c.is_ascii_digit()).then(|| id.to_string())
+        })
+        .collect();
+    ids.sort_by(|a, b| b.cmp(a));
+    Ok(ids)
+}
+
+#[tauri::command]
+pub fn list_skill_snapshots(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<Vec<SkillSnapshot>, AppError> {
+    let skill_dir = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_dir, &app)?;
+    let snapshots_dir = skill_snapshots_dir(&app, &skill_dir)?;
+
+    Ok(list_snapshot_ids(&snapshots_dir)?
+        .into_iter()
+        .map(|id| {
+            let size_bytes = fs::metadata(snapshots_dir.join(format!("{id}.zip")))
+                .map(|meta| meta.len())
+                .unwrap_or(0);
+            let created_ms = id.parse().unwrap_or(0);
+            let created_at = iso_timestamp(UNIX_EPOCH + Duration::from_millis(created_ms));
+            SkillSnapshot {
+                id,
+                created_at,
+                size_bytes,
+            }
+        })
+        .collect())
+}
+
+/// Replace the skill folder's contents with a snapshot. The snapshot is
+/// extracted next to the skill and swapped in for the current folder,
+/// which is put back if the swap fails and trashed once it succeeded.
+#[tauri::command]
+pub fn restore_skill_snapshot(
+    app: tauri::AppHandle,
+    path: String,
+    snapshot_id: String,
+) -> Result<(), AppError> {
+    let skill_dir = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_dir, &app)?;
+    if snapshot_id.is_empty() || !snapshot_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AppError::Validation(format!("Invalid snapshot id: {snapshot_id}")));
+    }
+    let archive = skill_snapshots_dir(&app, &skill_dir)?.join(format!("{snapshot_id}.zip"));
+    if !archive.is_file() {
+        return Err(AppError::NotFound(format!("Snapshot not found: {snapshot_id}")));
+    }
+    let tool = find_tool_for_path(&app, &skill_dir)?;
+    if is_tool_root(&tool, &skill_dir) {
+        return Err(AppError::InvalidPath(format!(
+            "Refusing to restore over a skills root: {path}"
+        )));
+    }
+
+    let parent = skill_dir
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid path: {path}")))?;
+    let name = dir_display_name(&skill_dir);
+    let pid = std::process::id();
+    let staging = parent.join(format!(".{name}.restore-{pid}"));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    let result = unzip_into(&archive, &staging)
+        .map_err(AppError::from)
+        .and_then(|()| swap_in_dir(&staging, &skill_dir));
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+/// Replace the folder `live` with `staging`, a finished sibling of it. The
+/// live folder is moved aside first and put back if the swap fails. Once
+/// the swap is done, the old folder is trashed, or removed outright if the
+/// trash is unavailable rather than left as a hidden folder.
+fn swap_in_dir(staging: &Path, live: &Path) -> Result<(), AppError> {
+    let parent = live
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid path: {}", live.display())))?;
+    let aside = parent.join(format!(
+        ".{}.replaced-{}",
+        dir_display_name(live),
+        std::process::id()
+    ));
+    if fs::symlink_metadata(&aside).is_ok() {
+        fs::remove_dir_all(&aside)?;
+    }
+    fs::rename(live, &aside)?;
+    if let Err(err) = fs::rename(staging, live) {
+        let _ = fs::rename(&aside, live);
+        return Err(err.into());
+    }
+    if trash_path(&aside).is_err() {
+        fs::remove_dir_all(&aside)?;
+    }
+    Ok(())
+}
+
+fn trash_path(path: &Path) -> Result<(), AppError> {
+    trash::delete(path).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))
+}
+
+#[tauri::command]
+pub fn delete_skill(app: tauri::AppHandle, path: String) -> Result<(), AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    if dir.exists() {
+        trash_path(&dir)?;
+    }
+
+    let mut state = load_state(&app)?;
+    if state.pinned_skills.remove(&path) {
+        save_state(&app, &state)?;
+    }
+    Ok(())
+}
+
+/// Move several skills to the trash. Each path is validated and trashed on
+/// its own, so one bad path is reported in `failed` instead of aborting the
+/// rest. `confirm` must be set; skills roots themselves are never deleted.
+#[tauri::command]
+pub fn delete_skills(
+    app: tauri::AppHandle,
+    skill_paths: Vec<String>,
+    confirm: bool,
+) -> Result<DeleteReport, AppError> {
+    if !confirm {
+        return Err(AppError::Validation(
+            "Bulk delete requires confirmation".to_string(),
+        ));
+    }
+
+    let roots: Vec<PathBuf> = resolve_tools(&app)?
+        .iter()
+        .flat_map(|tool| &tool.skills_paths)
+        .filter_map(|root| Path::new(root).canonicalize().ok())
+        .collect();
+    let delete_one = |path: &str| -> Result<(), AppError> {
+        let dir = PathBuf::from(path);
+        is_path_under_skills_root(&dir, &app)?;
+        if dir.canonicalize().is_ok_and(|canonical| roots.contains(&canonical)) {
+            return Err(AppError::InvalidPath(format!(
+                "Refusing to delete a skills root: {path}"
+            )));
+        }
+        trash_path(&dir)
+    };
+
+    let mut report = DeleteReport::default();
+    for path in skill_paths {
+        match delete_one(&path) {
+            Ok(()) => report.deleted.push(path),
+            Err(err) => report.failed.push(PathFailure {
+                path,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    let mut state = load_state(&app)?;
+    let before = state.pinned_skills.len();
+    for path in &report.deleted {
+        state.pinned_skills.remove(path);
+    }
+    if state.pinned_skills.len() != before {
+        save_state(&app, &state)?;
+    }
+    Ok(report)
+}
+
+/// Copy one skill into `target_root` under its folder name (suffixed via
+/// `unique_dir` on conflict), check the copy against the source SKILL.md,
+/// and only then trash the original. Returns `None` when the skill is
+/// already under `target_root`. Skills roots, and skills holding
+/// `target_root`, are refused.
+fn move_skill_dir(
+    app: &tauri::AppHandle,
+    target_tool: &ToolInfo,
+    target_root: &Path,
+    path: &str,
+) -> Result<Option<(PathBuf, SkillInfo)>, AppError> {
+    let source_dir = PathBuf::from(path);
+    is_path_under_skills_root(&source_dir, app)?;
+    let source_tool = find_tool_for_path(app, &source_dir)?;
+    if is_tool_root(&source_tool, &source_dir) {
+        return Err(AppError::InvalidPath(format!(
+            "Refusing to move a skills root: {path}"
+        )));
+    }
+    let canonical_source = source_dir.canonicalize()?;
+    if canonical_source.starts_with(target_root) {
+        return Ok(None);
+    }
+    if target_root.starts_with(&canonical_source) {
+        return Err(AppError::InvalidPath(format!(
+            "Target skills root is inside the skill being moved: {path}"
+        )));
+    }
+    let source_skill_file = skill_manifest_path(&source_dir);
+    if !source_skill_file.is_file() {
+        return Err(AppError::Validation(format!(
+            "Not a valid skill (no SKILL.md): {path}"
+        )));
+    }
+
+    let target_dir = unique_dir(target_root, &dir_display_name(&source_dir));
+    copy_dir_atomic(&source_dir, &target_dir, &SkillIgnore::default())?;
+    if fs::read(skill_manifest_path(&target_dir))? != fs::read(&source_skill_file)? {
+        trash_path(&target_dir)?;
+        return Err(AppError::Validation(format!(
+            "Copied SKILL.md does not match the original: {path}"
+        )));
+    }
+    trash_path(&source_dir)?;
+    Ok(Some((target_dir.clone(), load_skill_info(target_tool, &target_dir)?)))
+}
+
+/// Carry pins over from moved skills' old paths to their new ones.
+fn move_pins(app: &tauri::AppHandle, moves: Vec<(String, String)>) -> Result<(), AppError> {
+    let mut state = load_state(app)?;
+    let mut pins_changed = false;
+    for (old, new) in moves {
+        if state.pinned_skills.remove(&old) {
+            state.pinned_skills.insert(new);
+            pins_changed = true;
+        }
+    }
+    if pins_changed {
+        save_state(app, &state)?;
+    }
+    Ok(())
+}
+
+/// Move one skill into another tool's skills root, the same way
+/// `move_skills` does: the source is only trashed once the copy is complete
+/// and verified.
+#[tauri::command]
+pub fn move_skill(
+    app: tauri::AppHandle,
+    source_path: String,
+    target_tool_id: String,
+) -> Result<SkillInfo, AppError> {
+    let target_tool = find_tool_by_id(&app, &target_tool_id)?;
+    let target_root = PathBuf::from(&target_tool.skills_path);
+    ensure_dir(&target_root)?;
+    let target_root = target_root.canonicalize()?;
+
+    let Some((target_dir, skill)) = move_skill_dir(&app, &target_tool, &target_root, &source_path)?
+    else {
+        return Err(AppError::Validation(format!(
+            "Skill is already in {}: {source_path}",
+            target_tool.name
+        )));
+    };
+    move_pins(&app, vec![(source_path, target_dir.to_string_lossy().to_string())])?;
+    Ok(skill)
+}
+
+/// Fork a skill into a new folder next to it. The folder is the slug of
+/// `new_name`, or the original name's slug plus `-copy`, made unique; only
+/// the `name:` line of the copy's frontmatter changes, to that slug. The
+/// copy is a new skill, so its source sidecar is dropped.
+#[tauri::command]
+pub fn duplicate_skill(
+    app: tauri::AppHandle,
+    source_path: String,
+    new_name: Option<String>,
+) -> Result<SkillInfo, AppError> {
+    let source_dir = PathBuf::from(&source_path);
+    is_path_under_skills_root(&source_dir, &app)?;
+    let source_manifest = skill_manifest_path(&source_dir);
+    if !source_manifest.is_file() {
+        return Err(AppError::NotFound(format!(
+            "SKILL.md not found in {source_path}"
+        )));
+    }
+    let tool = find_tool_for_path(&app, &source_dir)?;
+    // A copy of a root skill would land inside the folder being copied.
+    if is_tool_root(&tool, &source_dir) {
+        return Err(AppError::InvalidPath(format!(
+            "Refusing to duplicate a skills root: {source_path}"
+        )));
+    }
+    let parent = source_dir
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid path: {source_path}")))?;
+
+    let content = read_skill_text(&source_manifest)?;
+    let meta = parse_skill_metadata(&content, &dir_display_name(&source_dir));
+    let slug = match new_name.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        Some(name) if !name.chars().any(|c| c.is_ascii_alphanumeric()) => {
+            return Err(AppError::Validation(
+                "New skill name must contain letters or digits".to_string(),
+            ));
+        }
+        Some(name) => slugify(name),
+        None => format!("{}-copy", slugify(&meta.name)),
+    };
+
+    let target_dir = unique_dir(parent, &slug);
+    copy_dir_atomic(&source_dir, &target_dir, &SkillIgnore::standard())?;
+    let sidecar = target_dir.join(SOURCE_META_FILE);
+    if sidecar.exists() {
+        fs::remove_file(sidecar)?;
+    }
+    let renamed = set_frontmatter_field(&content, "name", Some(&slug));
+    let target_manifest = target_dir.join(source_manifest.file_name().unwrap_or_default());
+    write_file_atomic(&target_manifest, renamed.as_bytes())?;
+    load_skill_info(&tool, &target_dir)
+}
+
+/// Move a batch of skills into another tool's skills root with
+/// `move_skill_dir`. Sources already under the target root are skipped.
+/// Failures don't stop the batch; they are reported next to the skills
+/// that did move.
+#[tauri::command]
+pub fn move_skills(
+    app: tauri::AppHandle,
+    source_paths: Vec<String>,
+    target_tool_id: String,
+) -> Result<MoveReport, AppError> {
+    let target_tool = find_tool_by_id(&app, &target_tool_id)?;
+    let target_root = PathBuf::from(&target_tool.skills_path);
+    ensure_dir(&target_root)?;
+    let target_root = target_root.canonicalize()?;
+
+    let mut report = MoveReport::default();
+    let mut renamed_pins = vec![];
+    for path in &source_paths {
+        match move_skill_dir(&app, &target_tool, &target_root, path) {
+            Ok(Some((target_dir, skill))) => {
+                renamed_pins.push((path.clone(), target_dir.to_string_lossy().to_string()));
+                report.moved.push(skill);
+            }
+            Ok(None) => {}
+            Err(err) => report.failed.push(PathFailure {
+                path: path.clone(),
+                error: err.to_string(),
+            }),
+        }
+    }
+    move_pins(&app, renamed_pins)?;
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn set_skill_pinned(
+    app: tauri::AppHandle,
+    skill_path: String,
+    pinned: bool,
+) -> Result<(), AppError> {
+    let dir = PathBuf::from(&skill_path);
+    is_path_under_skills_root(&dir, &app)?;
+
+    let mut state = load_state(&app)?;
+    if pinned {
+        state.pinned_skills.insert(skill_path);
+    } else {
+        state.pinned_skills.remove(&skill_path);
+    }
+    save_state(&app, &state)
+}
+
+/// Enable or disable one installed skill for the tool it lives under,
+/// renaming its SKILL.md to `SKILL.md.disabled` so the agent stops loading
+/// it. Copies of the skill installed for other tools are not affected.
+#[tauri::command]
+pub fn set_skill_enabled(
+    app: tauri::AppHandle,
+    skill_path: String,
+    enabled: bool,
+) -> Result<SkillInfo, AppError> {
+    let dir = PathBuf::from(&skill_path);
+    is_path_under_skills_root(&dir, &app)?;
+    let tool = find_tool_for_path(&app, &dir)?;
+
+    set_manifest_enabled(&dir, enabled)?;
+    let skill = load_skill_info(&tool, &dir)?;
+
+    let mut state = load_state(&app)?;
+    let changed = if enabled {
+        state.disabled_skills.remove(&skill.id)
+    } else {
+        state.disabled_skills.insert(skill.id.clone())
+    };
+    if changed {
+        save_state(&app, &state)?;
+    }
+    Ok(skill)
+}
+
+#[tauri::command]
+pub fn upsert_custom_tool(
+    app: tauri::AppHandle,
+    tool: CustomToolInput,
+) -> Result<DashboardData, AppError> {
+    let mut state = load_state(&app)?;
+    store_custom_tool(&mut state, tool)?;
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+/// Validate `tool` and add it to `state`, replacing a custom tool with the
+/// same id.
+fn store_custom_tool(state: &mut AppState, tool: CustomToolInput) -> Result<(), AppError> {
+    let next_id = slugify(&tool.id);
+    if built_in_tools().iter().any(|builtin| builtin.id == next_id) {
+        return Err(AppError::Validation(
+            "Custom tool id conflicts with a built-in integration".to_string(),
+        ));
+    }
+
+    let skills_path: Vec<String> = tool
+        .skills_path
+        .iter()
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .collect();
+    if skills_path.is_empty() {
+        return Err(AppError::Validation(
+            "Custom tool needs at least one skills path".to_string(),
+        ));
+    }
+    // Relative paths are project-local (see `resolve_skills_paths`). New
+    // skills go to the first absolute one, so the tool needs one.
+    for path in &skills_path {
+        let path = expand_home(path)?;
+        if !path.is_absolute() && path.components().any(|part| part == Component::ParentDir) {
+            return Err(AppError::Validation(format!(
+                "Project-local skills path must stay inside the project: {}",
+                path.display()
+            )));
+        }
+    }
+    if !skills_path.iter().any(|path| expand_home(path).is_ok_and(|path| path.is_absolute())) {
+        return Err(AppError::Validation(
+            "Custom tool needs an absolute or ~ skills path to install new skills into"
+                .to_string(),
+        ));
+    }
+
+    // A root shared with, or nested in, another tool's would be scanned,
+    // and counted, twice.
+    for root in resolve_skills_paths(&skills_path, &state.project_roots)? {
+        let root = root.canonicalize().unwrap_or(root);
+        if let Some(other) = tools_overlapping_root(state, &root, &next_id)?.first() {
+            return Err(AppError::Validation(format!(
+                "Skills path {} overlaps a skills path of {other}",
+                root.display()
+            )));
+        }
+    }
+
+    let clean_tool = CustomToolInput {
+        id: next_id,
+        name: tool.name,
+        config_path: tool.config_path,
+        skills_path,
+        cli: tool.cli,
+    };
+
+    if let Some(existing) = state
+        .custom_tools
+        .iter_mut()
+        .find(|t| t.id == clean_tool.id)
+    {
+        *existing = clean_tool;
+    } else {
+        state.custom_tools.push(clean_tool);
+    }
+    Ok(())
+}
+
+/// Expand `~` and resolve symlinks where the path exists, for comparing roots.
+fn resolve_root(path: &str) -> Result<PathBuf, AppError> {
+    let path = expand_home(path)?;
+    Ok(path.canonicalize().unwrap_or(path))
+}
+
+/// Check a custom tool's paths before saving it: whether they exist, how
+/// many skills the skills path holds, and which tools' roots overlap it.
+/// `tool_id` is the custom tool being edited, which is not counted.
+#[tauri::command]
+pub fn probe_custom_tool(
+    app: tauri::AppHandle,
+    config_path: String,
+    skills_path: String,
+    tool_id: Option<String>,
+) -> Result<CustomToolProbe, AppError> {
+    let state = load_state(&app)?;
+    let except_id = tool_id.map(|id| slugify(&id)).unwrap_or_default();
+    let config = expand_home(config_path.trim())?;
+    let skills = expand_home(skills_path.trim())?;
+    let skills_is_dir = skills.is_dir();
+
+    Ok(CustomToolProbe {
+        config_path: config.to_string_lossy().to_string(),
+        config_exists: config.exists(),
+        skills_path: skills.to_string_lossy().to_string(),
+        skills_exists: skills.exists(),
+        skills_is_dir,
+        skill_count: if skills_is_dir { count_skills_in_root(&skills) } else { 0 },
+        overlapping_tools: tools_overlapping_root(
+            &state,
+            &resolve_root(skills_path.trim())?,
+            &except_id,
+        )?,
+    })
+}
+
+/// Turn a root found by `discover_skills_paths` into a custom tool. The
+/// config path is the root's parent when the root is a `skills` folder
+/// (`~/.newtool/skills` -> `~/.newtool`), otherwise the root itself. An
+/// empty `name` is derived from the config folder.
+#[tauri::command]
+pub fn create_tool_from_discovered_root(
+    app: tauri::AppHandle,
+    path: String,
+    name: String,
+) -> Result<DashboardData, AppError> {
+    let root = expand_home(path.trim())?;
+    if !root.is_dir() {
+        return Err(AppError::NotFound(format!(
+            "Skills folder does not exist: {}",
+            root.display()
+        )));
+    }
+    let root = root.canonicalize()?;
+    for tool in resolve_tools(&app)? {
+        let covered = tool.skills_paths.iter().any(|existing| {
+            Path::new(existing)
+                .canonicalize()
+                .is_ok_and(|existing| root.starts_with(existing))
+        });
+        if covered {
+            return Err(AppError::Validation(format!(
+                "{} is already managed by {}",
+                root.display(),
+                tool.name
+            )));
+        }
+    }
+
+    let config = match (root.file_name(), root.parent()) {
+        (Some(leaf), Some(parent)) if leaf.eq_ignore_ascii_case("skills") => parent.to_path_buf(),
+        _ => root.clone(),
+    };
+    let name = match name.trim() {
+        "" => dir_display_name(&config).trim_start_matches('.').to_string(),
+        name => name.to_string(),
+    };
+    if !name.chars().any(|c| c.is_ascii_alphanumeric()) {
+        return Err(AppError::Validation(
+            "Tool name must contain at least one letter or digit".to_string(),
+        ));
+    }
+
+    let mut state = load_state(&app)?;
+    let id = slugify(&name);
+    if state.custom_tools.iter().any(|tool| tool.id == id) {
+        return Err(AppError::Validation(format!(
+            "A custom tool with id {id} already exists"
+        )));
+    }
+    store_custom_tool(
+        &mut state,
+        CustomToolInput {
+            id,
+            name,
+            config_path: config.to_string_lossy().to_string(),
+            skills_path: vec![root.to_string_lossy().to_string()],
+            cli: false,
+        },
+    )?;
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+#[tauri::command]
+pub fn discover_skills_paths(scan_root: String) -> Result<Vec<DiscoveredSkillsRoot>, AppError> {
+    let expanded = crate::helpers::expand_home(scan_root.trim())?;
+    if !expanded.exists() {
+        return Err(AppError::NotFound(format!(
+            "Scan path does not exist: {}",
+            expanded.display()
+        )));
+    }
+    if !expanded.is_dir() {
+        return Err(AppError::Validation(format!(
+            "Scan path is not a directory: {}",
+            expanded.display()
+        )));
+    }
+
+    Ok(discover_skills_roots(&expanded))
+}
+
+/// Probe the current HOME and other conventional home directories for the
+/// skills paths of every known tool, reporting each root that exists.
+#[tauri::command]
+pub fn scan_common_home_locations(
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredSkillsRoot>, AppError> {
+    let state = load_state(&app)?;
+    let suffixes: Vec<String> = built_in_tools()
+        .iter()
+        .chain(state.custom_tools.iter())
+        .flat_map(|tool| &tool.skills_path)
+        .filter_map(|path| path.strip_prefix("~/").map(|s| s.to_string()))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut out = vec![];
+    for home in candidate_home_dirs() {
+        for suffix in &suffixes {
+            let root = home.join(suffix);
+            if !root.is_dir() || !seen.insert(root.clone()) {
+                continue;
+            }
+            out.push(DiscoveredSkillsRoot {
+                path: root.to_string_lossy().to_string(),
+                skill_count: count_skills_in_root(&root),
+            });
+        }
+    }
+
+    out.sort_by(|a, b| {
+        b.skill_count
+            .cmp(&a.skill_count)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    Ok(out)
+}
+
+#[tauri::command]
+pub fn delete_custom_tool(
+    app: tauri::AppHandle,
+    tool_id: String,
+) -> Result<DashboardData, AppError> {
+    let mut state = load_state(&app)?;
+    state.custom_tools.retain(|t| t.id != tool_id);
+    state.tool_toggles.remove(&tool_id);
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+/// Register a project directory so the skills roots inside it (such as
+/// `.claude/skills`) are listed as one `project` tool. Adding a project
+/// again re-discovers its roots.
+#[tauri::command]
+pub fn add_project_root(app: tauri::AppHandle, path: String) -> Result<DashboardData, AppError> {
+    let dir = expand_home(path.trim())?;
+    if !dir.is_dir() {
+        return Err(AppError::NotFound(format!(
+            "Project folder does not exist: {}",
+            dir.display()
+        )));
+    }
+    let dir = dir.canonicalize()?;
+    let dir_string = dir.to_string_lossy().to_string();
+
+    let mut state = load_state(&app)?;
+    let suffixes: Vec<String> = built_in_tools()
+        .iter()
+        .chain(state.custom_tools.iter())
+        .flat_map(|tool| &tool.skills_path)
+        .filter_map(|path| path.strip_prefix("~/").map(|s| s.to_string()))
+        .collect();
+    let skills_paths: Vec<String> = project_skill_roots(&dir, &suffixes)
+        .iter()
+        .map(|root| root.to_string_lossy().to_string())
+        .collect();
+    if skills_paths.is_empty() {
+        return Err(AppError::NotFound(format!(
+            "No skills folders found under {dir_string}"
+        )));
+    }
+
+    if let Some(existing) = state.project_roots.iter_mut().find(|p| p.path == dir_string) {
+        existing.skills_paths = skills_paths;
+    } else {
+        let name = dir_display_name(&dir);
+        let base = format!("project-{}", slugify(&name));
+        let mut id = base.clone();
+        let mut n = 2;
+        while state.project_roots.iter().any(|p| p.id == id) {
+            id = format!("{base}-{n}");
+            n += 1;
+        }
+        state.project_roots.push(ProjectRoot {
+            id,
+            name,
+            path: dir_string,
+            skills_paths,
+        });
+    }
+
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+/// Stop listing a project added with `add_project_root`. Its files are left
+/// alone.
+#[tauri::command]
+pub fn remove_project_root(
+    app: tauri::AppHandle,
+    project_id: String,
+) -> Result<DashboardData, AppError> {
+    let mut state = load_state(&app)?;
+    state.project_roots.retain(|p| p.id != project_id);
+    state.tool_toggles.remove(&project_id);
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+/// Locate the skill inside a fresh clone. A user-supplied `skill_path` goes
+/// through the same no-traversal normalization as skill entry paths and
+/// must still resolve inside the clone (symlinks included); without one the
+/// first SKILL.md found is used.
+fn resolve_cloned_skill_dir(clone_root: &Path, skill_path: Option<&str>) -> Result<PathBuf, AppError> {
+    let requested = skill_path.map(str::trim).filter(|p| !p.is_empty() && *p != ".");
+    let Some(requested) = requested else {
+        return discover_skill_dir(clone_root, 0).ok_or_else(|| {
+            AppError::NotFound(
+                "Unable to determine skill directory automatically; provide `skillPath`"
+                    .to_string(),
+            )
+        });
+    };
+
+    let source_dir = clone_root.join(normalize_relative_path(requested)?);
+    if let (Ok(resolved), Ok(root)) = (source_dir.canonicalize(), clone_root.canonicalize()) {
+        if !resolved.starts_with(&root) {
+            return Err(AppError::InvalidPath(format!(
+                "Skill path escapes the repository: {requested}"
+            )));
+        }
+    }
+    Ok(source_dir)
+}
+
+/// Trimmed stdout of a git command run in `repo`, or `None` if git isn't
+/// available or the command fails.
+fn git_output(repo: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !text.is_empty()).then_some(text)
 }
 
+/// Rebuild a missing provenance sidecar for a skill that sits in a git
+/// checkout (the skill folder itself or an ancestor within its skills
+/// root), using the `origin` remote and current commit. An existing sidecar
+/// is returned as-is. Returns `None` when there is no git metadata or the
+/// remote isn't GitHub.
 #[tauri::command]
-pub fn delete_skill_empty_dir(
+pub fn recover_provenance(
     app: tauri::AppHandle,
     path: String,
-    relative_path: String,
-) -> Result<(), AppError> {
-    let skill_root = PathBuf::from(&path);
-    is_path_under_skills_root(&skill_root, &app)?;
-
-    let target = resolve_skill_child_path(&skill_root, &relative_path)?;
-    if !target.exists() {
-        return Err(AppError::NotFound(format!(
-            "Path does not exist: {}",
-            relative_path
-        )));
-    }
-    if !target.is_dir() {
-        return Err(AppError::Validation(format!(
-            "Path is not a directory: {}",
-            relative_path
-        )));
+) -> Result<Option<ProvenanceInfo>, AppError> {
+    let dir = PathBuf::from(&path);
+    is_path_under_skills_root(&dir, &app)?;
+    if let Some(existing) = read_provenance(&dir) {
+        return Ok(Some(existing));
     }
-    let mut iter = fs::read_dir(&target)?;
-    if iter.next().is_some() {
-        return Err(AppError::Validation(format!(
-            "Directory is not empty: {}",
-            relative_path
-        )));
+
+    let tool = find_tool_for_path(&app, &dir)?;
+    let dir = dir.canonicalize()?;
+    let Some(root) = tool
+        .skills_paths
+        .iter()
+        .map(|root| {
+            let root = PathBuf::from(root);
+            root.canonicalize().unwrap_or(root)
+        })
+        .filter(|root| dir.starts_with(root))
+        .max_by_key(|root| root.components().count())
+    else {
+        return Ok(None);
+    };
+    let Some(repo) = dir
+        .ancestors()
+        .take_while(|ancestor| ancestor.starts_with(&root))
+        .find(|ancestor| ancestor.join(".git").exists())
+    else {
+        return Ok(None);
+    };
+
+    let Some(repo_url) = git_output(repo, &["config", "--get", "remote.origin.url"])
+        .and_then(|remote| github_https_url(&remote))
+    else {
+        return Ok(None);
+    };
+    let commit = git_output(repo, &["rev-parse", "HEAD"]);
+    let skill_path = dir
+        .strip_prefix(repo)
+        .ok()
+        .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+        .filter(|rel| !rel.is_empty());
+
+    write_skill_source_meta(&dir, &repo_url, skill_path.as_deref(), commit.as_deref())?;
+    Ok(read_provenance(&dir))
+}
+
+/// The tool an install should target: the explicit id when given,
+/// otherwise the configured default install tool.
+fn resolve_install_tool(app: &tauri::AppHandle, tool_id: Option<&str>) -> Result<ToolInfo, AppError> {
+    let explicit = tool_id.map(str::trim).filter(|id| !id.is_empty());
+    if let Some(id) = explicit {
+        return find_tool_by_id(app, id);
     }
-    trash::delete(&target).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
-    Ok(())
+    let state = load_state(app)?;
+    let default_id = state.default_install_tool_id.ok_or_else(|| {
+        AppError::Validation(
+            "No target tool selected and no default install tool is set".to_string(),
+        )
+    })?;
+    find_tool_by_id(app, &default_id)
+}
+
+/// The GitHub token saved in Settings, if any.
+fn stored_github_token(app: &tauri::AppHandle) -> Result<Option<String>, AppError> {
+    load_github_token(app)
 }
 
 #[tauri::command]
-pub fn save_skill_file(
+pub fn install_skill_from_github(
     app: tauri::AppHandle,
-    request: SaveSkillRequest,
+    request: InstallSkillRequest,
 ) -> Result<SkillInfo, AppError> {
-    let tool = find_tool_by_id(&app, &request.target_tool_id)?;
+    let repo_url = request.repo_url.trim().to_string();
+    if !repo_url.starts_with("https://github.com/") {
+        return Err(AppError::Validation(
+            "Only GitHub repository URLs are supported".to_string(),
+        ));
+    }
+
+    let tool = resolve_install_tool(&app, request.target_tool_id.as_deref())?;
     let skills_root = PathBuf::from(&tool.skills_path);
     ensure_dir(&skills_root)?;
 
-    // Parse name/description from the content's frontmatter
-    let meta = parse_skill_metadata(&request.content, "skill");
+    let temp = TempDir::new("skillsyoga-install")?;
+    let temp_root = temp.path();
 
-    let target_dir = if let Some(existing) = request.existing_path {
-        let p = PathBuf::from(&existing);
-        is_path_under_skills_root(&p, &app)?;
-        p
-    } else {
-        unique_dir(&skills_root, &slugify(&meta.name))
+    let token = stored_github_token(&app)?;
+    git_clone_shallow(
+        &repo_url,
+        temp_root,
+        GIT_CLONE_TIMEOUT,
+        token.as_deref(),
+        request.git_ref.as_deref(),
+    )?;
+
+    let source_dir = resolve_cloned_skill_dir(temp_root, request.skill_path.as_deref())?;
+    install_cloned_skill(&tool, temp_root, &source_dir, &repo_url)
+}
+
+/// Like `install_skill_from_github`, but without a `skill_path` every skill
+/// found in the repository is installed, each into its own folder, so a
+/// repo of many skills is picked up in one go. A skill that fails to
+/// install is reported and doesn't stop the rest.
+#[tauri::command]
+pub fn install_all_skills_from_github(
+    app: tauri::AppHandle,
+    request: InstallSkillRequest,
+) -> Result<InstallReport, AppError> {
+    let repo_url = request.repo_url.trim().to_string();
+    if !repo_url.starts_with("https://github.com/") {
+        return Err(AppError::Validation(
+            "Only GitHub repository URLs are supported".to_string(),
+        ));
+    }
+
+    let tool = resolve_install_tool(&app, request.target_tool_id.as_deref())?;
+    ensure_dir(Path::new(&tool.skills_path))?;
+
+    let temp = TempDir::new("skillsyoga-install")?;
+    let temp_root = temp.path();
+
+    let token = stored_github_token(&app)?;
+    git_clone_shallow(
+        &repo_url,
+        temp_root,
+        GIT_CLONE_TIMEOUT,
+        token.as_deref(),
+        request.git_ref.as_deref(),
+    )?;
+
+    let requested = request.skill_path.as_deref().map(str::trim).filter(|p| !p.is_empty());
+    let source_dirs = match requested {
+        Some(requested) => vec![resolve_cloned_skill_dir(temp_root, Some(requested))?],
+        None => discover_all_skill_dirs(temp_root, REPO_SKILL_SCAN_DEPTH),
     };
+    if source_dirs.is_empty() {
+        return Err(AppError::NotFound(
+            "No SKILL.md found in the repository".to_string(),
+        ));
+    }
 
-    ensure_dir(&target_dir)?;
+    let mut report = InstallReport::default();
+    for source_dir in &source_dirs {
+        match install_cloned_skill(&tool, temp_root, source_dir, &repo_url) {
+            Ok(skill) => report.installed.push(skill),
+            Err(err) => report.failed.push(PathFailure {
+                path: to_relative_string(temp_root, source_dir).unwrap_or_else(|| {
+                    source_dir.to_string_lossy().to_string()
+                }),
+                error: err.to_string(),
+            }),
+        }
+    }
+    Ok(report)
+}
+
+/// Copy one skill folder out of a clone at `clone_root` into `tool`'s
+/// skills root and record where it came from.
+fn install_cloned_skill(
+    tool: &ToolInfo,
+    clone_root: &Path,
+    source_dir: &Path,
+    repo_url: &str,
+) -> Result<SkillInfo, AppError> {
+    if !source_dir.exists() || !source_dir.join("SKILL.md").exists() {
+        return Err(AppError::NotFound(format!(
+            "Skill folder invalid: {}",
+            source_dir.to_string_lossy()
+        )));
+    }
+
+    let default_name = dir_display_name(source_dir);
+    let source_content = read_skill_text(&source_dir.join("SKILL.md"))?;
+    let source_skill_meta = parse_skill_metadata(&source_content, &default_name);
+    let source_rel = source_dir
+        .strip_prefix(clone_root)
+        .ok()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .filter(|v| !v.is_empty() && v != ".");
 
-    let skill_file = target_dir.join("SKILL.md");
-    fs::write(&skill_file, &request.content)?;
+    let folder = skill_folder_name(&dir_display_name(source_dir), &source_skill_meta.name);
+    let target = unique_dir(Path::new(&tool.skills_path), &folder);
+    copy_dir_atomic(source_dir, &target, &SkillIgnore::standard())?;
+    write_skill_source_meta(&target, repo_url, source_rel.as_deref(), None)?;
 
-    let dir_name = dir_display_name(&target_dir);
+    let content = read_skill_text(&target.join("SKILL.md"))?;
+
+    let skill_meta = parse_skill_metadata(&content, &default_name);
 
     Ok(SkillInfo {
-        id: format!("{}:{}", tool.id, dir_name),
-        name: meta.name,
-        description: meta.description,
-        path: target_dir.to_string_lossy().to_string(),
+        id: skill_id(tool, &target),
+        name: skill_meta.name,
+        description: skill_meta.description,
+        path: target.to_string_lossy().to_string(),
         source: tool.id.clone(),
-        enabled_for: vec![tool.id],
+        enabled_for: vec![tool.id.clone()],
         updated_at: now_iso(),
-        github_repo_url: None,
-        github_skill_path: None,
+        github_repo_url: Some(repo_url.to_string()),
+        github_skill_path: source_rel,
+        pinned: false,
+        tags: skill_meta.tags,
+        license: skill_meta.license,
+        version: skill_meta.version,
+        author: skill_meta.author,
+        enabled: true,
+        skill_md_bytes: file_len(&target.join("SKILL.md")),
+        conflicts: vec![],
     })
 }
 
+/// Clone a repository to a temporary folder and describe the skills an
+/// install would pick up, without installing anything. With `skill_path`
+/// only that folder is described; otherwise every skill in the repo is, so
+/// the right path can be chosen. `target_path` is where the skill would be
+/// installed, when a target or default install tool is known.
 #[tauri::command]
-pub fn delete_skill(app: tauri::AppHandle, path: String) -> Result<(), AppError> {
-    let dir = PathBuf::from(&path);
-    is_path_under_skills_root(&dir, &app)?;
-    if dir.exists() {
-        trash::delete(&dir).map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
+pub fn preview_github_install(
+    app: tauri::AppHandle,
+    repo_url: String,
+    skill_path: Option<String>,
+    target_tool_id: Option<String>,
+) -> Result<Vec<InstallPreview>, AppError> {
+    let repo_url = repo_url.trim().to_string();
+    if !repo_url.starts_with("https://github.com/") {
+        return Err(AppError::Validation(
+            "Only GitHub repository URLs are supported".to_string(),
+        ));
     }
-    Ok(())
+    let tool = match target_tool_id.as_deref().map(str::trim).filter(|id| !id.is_empty()) {
+        Some(id) => Some(find_tool_by_id(&app, id)?),
+        None => resolve_install_tool(&app, None).ok(),
+    };
+
+    let temp = TempDir::new("skillsyoga-preview")?;
+    let temp_root = temp.path();
+    let token = stored_github_token(&app)?;
+    git_clone_shallow(&repo_url, temp_root, GIT_CLONE_TIMEOUT, token.as_deref(), None)?;
+
+    let skill_dirs = match skill_path.as_deref().map(str::trim).filter(|p| !p.is_empty()) {
+        Some(requested) => vec![resolve_cloned_skill_dir(temp_root, Some(requested))?],
+        None => discover_all_skill_dirs(temp_root, REPO_SKILL_SCAN_DEPTH),
+    };
+
+    let mut previews = vec![];
+    for dir in skill_dirs {
+        let skill_file = dir.join("SKILL.md");
+        if !skill_file.is_file() {
+            return Err(AppError::NotFound(format!(
+                "Skill folder invalid: {}",
+                to_relative_string(temp_root, &dir).unwrap_or_default()
+            )));
+        }
+        let content = read_skill_text(&skill_file)?;
+        let meta = parse_skill_metadata(&content, &dir_display_name(&dir));
+        let target_path = tool.as_ref().map(|tool| {
+            let folder = skill_folder_name(&dir_display_name(&dir), &meta.name);
+            unique_dir(Path::new(&tool.skills_path), &folder)
+                .to_string_lossy()
+                .to_string()
+        });
+        previews.push(InstallPreview {
+            name: meta.name,
+            description: meta.description,
+            skill_path: to_relative_string(temp_root, &dir).filter(|rel| !rel.is_empty()),
+            target_path,
+            files: collect_skill_entries(&dir, &SkillIgnore::standard())?,
+        });
+    }
+    if previews.is_empty() {
+        return Err(AppError::NotFound(
+            "No SKILL.md found in the repository".to_string(),
+        ));
+    }
+    Ok(previews)
 }
 
 #[tauri::command]
-pub fn upsert_custom_tool(
+pub fn update_skill_from_github(
     app: tauri::AppHandle,
-    tool: CustomToolInput,
-) -> Result<DashboardData, AppError> {
-    let mut state = load_state(&app)?;
-
-    let next_id = slugify(&tool.id);
-    if built_in_tools().iter().any(|builtin| builtin.id == next_id) {
+    request: UpdateSkillFromGithubRequest,
+) -> Result<SkillInfo, AppError> {
+    let repo_url = request.repo_url.trim().to_string();
+    if !repo_url.starts_with("https://github.com/") {
         return Err(AppError::Validation(
-            "Custom tool id conflicts with a built-in integration".to_string(),
+            "Only GitHub repository URLs are supported".to_string(),
         ));
     }
 
-    let clean_tool = CustomToolInput {
-        id: next_id,
-        name: tool.name,
-        config_path: tool.config_path,
-        skills_path: tool.skills_path,
+    let skill_root = PathBuf::from(&request.path);
+    is_path_under_skills_root(&skill_root, &app)?;
+    if !skill_root.exists() || !skill_root.is_dir() {
+        return Err(AppError::NotFound(format!(
+            "Skill folder does not exist: {}",
+            skill_root.display()
+        )));
+    }
+
+    let temp = TempDir::new("skillsyoga-update")?;
+    let temp_root = temp.path();
+
+    let token = stored_github_token(&app)?;
+    git_clone_shallow(&repo_url, temp_root, GIT_CLONE_TIMEOUT, token.as_deref(), None)?;
+
+    // The recorded skill path may have moved upstream; fall back to a
+    // folder with the installed skill's name. A path that is unsafe, rather
+    // than just missing, is an error.
+    let recorded = match resolve_cloned_skill_dir(temp_root, request.skill_path.as_deref()) {
+        Ok(dir) => Some(dir),
+        Err(AppError::NotFound(_)) => None,
+        Err(err) => return Err(err),
     };
+    let source_dir = recorded
+        .filter(|dir| dir.join("SKILL.md").is_file())
+        .or_else(|| discover_skill_dir_by_name(temp_root, &dir_display_name(&skill_root), 0))
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Could not find the skill in {repo_url}{}",
+                request
+                    .skill_path
+                    .as_deref()
+                    .map(|p| format!(" at {p}"))
+                    .unwrap_or_default()
+            ))
+        })?;
 
-    if let Some(existing) = state
-        .custom_tools
-        .iter_mut()
-        .find(|t| t.id == clean_tool.id)
-    {
-        *existing = clean_tool;
-    } else {
-        state.custom_tools.push(clean_tool);
+    let source_rel = source_dir
+        .strip_prefix(temp_root)
+        .ok()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .filter(|v| !v.is_empty() && v != ".");
+
+    // Overlay rather than wipe, so local-only files the source doesn't
+    // have (notes, extra scripts) survive the update. A disabled skill
+    // stays disabled: the fresh manifest replaces the disabled one.
+    let was_disabled = skill_manifest_path(&skill_root).ends_with(DISABLED_SKILL_MD);
+    overlay_dir(&source_dir, &skill_root, &SkillIgnore::standard())?;
+    if was_disabled {
+        let disabled = skill_root.join(DISABLED_SKILL_MD);
+        fs::remove_file(&disabled)?;
+        fs::rename(skill_root.join("SKILL.md"), &disabled)?;
     }
+    write_skill_source_meta(&skill_root, &repo_url, source_rel.as_deref(), None)?;
 
-    save_state(&app, &state)?;
-    dashboard(&app)
+    let tool = find_tool_for_path(&app, &skill_root)?;
+    load_skill_info(&tool, &skill_root)
 }
 
+/// Queries shorter than this (after trimming) return no results unless the
+/// caller explicitly asks for browse mode via `allow_short`.
+const MIN_SEARCH_QUERY_LEN: usize = 2;
+
+/// Page size for `search_skills` when the caller doesn't pick one, and the
+/// most it may ask for.
+const DEFAULT_SEARCH_LIMIT: u32 = 20;
+const MAX_SEARCH_LIMIT: u32 = 100;
+
+/// Fill `installed_in` for each result with the tools that have a skill
+/// whose folder or name slug matches the result's skill id.
+fn mark_installed(results: &mut [SearchSkillResult], installed: &[SkillInfo]) {
+    for result in results {
+        let leaf = result.skill_id.rsplit('/').next().unwrap_or(&result.skill_id);
+        let slug = slugify(leaf);
+        let mut tools: Vec<String> = installed
+            .iter()
+            .filter(|skill| {
+                slugify(&dir_display_name(Path::new(&skill.path))) == slug
+                    || slugify(&skill.name) == slug
+            })
+            .map(|skill| skill.source.clone())
+            .collect();
+        tools.sort();
+        tools.dedup();
+        result.installed_in = tools;
+    }
+}
+
+/// Search the configured registry (skills.sh by default) one page at a
+/// time. `tool` narrows the results to skills for that tool or framework;
+/// `total` comes straight from the registry so the UI can page through
+/// everything.
 #[tauri::command]
-pub fn discover_skills_paths(scan_root: String) -> Result<Vec<DiscoveredSkillsRoot>, AppError> {
-    let expanded = crate::helpers::expand_home(scan_root.trim())?;
-    if !expanded.exists() {
-        return Err(AppError::NotFound(format!(
-            "Scan path does not exist: {}",
-            expanded.display()
+pub async fn search_skills(
+    app: tauri::AppHandle,
+    query: String,
+    allow_short: Option<bool>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    tool: Option<String>,
+) -> Result<SearchSkillsPage, AppError> {
+    let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT);
+    let offset = offset.unwrap_or(0);
+    let query = query.trim().to_string();
+    if query.chars().count() < MIN_SEARCH_QUERY_LEN && !allow_short.unwrap_or(false) {
+        return Ok(SearchSkillsPage {
+            skills: vec![],
+            total: Some(0),
+            limit,
+            offset,
+        });
+    }
+
+    let registry = load_state(&app)?.registry_url;
+    let url = registry_search_url(&registry, &query, limit, offset, tool.as_deref())?;
+    let resp = send_with_retry(http_client().get(&url), &format!("Failed to reach {registry}"))
+        .await?;
+    let data: SearchSkillsResponse = resp
+        .json()
+        .await
+        .map_err(|e| network_error(&format!("Invalid response from {registry}"), e))?;
+
+    let mut results = data.skills;
+    // Scanning reads every enabled tool's skills, so keep it off the async runtime.
+    let tools = resolve_tools(&app)?;
+    let (installed, _) = tauri::async_runtime::spawn_blocking(move || scan_enabled_tools(&tools))
+        .await
+        .map_err(std::io::Error::other)?;
+    mark_installed(&mut results, &installed);
+    Ok(SearchSkillsPage {
+        skills: results,
+        total: data.total,
+        limit,
+        offset,
+    })
+}
+
+/// Import a single-file skill from a raw `SKILL.md` URL (a gist or GitHub
+/// raw link). The folder is named after `name` when given, otherwise the
+/// frontmatter `name`, and a given `name` fills in a missing frontmatter
+/// one. Only `https://` URLs are accepted.
+#[tauri::command]
+pub async fn install_skill_from_url(
+    app: tauri::AppHandle,
+    url: String,
+    target_tool_id: Option<String>,
+    name: Option<String>,
+) -> Result<SkillInfo, AppError> {
+    let url = url.trim();
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| AppError::Validation(format!("Invalid URL {url}: {e}")))?;
+    if parsed.scheme() != "https" {
+        return Err(AppError::Validation(format!(
+            "Only https:// URLs can be imported: {url}"
         )));
     }
-    if !expanded.is_dir() {
+
+    let tool = resolve_install_tool(&app, target_tool_id.as_deref())?;
+    let state = load_state(&app)?;
+
+    let fetch_failed = format!("Failed to fetch {url}");
+    let mut response = send_with_retry(http_client().get(parsed), &fetch_failed).await?;
+    if !response.status().is_success() {
+        return Err(AppError::Network(format!(
+            "Failed to fetch {url} ({})",
+            response.status()
+        )));
+    }
+    if let Some(len) = response.content_length() {
+        check_skill_md_size(len as usize, state.max_skill_md_bytes)?;
+    }
+    // Content-Length may be missing or wrong, so the body is read in chunks
+    // and abandoned as soon as it passes the limit.
+    let mut bytes = vec![];
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| network_error(&fetch_failed, e))?
+    {
+        bytes.extend_from_slice(&chunk);
+        check_skill_md_size(bytes.len(), state.max_skill_md_bytes)?;
+    }
+    let (content, _) = decode_text(&bytes);
+
+    // A given `name` both names the folder and fills a missing frontmatter
+    // `name`, so the file is only rejected for lacking one when none was
+    // given.
+    let name = name.filter(|name| !name.trim().is_empty());
+    let content = match &name {
+        Some(name) => with_frontmatter_name(&content, &slugify(name))?,
+        None => content,
+    };
+    let folder_name = name.or_else(|| frontmatter_name(&content)).ok_or_else(|| {
+        AppError::Validation("SKILL.md has no frontmatter name; provide a name".to_string())
+    })?;
+
+    let errors: Vec<String> = validate_skill_content(&content)
+        .into_iter()
+        .filter(|issue| issue.severity == "error")
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
         return Err(AppError::Validation(format!(
-            "Scan path is not a directory: {}",
-            expanded.display()
+            "Not a valid skill: {}",
+            errors.join("; ")
         )));
     }
 
-    Ok(discover_skills_roots(&expanded))
+    let skills_root = PathBuf::from(&tool.skills_path);
+    ensure_dir(&skills_root)?;
+    let target_dir = unique_dir(&skills_root, &slugify(&folder_name));
+    ensure_dir(&target_dir)?;
+    write_file_atomic(&target_dir.join("SKILL.md"), content.as_bytes())?;
+
+    load_skill_info(&tool, &target_dir)
 }
 
-#[tauri::command]
-pub fn delete_custom_tool(
-    app: tauri::AppHandle,
-    tool_id: String,
-) -> Result<DashboardData, AppError> {
-    let mut state = load_state(&app)?;
-    state.custom_tools.retain(|t| t.id != tool_id);
-    state.tool_toggles.remove(&tool_id);
-    save_state(&app, &state)?;
-    dashboard(&app)
+fn pending_install_links() -> &'static Mutex<Vec<InstallFromRegistryRequest>> {
+    static PENDING: OnceLock<Mutex<Vec<InstallFromRegistryRequest>>> = OnceLock::new();
+    PENDING.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Handle a `skillsyoga://install` deep link. Nothing is installed here:
+/// the parsed request is queued and `deep-link-install` is emitted so the
+/// frontend can ask the user before calling `install_from_registry`.
+/// Links are queued rather than sent as the event payload because a link
+/// that launched the app arrives before the frontend is listening. Invalid
+/// links emit `deep-link-error` with the reason.
+pub fn handle_install_link(app: &tauri::AppHandle, link: &str) {
+    match parse_install_link(link) {
+        Ok(request) => {
+            pending_install_links()
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(request);
+            let _ = app.emit("deep-link-install", ());
+        }
+        Err(err) => {
+            let _ = app.emit("deep-link-error", err.to_string());
+        }
+    }
 }
 
+/// Install requests received through deep links since the last call.
 #[tauri::command]
-pub fn install_skill_from_github(
+pub fn take_pending_install_links() -> Vec<InstallFromRegistryRequest> {
+    std::mem::take(
+        &mut *pending_install_links()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
+#[tauri::command]
+pub fn install_from_registry(
     app: tauri::AppHandle,
-    request: InstallSkillRequest,
+    request: InstallFromRegistryRequest,
 ) -> Result<SkillInfo, AppError> {
-    let repo_url = request.repo_url.trim().to_string();
-    if !repo_url.starts_with("https://github.com/") {
-        return Err(AppError::Validation(
-            "Only GitHub repository URLs are supported".to_string(),
-        ));
-    }
+    validate_registry_request(&request)?;
+    let repo_url = match request.repo_url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        Some(repo_url) => repo_url.to_string(),
+        None => format!("https://github.com/{}", request.source),
+    };
 
-    let tool = find_tool_by_id(&app, &request.target_tool_id)?;
+    let tool = resolve_install_tool(&app, request.target_tool_id.as_deref())?;
     let skills_root = PathBuf::from(&tool.skills_path);
     ensure_dir(&skills_root)?;
 
-    let temp = TempDir::new("skillsyoga-install")?;
+    let temp = TempDir::new("skillsyoga-registry")?;
     let temp_root = temp.path();
 
-    git_clone_shallow(&repo_url, temp_root, GIT_CLONE_TIMEOUT)?;
-
-    let source_dir = if let Some(skill_path) = request.skill_path {
-        temp_root.join(skill_path)
+    git_clone_shallow(
+        &repo_url,
+        temp_root,
+        GIT_CLONE_TIMEOUT,
+        None,
+        request.git_ref.as_deref(),
+    )?;
+
+    // Registries that key skills by path (`category/skill-id`) are resolved
+    // against the repo root first; otherwise fall back to finding a folder
+    // named after the id (or the path's last segment) anywhere in the tree.
+    let skill_id = request.skill_id.trim().trim_matches('/');
+    let by_path = if skill_id.contains('/') {
+        Some(resolve_cloned_skill_dir(temp_root, Some(skill_id))?)
+            .filter(|dir| dir.join("SKILL.md").is_file())
     } else {
-        discover_skill_dir(temp_root, 0).ok_or_else(|| {
-            AppError::NotFound(
-                "Unable to determine skill directory automatically; provide `skillPath`"
-                    .to_string(),
-            )
-        })?
+        None
     };
+    let leaf_name = skill_id.rsplit('/').next().unwrap_or(skill_id);
+    let source_dir = by_path
+        .or_else(|| discover_skill_dir_by_name(temp_root, leaf_name, 0))
+        .or_else(|| discover_skill_dir(temp_root, 0))
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Could not find skill '{}' in repository",
+                request.skill_id
+            ))
+        })?;
 
-    if !source_dir.exists() || !source_dir.join("SKILL.md").exists() {
+    if !source_dir.join("SKILL.md").exists() {
         return Err(AppError::NotFound(format!(
             "Skill folder invalid: {}",
             source_dir.to_string_lossy()
@@ -649,24 +3295,23 @@ pub fn install_skill_from_github(
     }
 
     let default_name = dir_display_name(&source_dir);
-    let source_content = fs::read_to_string(source_dir.join("SKILL.md"))?;
+    let source_content = read_skill_text(&source_dir.join("SKILL.md"))?;
     let source_skill_meta = parse_skill_metadata(&source_content, &default_name);
     let source_rel = source_dir
         .strip_prefix(temp_root)
         .ok()
         .map(|p| p.to_string_lossy().replace('\\', "/"))
         .filter(|v| !v.is_empty() && v != ".");
+    let folder = skill_folder_name(&dir_display_name(&source_dir), &source_skill_meta.name);
+    let target = unique_dir(&skills_root, &folder);
+    copy_dir_atomic(&source_dir, &target, &SkillIgnore::standard())?;
+    write_skill_source_meta(&target, &repo_url, source_rel.as_deref(), None)?;
 
-    let target = unique_dir(&skills_root, &slugify(&source_skill_meta.name));
-    copy_dir_recursive(&source_dir, &target)?;
-    write_skill_source_meta(&target, &repo_url, source_rel.as_deref())?;
-
-    let content = fs::read_to_string(target.join("SKILL.md"))?;
-
+    let content = read_skill_text(&target.join("SKILL.md"))?;
     let skill_meta = parse_skill_metadata(&content, &default_name);
 
     Ok(SkillInfo {
-        id: format!("{}:{}", tool.id, slugify(&skill_meta.name)),
+        id: crate::skills::skill_id(&tool, &target),
         name: skill_meta.name,
         description: skill_meta.description,
         path: target.to_string_lossy().to_string(),
@@ -675,262 +3320,1054 @@ pub fn install_skill_from_github(
         updated_at: now_iso(),
         github_repo_url: Some(repo_url),
         github_skill_path: source_rel,
+        pinned: false,
+        tags: skill_meta.tags,
+        license: skill_meta.license,
+        version: skill_meta.version,
+        author: skill_meta.author,
+        enabled: true,
+        skill_md_bytes: file_len(&target.join("SKILL.md")),
+        conflicts: vec![],
     })
 }
 
-fn remove_dir_contents(dir: &Path) -> Result<(), AppError> {
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let file_type = entry.file_type()?;
-        if file_type.is_dir() {
-            fs::remove_dir_all(path)?;
-        } else {
-            fs::remove_file(path)?;
+/// Return the cached shallow clone of a curated source, cloning it on first
+/// use. The clone lands in a `.partial` sibling first so an interrupted
+/// clone is never mistaken for a complete cache entry.
+fn cached_source_clone(app: &tauri::AppHandle, source_id: &str, repo_url: &str) -> Result<PathBuf, AppError> {
+    let cache_root = app_data_dir(app)?.join("source-cache");
+    let target = cache_root.join(source_id);
+    if target.is_dir() {
+        return Ok(target);
+    }
+
+    ensure_dir(&cache_root)?;
+    let partial = cache_root.join(format!("{source_id}.partial"));
+    if partial.exists() {
+        fs::remove_dir_all(&partial)?;
+    }
+    if let Err(err) = git_clone_shallow(repo_url, &partial, GIT_CLONE_TIMEOUT, None, None) {
+        let _ = fs::remove_dir_all(&partial);
+        return Err(err);
+    }
+    fs::rename(&partial, &target)?;
+    Ok(target)
+}
+
+fn normalize_skill_text(content: &str) -> String {
+    content.replace("\r\n", "\n").trim().to_string()
+}
+
+/// Guess which curated source an installed skill came from, for skills
+/// installed before provenance sidecars existed. Identical SKILL.md content
+/// scores 1.0; a matching name or folder scores 0.6.
+#[tauri::command]
+pub fn guess_skill_source(app: tauri::AppHandle, path: String) -> Result<Vec<SourceGuess>, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+    let content = read_skill_text(&skill_manifest_path(&skill_root))?;
+    let local_text = normalize_skill_text(&content);
+    let local_dir = dir_display_name(&skill_root);
+    let local_slug = slugify(&parse_skill_metadata(&content, &local_dir).name);
+
+    let mut guesses = vec![];
+    for source in curated_sources() {
+        let clone_root = match cached_source_clone(&app, &source.id, &source.repo_url) {
+            Ok(root) => root,
+            Err(err) => {
+                diag_log(&format!("[backend] guess_skill_source skipped {}: {err}", source.id));
+                continue;
+            }
+        };
+
+        for candidate in discover_all_skill_dirs(&clone_root, 4) {
+            let Ok(candidate_content) = read_skill_text(&candidate.join("SKILL.md")) else {
+                continue;
+            };
+            let candidate_dir = dir_display_name(&candidate);
+            let candidate_slug = slugify(&parse_skill_metadata(&candidate_content, &candidate_dir).name);
+
+            let (confidence, reason) = if normalize_skill_text(&candidate_content) == local_text {
+                (1.0, "Identical SKILL.md content")
+            } else if candidate_slug == local_slug || candidate_dir == local_dir {
+                (0.6, "Same skill name")
+            } else {
+                continue;
+            };
+
+            guesses.push(SourceGuess {
+                source_id: source.id.clone(),
+                source_name: source.name.clone(),
+                repo_url: source.repo_url.clone(),
+                skill_path: to_relative_string(&clone_root, &candidate).unwrap_or_default(),
+                confidence,
+                reason: reason.to_string(),
+            });
         }
     }
-    Ok(())
+
+    guesses.sort_by(|a, b| {
+        b.confidence
+            .total_cmp(&a.confidence)
+            .then_with(|| a.source_name.cmp(&b.source_name))
+    });
+    Ok(guesses)
 }
 
-fn copy_dir_contents(source: &Path, target: &Path) -> Result<(), AppError> {
-    for entry in fs::read_dir(source)? {
-        let entry = entry?;
-        let source_path = entry.path();
-        let target_path = target.join(entry.file_name());
-        let file_type = entry.file_type()?;
-        if file_type.is_dir() {
-            copy_dir_recursive(&source_path, &target_path)?;
-        } else {
-            fs::copy(&source_path, &target_path)?;
+/// Tags are restricted to a conservative character set so they can be
+/// written into YAML frontmatter without quoting.
+fn clean_tag(tag: &str) -> Result<String, AppError> {
+    let clean = tag.trim();
+    let valid = !clean.is_empty()
+        && clean
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+    if !valid {
+        return Err(AppError::Validation(format!(
+            "Invalid tag '{tag}': use letters, digits, '-', '_', '.', or '/'"
+        )));
+    }
+    Ok(clean.to_string())
+}
+
+/// Rewrite the frontmatter `tags` of each skill with `update`, only touching
+/// files whose tag list actually changed. All paths are validated before
+/// any file is written.
+fn update_skill_tags(
+    app: &tauri::AppHandle,
+    skill_paths: &[String],
+    update: impl Fn(&mut Vec<String>),
+) -> Result<Vec<SkillInfo>, AppError> {
+    let mut targets = vec![];
+    for path in skill_paths {
+        let dir = PathBuf::from(path);
+        is_path_under_skills_root(&dir, app)?;
+        let skill_file = skill_manifest_path(&dir);
+        if !skill_file.is_file() {
+            return Err(AppError::Validation(format!(
+                "Source is not a valid skill (no SKILL.md): {path}"
+            )));
         }
+        targets.push((find_tool_for_path(app, &dir)?, dir, skill_file));
     }
-    Ok(())
+
+    let mut updated = vec![];
+    for (tool, dir, skill_file) in targets {
+        let content = read_skill_text(&skill_file)?;
+        let before = parse_skill_metadata(&content, &dir_display_name(&dir)).tags;
+        let mut tags = before.clone();
+        update(&mut tags);
+        if tags != before {
+            let value = (!tags.is_empty()).then(|| yaml_inline_list(&tags));
+            let updated = set_frontmatter_field(&content, "tags", value.as_deref());
+            write_file_atomic(&skill_file, updated.as_bytes())?;
+        }
+        updated.push(load_skill_info(&tool, &dir)?);
+    }
+    Ok(updated)
 }
 
 #[tauri::command]
-pub fn update_skill_from_github(
+pub fn add_tag_to_skills(
     app: tauri::AppHandle,
-    request: UpdateSkillFromGithubRequest,
-) -> Result<SkillInfo, AppError> {
-    let repo_url = request.repo_url.trim().to_string();
-    if !repo_url.starts_with("https://github.com/") {
+    skill_paths: Vec<String>,
+    tag: String,
+) -> Result<Vec<SkillInfo>, AppError> {
+    let tag = clean_tag(&tag)?;
+    update_skill_tags(&app, &skill_paths, |tags| {
+        if !tags.contains(&tag) {
+            tags.push(tag.clone());
+        }
+    })
+}
+
+#[tauri::command]
+pub fn remove_tag_from_skills(
+    app: tauri::AppHandle,
+    skill_paths: Vec<String>,
+    tag: String,
+) -> Result<Vec<SkillInfo>, AppError> {
+    let tag = clean_tag(&tag)?;
+    update_skill_tags(&app, &skill_paths, |tags| tags.retain(|t| t != &tag))
+}
+
+/// Tools whose skills roots coincide or nest, which makes the same skills
+/// show up under several tools. Roots are canonicalized when they exist so
+/// symlinked paths are caught too.
+#[tauri::command]
+pub fn detect_overlapping_tool_roots(app: tauri::AppHandle) -> Result<Vec<RootOverlap>, AppError> {
+    let roots: Vec<(String, PathBuf)> = resolve_tools(&app)?
+        .into_iter()
+        .flat_map(|tool| {
+            let id = tool.id;
+            tool.skills_paths.into_iter().map(move |root| {
+                let root = PathBuf::from(root);
+                (id.clone(), root.canonicalize().unwrap_or(root))
+            })
+        })
+        .collect();
+    Ok(find_root_overlaps(&roots))
+}
+
+/// Per-tool default vs effective skills path, for diagnosing detection.
+#[tauri::command]
+pub fn tool_path_details(app: tauri::AppHandle) -> Result<Vec<ToolPathDetail>, AppError> {
+    let defaults: Vec<CustomToolInput> = built_in_tools();
+    resolve_tools(&app)?
+        .into_iter()
+        .map(|tool| {
+            let default_skills_path = defaults
+                .iter()
+                .find(|builtin| tool.kind == "builtin" && builtin.id == tool.id)
+                .and_then(|builtin| builtin.skills_path.first())
+                .map(|path| expand_home(path))
+                .transpose()?
+                .map(|path| path.to_string_lossy().to_string());
+            Ok(ToolPathDetail {
+                exists: Path::new(&tool.skills_path).is_dir(),
+                default_skills_path,
+                tool_id: tool.id,
+                name: tool.name,
+                kind: tool.kind,
+                skills_path: tool.skills_path,
+            })
+        })
+        .collect()
+}
+
+/// Compare the current state with a backup file so the UI can confirm
+/// what a restore would change before anything is overwritten.
+#[tauri::command]
+pub fn diff_state_backup(app: tauri::AppHandle, src_path: String) -> Result<StateDiff, AppError> {
+    let backup = read_state_file(Path::new(&src_path))?;
+    let mut current = load_state(&app)?;
+    current.github_token = load_github_token(&app)?;
+    Ok(diff_states(&current, &backup))
+}
+
+/// Write the current state to a JSON file at `dest` (a folder gets a
+/// `skillsyoga-state.json` inside it). The GitHub token is left out unless
+/// `include_token` is set. Returns the path written.
+#[tauri::command]
+pub fn export_state(
+    app: tauri::AppHandle,
+    dest: String,
+    include_token: Option<bool>,
+) -> Result<String, AppError> {
+    let dest = dest.trim();
+    if dest.is_empty() {
         return Err(AppError::Validation(
-            "Only GitHub repository URLs are supported".to_string(),
+            "Destination cannot be empty".to_string(),
         ));
     }
+    let mut target = expand_home(dest)?;
+    if target.is_dir() {
+        target = target.join("skillsyoga-state.json");
+    } else if target.extension().is_none() {
+        target.set_extension("json");
+    }
+    if !target.parent().is_some_and(Path::is_dir) {
+        return Err(AppError::NotFound(format!(
+            "Destination folder does not exist: {}",
+            target.display()
+        )));
+    }
 
-    let skill_root = PathBuf::from(&request.path);
-    is_path_under_skills_root(&skill_root, &app)?;
-    if !skill_root.exists() || !skill_root.is_dir() {
+    let mut state = load_state(&app)?;
+    state.github_token = if include_token.unwrap_or(false) {
+        load_github_token(&app)?
+    } else {
+        None
+    };
+    write_file_atomic(&target, serde_json::to_string_pretty(&state)?.as_bytes())?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+/// Merge a state file written by `export_state` (or a `state.json` backup)
+/// into the current state. Run `diff_state_backup` first to preview it.
+#[tauri::command]
+pub fn import_state(app: tauri::AppHandle, path: String) -> Result<DashboardData, AppError> {
+    let mut imported = read_state_file(&expand_home(path.trim())?)?;
+    validate_imported_state(&imported)?;
+    let token = imported.github_token.take();
+    let merged = merge_states(load_state(&app)?, imported);
+    save_state(&app, &merged)?;
+    if let Some(token) = token.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+        store_github_token(&app, Some(token))?;
+    }
+    dashboard(&app)
+}
+
+/// Persist the tool order after `sanitize_tool_order` and return what was
+/// stored.
+#[tauri::command]
+pub fn reorder_tools(
+    app: tauri::AppHandle,
+    tool_order: Vec<String>,
+) -> Result<Vec<String>, AppError> {
+    let tools = resolve_tools(&app)?;
+    let mut state = load_state(&app)?;
+    state.tool_order = sanitize_tool_order(tool_order, &tools);
+    save_state(&app, &state)?;
+    Ok(state.tool_order)
+}
+
+#[tauri::command]
+pub fn reveal_in_finder(app: tauri::AppHandle, path: String) -> Result<(), AppError> {
+    let p = PathBuf::from(&path);
+    if !p.exists() {
+        return Err(AppError::NotFound(format!("Path not found: {path}")));
+    }
+
+    // Only allow revealing paths that belong to a known skills directory.
+    // Prevents the frontend from asking the OS to `open -R` arbitrary files.
+    is_path_under_skills_root(&p, &app)?;
+
+    reveal_path(&p)
+}
+
+/// Reveal a tool's config directory. The path comes from the tool
+/// definition rather than the frontend, so no skills-root check applies.
+#[tauri::command]
+pub fn open_tool_config(app: tauri::AppHandle, tool_id: String) -> Result<(), AppError> {
+    let tool = find_tool_by_id(&app, &tool_id)?;
+    let config = PathBuf::from(&tool.config_path);
+    if !config.exists() {
         return Err(AppError::NotFound(format!(
-            "Skill folder does not exist: {}",
-            skill_root.display()
+            "{} config directory not found (is it installed?): {}",
+            tool.name, tool.config_path
         )));
     }
 
-    let temp = TempDir::new("skillsyoga-update")?;
-    let temp_root = temp.path();
+    reveal_path(&config)
+}
+
+/// Reveal `state.json` in the app data directory, or the directory itself
+/// when no state has been saved yet.
+#[tauri::command]
+pub fn reveal_app_data_dir(app: tauri::AppHandle) -> Result<(), AppError> {
+    let state_path = app_state_path(&app)?;
+    if state_path.is_file() {
+        return reveal_path(&state_path);
+    }
+    let dir = app_data_dir(&app)?;
+    ensure_dir(&dir)?;
+    reveal_path(&dir)
+}
+
+/// How long `reveal_path` waits for the launcher to exit and report a
+/// failure. A launcher still running after that is assumed to have worked.
+const REVEAL_STATUS_WAIT: Duration = Duration::from_secs(2);
+
+fn reveal_path(path: &Path) -> Result<(), AppError> {
+    let mut child = reveal_command(path)?
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| AppError::NotFound(format!("Could not start the file manager: {e}")))?;
+
+    // explorer.exe exits with 1 even when it opened the window, so its
+    // status says nothing.
+    if cfg!(target_os = "windows") {
+        return Ok(());
+    }
+    let start = Instant::now();
+    loop {
+        match child.try_wait()? {
+            Some(status) if status.success() => return Ok(()),
+            Some(status) => {
+                return Err(AppError::NotFound(format!(
+                    "The file manager could not open {} ({status})",
+                    path.display()
+                )));
+            }
+            None if start.elapsed() >= REVEAL_STATUS_WAIT => {
+                // Reap it whenever it exits instead of leaving a zombie.
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+                return Ok(());
+            }
+            None => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+}
 
-    git_clone_shallow(&repo_url, temp_root, GIT_CLONE_TIMEOUT)?;
+/// Build the per-OS command that reveals `path` in the system file browser.
+fn reveal_command(path: &Path) -> Result<Command, AppError> {
+    #[cfg(target_os = "macos")]
+    {
+        let mut cmd = Command::new(file_manager_binary("open")?);
+        cmd.arg("-R").arg(path);
+        Ok(cmd)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // explorer parses its own command line: the path must be quoted
+        // after `/select,` rather than the whole argument, or paths with
+        // spaces or commas open the wrong folder. `"` can't occur in a
+        // Windows path, so no escaping is needed.
+        let mut cmd = Command::new(file_manager_binary("explorer")?);
+        cmd.raw_arg(format!("/select,\"{}\"", path.display()));
+        Ok(cmd)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // xdg-open can't select a file, so open the folder holding it; a
+        // folder is opened as is.
+        let folder = if path.is_dir() { path } else { path.parent().unwrap_or(path) };
+        let mut cmd = Command::new(file_manager_binary("xdg-open")?);
+        cmd.arg(folder);
+        Ok(cmd)
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        let _ = path;
+        Err(AppError::NotFound(
+            "No file manager found for this platform".to_string(),
+        ))
+    }
+}
+
+/// Resolve the OS file browser binary, failing early with a clear message
+/// instead of spawning something that isn't installed.
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+fn file_manager_binary(name: &str) -> Result<PathBuf, AppError> {
+    find_executable(name).ok_or_else(|| {
+        AppError::NotFound(format!("No file manager found ({name} is not on PATH)"))
+    })
+}
 
-    let source_dir = if let Some(skill_path) = request.skill_path {
-        temp_root.join(skill_path)
+/// Save (or, when blank, clear) the GitHub token. With `verify`, the token
+/// is first checked against the GitHub API and only saved if it works; the
+/// account it belongs to is returned.
+#[tauri::command]
+pub async fn set_github_token(
+    app: tauri::AppHandle,
+    token: String,
+    verify: Option<bool>,
+) -> Result<Option<GithubTokenInfo>, AppError> {
+    let cleaned = token.trim();
+    let info = if verify.unwrap_or(false) && !cleaned.is_empty() {
+        Some(verify_github_token(cleaned).await?)
     } else {
-        discover_skill_dir(temp_root, 0).ok_or_else(|| {
-            AppError::NotFound(
-                "Unable to determine skill directory automatically; provide `skillPath`"
-                    .to_string(),
-            )
-        })?
+        None
     };
+    store_github_token(&app, Some(cleaned).filter(|token| !token.is_empty()))?;
+    Ok(info)
+}
 
-    if !source_dir.exists() || !source_dir.join("SKILL.md").exists() {
-        return Err(AppError::NotFound(format!(
-            "Skill folder invalid: {}",
-            source_dir.to_string_lossy()
+/// Look up the account behind `token` with `GET /user`. A rejected token is
+/// a validation error rather than a network one.
+async fn verify_github_token(token: &str) -> Result<GithubTokenInfo, AppError> {
+    let request = http_client()
+        .get("https://api.github.com/user")
+        .header("Accept", "application/vnd.github+json")
+        .bearer_auth(token);
+    let response = send_with_retry(request, "Failed to verify GitHub token").await?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(AppError::Validation(
+            "GitHub token is invalid or expired".to_string(),
+        ));
+    }
+    if !status.is_success() {
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(AppError::Network(format!(
+            "GitHub user API failed ({status}): {message}"
         )));
     }
 
-    let source_rel = source_dir
-        .strip_prefix(temp_root)
-        .ok()
-        .map(|p| p.to_string_lossy().replace('\\', "/"))
-        .filter(|v| !v.is_empty() && v != ".");
+    let scopes = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|value| value.to_str().ok())
+        .map(parse_oauth_scopes);
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| network_error("Invalid GitHub response", e))?;
+    let login = data
+        .get("login")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| AppError::Network("GitHub response missing login".to_string()))?;
+
+    Ok(GithubTokenInfo {
+        login: login.to_string(),
+        has_gist_scope: scopes.as_ref().map(|s| s.iter().any(|scope| scope == "gist")),
+        scopes: scopes.unwrap_or_default(),
+    })
+}
+
+#[tauri::command]
+pub fn set_skill_editor_default_mode(app: tauri::AppHandle, mode: String) -> Result<(), AppError> {
+    let clean = mode.trim().to_lowercase();
+    if clean != "view" && clean != "edit" {
+        return Err(AppError::Validation(
+            "Mode must be either 'view' or 'edit'".to_string(),
+        ));
+    }
+    let mut state = load_state(&app)?;
+    state.skill_editor_default_mode = clean;
+    save_state(&app, &state)
+}
+
+const PUBLISH_GITIGNORE: &str = ".DS_Store\nnode_modules/\n";
+const PUBLISH_LICENSE_PLACEHOLDER: &str =
+    "Choose a license for this skill (e.g. MIT or Apache-2.0) and replace this file with its text.\n";
+
+fn write_if_missing(path: &Path, content: &str) -> Result<(), AppError> {
+    if !path.exists() {
+        fs::write(path, content)?;
+    }
+    Ok(())
+}
+
+/// Lay out an installed skill as a PR-ready registry repository:
+/// `dest_dir/skills/<slug>/` plus README, LICENSE placeholder and
+/// `.gitignore`. Existing top-level files are left alone so several skills
+/// can be published into the same repo.
+#[tauri::command]
+pub fn prepare_for_publish(
+    app: tauri::AppHandle,
+    path: String,
+    dest_dir: String,
+) -> Result<PublishBundle, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+    let skill_file = skill_manifest_path(&skill_root);
+    if !skill_file.is_file() {
+        return Err(AppError::Validation(
+            "Source is not a valid skill (no SKILL.md)".to_string(),
+        ));
+    }
+
+    let content = read_skill_text(&skill_file)?;
+    let issues = validate_skill_content(&content);
+    let errors: Vec<&str> = issues
+        .iter()
+        .filter(|issue| issue.severity == "error")
+        .map(|issue| issue.message.as_str())
+        .collect();
+    if !errors.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Fix validation errors before publishing: {}",
+            errors.join("; ")
+        )));
+    }
+
+    let dest = dest_dir.trim();
+    if dest.is_empty() {
+        return Err(AppError::Validation(
+            "Destination directory cannot be empty".to_string(),
+        ));
+    }
+    let dest_root = expand_home(dest)?;
+    // The destination may not exist yet; its nearest existing ancestor
+    // decides whether it would end up inside the skill.
+    let existing_ancestor = dest_root
+        .ancestors()
+        .find_map(|ancestor| ancestor.canonicalize().ok())
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid destination: {dest}")))?;
+    if existing_ancestor.starts_with(skill_root.canonicalize()?) {
+        return Err(AppError::InvalidPath(
+            "Destination must not be inside the skill being published".to_string(),
+        ));
+    }
+    let skills_dir = dest_root.join("skills");
+    ensure_dir(&skills_dir)?;
 
-    remove_dir_contents(&skill_root)?;
-    copy_dir_contents(&source_dir, &skill_root)?;
-    write_skill_source_meta(&skill_root, &repo_url, source_rel.as_deref())?;
+    let meta = parse_skill_metadata(&content, &dir_display_name(&skill_root));
+    let slug = slugify(&meta.name);
+    let target = skills_dir.join(&slug);
+    if target.exists() {
+        return Err(AppError::Validation(format!(
+            "Target path already exists: {}",
+            target.display()
+        )));
+    }
 
-    let content = fs::read_to_string(skill_root.join("SKILL.md"))?;
-    let default_name = dir_display_name(&skill_root);
-    let skill_meta = parse_skill_metadata(&content, &default_name);
+    copy_dir_recursive(&skill_root, &target, &SkillIgnore::standard())?;
+    // A skill disabled locally still ships with an active manifest.
+    if skill_file.ends_with(DISABLED_SKILL_MD) {
+        fs::rename(target.join(DISABLED_SKILL_MD), target.join("SKILL.md"))?;
+    }
+    // Provenance is local bookkeeping; it doesn't belong in a published repo.
+    let sidecar = target.join(SOURCE_META_FILE);
+    if sidecar.exists() {
+        fs::remove_file(sidecar)?;
+    }
 
-    let tools = resolve_tools(&app)?;
-    let tool_id = tools
-        .iter()
-        .find(|tool| skill_root.starts_with(PathBuf::from(&tool.skills_path)))
-        .map(|tool| tool.id.clone())
-        .ok_or_else(|| {
-            AppError::NotFound("Could not determine tool for updated skill path".to_string())
-        })?;
+    write_if_missing(&dest_root.join("README.md"), &render_skill_readme(&meta, &slug))?;
+    write_if_missing(&dest_root.join("LICENSE"), PUBLISH_LICENSE_PLACEHOLDER)?;
+    write_if_missing(&dest_root.join(".gitignore"), PUBLISH_GITIGNORE)?;
 
-    Ok(SkillInfo {
-        id: format!("{}:{}", tool_id, slugify(&skill_meta.name)),
-        name: skill_meta.name,
-        description: skill_meta.description,
-        path: skill_root.to_string_lossy().to_string(),
-        source: tool_id.clone(),
-        enabled_for: vec![tool_id],
-        updated_at: now_iso(),
-        github_repo_url: Some(repo_url.clone()),
-        github_skill_path: source_rel,
+    Ok(PublishBundle {
+        path: dest_root.to_string_lossy().to_string(),
+        skill_path: target.to_string_lossy().to_string(),
+        warnings: issues,
     })
 }
 
-#[tauri::command]
-pub async fn search_skills(query: String) -> Result<Vec<SearchSkillResult>, AppError> {
-    let url = format!(
-        "https://skills.sh/api/search?q={}&limit=20",
-        urlencoding::encode(&query)
-    );
-    let resp = reqwest::get(&url)
-        .await
-        .map_err(|e| AppError::Network(format!("Failed to reach skills.sh: {e}")))?;
-    let data: SearchSkillsResponse = resp
-        .json()
-        .await
-        .map_err(|e| AppError::Network(format!("Invalid response from skills.sh: {e}")))?;
-    Ok(data.skills)
-}
+/// Largest total size `import_skill_from_zip` will unpack, in bytes.
+const MAX_IMPORT_ZIP_BYTES: u64 = 512 * 1024 * 1024;
 
+/// Install a skill from a zip archive, e.g. one made by `export_skill`,
+/// into a fresh folder under the target tool's skills root. The archive may
+/// hold the skill at its root or in a subfolder; entries that would escape
+/// the extraction directory reject the whole archive.
 #[tauri::command]
-pub fn install_from_registry(
+pub fn import_skill_from_zip(
     app: tauri::AppHandle,
-    request: InstallFromRegistryRequest,
+    zip_path: String,
+    target_tool_id: String,
 ) -> Result<SkillInfo, AppError> {
-    let repo_url = format!("https://github.com/{}", request.source);
-
-    let tool = find_tool_by_id(&app, &request.target_tool_id)?;
+    let archive = expand_home(zip_path.trim())?;
+    if !archive.is_file() {
+        return Err(AppError::NotFound(format!("Archive not found: {zip_path}")));
+    }
+    let tool = find_tool_by_id(&app, &target_tool_id)?;
     let skills_root = PathBuf::from(&tool.skills_path);
     ensure_dir(&skills_root)?;
 
-    let temp = TempDir::new("skillsyoga-registry")?;
-    let temp_root = temp.path();
+    let temp = TempDir::new("skillsyoga-zip-import")?;
+    unzip_checked(&archive, temp.path(), MAX_IMPORT_ZIP_BYTES)?;
+    let source_dir = discover_skill_dir(temp.path(), 0)
+        .ok_or_else(|| AppError::NotFound(format!("No SKILL.md found in {zip_path}")))?;
+
+    let content = read_skill_text(&source_dir.join("SKILL.md"))?;
+    let fallback = archive
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|_| source_dir == temp.path())
+        .unwrap_or_else(|| dir_display_name(&source_dir));
+    let meta = parse_skill_metadata(&content, &fallback);
+
+    let target = unique_dir(&skills_root, &skill_folder_name(&fallback, &meta.name));
+    copy_dir_atomic(&source_dir, &target, &SkillIgnore::standard())?;
+    load_skill_info(&tool, &target)
+}
+
+/// Write a skill to a single zip archive for sharing. Entries sit under a
+/// top-level `<slug>/` folder so the archive unpacks cleanly, and what
+/// `.skillignore` excludes is left out. `dest` may be a directory, in which
+/// case `<slug>.zip` is written inside it. Returns the archive path.
+#[tauri::command]
+pub fn export_skill(app: tauri::AppHandle, path: String, dest: String) -> Result<String, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+    let skill_file = skill_manifest_path(&skill_root);
+    if !skill_file.is_file() {
+        return Err(AppError::Validation(format!(
+            "Source is not a valid skill (no SKILL.md): {path}"
+        )));
+    }
+    let dest = dest.trim();
+    if dest.is_empty() {
+        return Err(AppError::Validation(
+            "Destination cannot be empty".to_string(),
+        ));
+    }
 
-    git_clone_shallow(&repo_url, temp_root, GIT_CLONE_TIMEOUT)?;
+    let content = read_skill_text(&skill_file)?;
+    let slug = slugify(&parse_skill_metadata(&content, &dir_display_name(&skill_root)).name);
+    let mut archive = expand_home(dest)?;
+    if archive.is_dir() {
+        archive = archive.join(format!("{slug}.zip"));
+    } else if archive.extension().is_none() {
+        archive.set_extension("zip");
+    }
+    let parent = archive
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid destination: {dest}")))?;
+    ensure_dir(parent)?;
+    if parent.canonicalize()?.starts_with(skill_root.canonicalize()?) {
+        return Err(AppError::InvalidPath(
+            "Destination must not be inside the skill being exported".to_string(),
+        ));
+    }
 
-    let source_dir = discover_skill_dir_by_name(temp_root, &request.skill_id, 0)
-        .or_else(|| discover_skill_dir(temp_root, 0))
-        .ok_or_else(|| {
-            AppError::NotFound(format!(
-                "Could not find skill '{}' in repository",
-                request.skill_id
-            ))
-        })?;
+    let staging = parent.join(format!(".{slug}.zip.partial"));
+    let result = zip_dir(&skill_root, &staging, &slug, &SkillIgnore::standard())
+        .and_then(|()| fs::rename(&staging, &archive));
+    if let Err(err) = result {
+        let _ = fs::remove_file(&staging);
+        return Err(err.into());
+    }
+    Ok(archive.to_string_lossy().to_string())
+}
 
-    if !source_dir.join("SKILL.md").exists() {
-        return Err(AppError::NotFound(format!(
-            "Skill folder invalid: {}",
-            source_dir.to_string_lossy()
+/// Assemble the selected skills into a Claude Code plugin:
+/// `<dest_dir>/<plugin>/.claude-plugin/plugin.json` plus
+/// `<dest_dir>/<plugin>/skills/<slug>/...`. Returns the plugin directory.
+#[tauri::command]
+pub fn export_as_plugin(
+    app: tauri::AppHandle,
+    skill_paths: Vec<String>,
+    dest_dir: String,
+    plugin_name: String,
+) -> Result<String, AppError> {
+    if skill_paths.is_empty() {
+        return Err(AppError::Validation(
+            "Select at least one skill to export".to_string(),
+        ));
+    }
+    if !plugin_name.chars().any(|c| c.is_ascii_alphanumeric()) {
+        return Err(AppError::Validation(
+            "Plugin name must contain letters or digits".to_string(),
+        ));
+    }
+    let plugin_slug = slugify(&plugin_name);
+
+    let mut sources = vec![];
+    for path in &skill_paths {
+        let dir = PathBuf::from(path);
+        is_path_under_skills_root(&dir, &app)?;
+        let skill_file = skill_manifest_path(&dir);
+        if !skill_file.is_file() {
+            return Err(AppError::Validation(format!(
+                "Source is not a valid skill (no SKILL.md): {path}"
+            )));
+        }
+        let content = read_skill_text(&skill_file)?;
+        let meta = parse_skill_metadata(&content, &dir_display_name(&dir));
+        sources.push((dir, slugify(&meta.name)));
+    }
+
+    let dest = dest_dir.trim();
+    if dest.is_empty() {
+        return Err(AppError::Validation(
+            "Destination directory cannot be empty".to_string(),
+        ));
+    }
+    let plugin_root = expand_home(dest)?.join(&plugin_slug);
+    if plugin_root.exists() {
+        return Err(AppError::Validation(format!(
+            "Target path already exists: {}",
+            plugin_root.display()
         )));
     }
 
-    let default_name = dir_display_name(&source_dir);
-    let source_content = fs::read_to_string(source_dir.join("SKILL.md"))?;
-    let source_skill_meta = parse_skill_metadata(&source_content, &default_name);
-    let source_rel = source_dir
-        .strip_prefix(temp_root)
-        .ok()
-        .map(|p| p.to_string_lossy().replace('\\', "/"))
-        .filter(|v| !v.is_empty() && v != ".");
-    let target = unique_dir(&skills_root, &slugify(&source_skill_meta.name));
-    copy_dir_recursive(&source_dir, &target)?;
-    write_skill_source_meta(&target, &repo_url, source_rel.as_deref())?;
+    // Built in a staging sibling and renamed into place, so a failed export
+    // leaves no half-built plugin behind.
+    let staging = expand_home(dest)?.join(format!(".{plugin_slug}.plugin.partial"));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    let result = build_plugin_dir(&staging, &plugin_slug, &sources)
+        .and_then(|()| fs::rename(&staging, &plugin_root).map_err(AppError::from));
+    if let Err(err) = result {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(err);
+    }
+    Ok(plugin_root.to_string_lossy().to_string())
+}
 
-    let content = fs::read_to_string(target.join("SKILL.md"))?;
-    let skill_meta = parse_skill_metadata(&content, &default_name);
+/// Lay out a plugin for `export_as_plugin` in `plugin_root`.
+fn build_plugin_dir(
+    plugin_root: &Path,
+    plugin_slug: &str,
+    sources: &[(PathBuf, String)],
+) -> Result<(), AppError> {
+    let manifest_dir = plugin_root.join(".claude-plugin");
+    let skills_dir = plugin_root.join("skills");
+    ensure_dir(&manifest_dir)?;
+    ensure_dir(&skills_dir)?;
+
+    for (source, slug) in sources {
+        let target = unique_dir(&skills_dir, slug);
+        copy_dir_recursive(source, &target, &SkillIgnore::standard())?;
+        // A skill disabled locally still ships with an active manifest.
+        if target.join(DISABLED_SKILL_MD).is_file() {
+            fs::rename(target.join(DISABLED_SKILL_MD), target.join("SKILL.md"))?;
+        }
+        let sidecar = target.join(SOURCE_META_FILE);
+        if sidecar.exists() {
+            fs::remove_file(sidecar)?;
+        }
+    }
 
-    Ok(SkillInfo {
-        id: format!("{}:{}", tool.id, slugify(&skill_meta.name)),
-        name: skill_meta.name,
-        description: skill_meta.description,
-        path: target.to_string_lossy().to_string(),
-        source: tool.id.clone(),
-        enabled_for: vec![tool.id],
-        updated_at: now_iso(),
-        github_repo_url: Some(repo_url),
-        github_skill_path: source_rel,
-    })
+    let manifest = serde_json::json!({
+        "name": plugin_slug,
+        "version": "0.1.0",
+        "description": format!("{} skill(s) exported from SkillsYoga", sources.len()),
+    });
+    fs::write(
+        manifest_dir.join("plugin.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    Ok(())
 }
 
 #[tauri::command]
-pub fn reorder_tools(app: tauri::AppHandle, tool_order: Vec<String>) -> Result<(), AppError> {
+pub fn set_skill_merge_mode(app: tauri::AppHandle, mode: String) -> Result<(), AppError> {
+    let clean = mode.trim().to_lowercase();
+    if !matches!(clean.as_str(), "none" | "name" | "slug") {
+        return Err(AppError::Validation(
+            "Merge mode must be one of 'none', 'name', or 'slug'".to_string(),
+        ));
+    }
     let mut state = load_state(&app)?;
-    state.tool_order = tool_order;
+    state.skill_merge_mode = clean;
     save_state(&app, &state)
 }
 
+/// Set (or clear, with `None`) the tool installs go to when the request
+/// doesn't name one.
 #[tauri::command]
-pub fn reveal_in_finder(app: tauri::AppHandle, path: String) -> Result<(), AppError> {
-    let p = PathBuf::from(&path);
-    if !p.exists() {
-        return Err(AppError::NotFound(format!("Path not found: {path}")));
+pub fn set_default_install_tool(
+    app: tauri::AppHandle,
+    tool_id: Option<String>,
+) -> Result<(), AppError> {
+    let tool_id = tool_id.map(|id| id.trim().to_string()).filter(|id| !id.is_empty());
+    if let Some(id) = &tool_id {
+        find_tool_by_id(&app, id)?;
     }
+    let mut state = load_state(&app)?;
+    state.default_install_tool_id = tool_id;
+    save_state(&app, &state)
+}
 
-    // Only allow revealing paths that belong to a known skills directory.
-    // Prevents the frontend from asking the OS to `open -R` arbitrary files.
-    is_path_under_skills_root(&p, &app)?;
-
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("open").arg("-R").arg(&p).spawn()?;
+/// Set (or reset to the default, with `None`) a tool's context budget.
+#[tauri::command]
+pub fn set_context_budget(
+    app: tauri::AppHandle,
+    tool_id: String,
+    budget_bytes: Option<u64>,
+) -> Result<(), AppError> {
+    find_tool_by_id(&app, &tool_id)?;
+    let mut state = load_state(&app)?;
+    match budget_bytes {
+        Some(0) => {
+            return Err(AppError::Validation(
+                "Context budget must be greater than zero".to_string(),
+            ));
+        }
+        Some(bytes) => {
+            state.context_budgets.insert(tool_id, bytes);
+        }
+        None => {
+            state.context_budgets.remove(&tool_id);
+        }
     }
+    save_state(&app, &state)
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("explorer").arg(format!("/select,{}", p.display())).spawn()?;
+#[tauri::command]
+pub fn set_editor_settings(app: tauri::AppHandle, settings: EditorSettings) -> Result<(), AppError> {
+    if !(1..=8).contains(&settings.tab_size) {
+        return Err(AppError::Validation(
+            "Tab size must be between 1 and 8".to_string(),
+        ));
     }
+    let mut state = load_state(&app)?;
+    state.editor_settings = settings;
+    save_state(&app, &state)
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("xdg-open")
-            .arg(p.parent().unwrap_or(&p))
-            .spawn()?;
-    }
+/// Place `source` at `target` using `mode`. If a symlink or hardlink can't
+/// be created (no privilege on Windows, different volume, ...), any partial
+/// result is removed and the skill is copied instead; the mode actually
+/// used and the reason for falling back are returned.
+fn materialize_skill(
+    source: &Path,
+    target: &Path,
+    mode: LinkMode,
+) -> Result<(LinkMode, Option<String>), AppError> {
+    let linked = match mode {
+        LinkMode::Copy => {
+            copy_dir_atomic(source, target, &SkillIgnore::standard())?;
+            return Ok((LinkMode::Copy, None));
+        }
+        LinkMode::Symlink => symlink_dir(&source.canonicalize()?, target),
+        LinkMode::Hardlink => hardlink_dir_recursive(source, target, &SkillIgnore::standard()),
+    };
 
-    Ok(())
+    match linked {
+        Ok(()) => Ok((mode, None)),
+        Err(err) => {
+            if let Ok(meta) = fs::symlink_metadata(target) {
+                if meta.is_dir() {
+                    fs::remove_dir_all(target)?;
+                } else {
+                    fs::remove_file(target)?;
+                }
+            }
+            copy_dir_atomic(source, target, &SkillIgnore::standard())?;
+            Ok((LinkMode::Copy, Some(format!("Linking failed, copied instead: {err}"))))
+        }
+    }
 }
 
+/// Create a skill in `target_tool_id`'s skills root from a built-in
+/// template (`basic` when `template_id` is absent), in a folder named after
+/// the slug of `name`.
 #[tauri::command]
-pub fn set_github_token(app: tauri::AppHandle, token: String) -> Result<(), AppError> {
-    let mut state = load_state(&app)?;
-    let cleaned = token.trim().to_string();
-    state.github_token = if cleaned.is_empty() {
-        None
-    } else {
-        Some(cleaned)
+pub fn create_skill_from_template(
+    app: tauri::AppHandle,
+    target_tool_id: String,
+    name: String,
+    description: String,
+    template_id: Option<String>,
+) -> Result<SkillInfo, AppError> {
+    let tool = find_tool_by_id(&app, &target_tool_id)?;
+    let template_id = template_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .unwrap_or(SKILL_TEMPLATE_IDS[0]);
+    let state = load_state(&app)?;
+    let content = match state.skill_templates.iter().find(|t| t.id == template_id) {
+        Some(custom) => fill_skill_template(&custom.body, &name, &description)?,
+        None => render_skill_template(template_id, &name, &description)?,
     };
-    save_state(&app, &state)
+
+    let skills_root = PathBuf::from(&tool.skills_path);
+    ensure_dir(&skills_root)?;
+    let target_dir = unique_dir(&skills_root, &slugify(&name));
+    ensure_dir(&target_dir)?;
+    write_file_atomic(&target_dir.join("SKILL.md"), content.as_bytes())?;
+    load_skill_info(&tool, &target_dir)
 }
 
+/// Add or replace a user-defined skill template, keyed by the slug of its
+/// id.
 #[tauri::command]
-pub fn set_skill_editor_default_mode(app: tauri::AppHandle, mode: String) -> Result<(), AppError> {
-    let clean = mode.trim().to_lowercase();
-    if clean != "view" && clean != "edit" {
+pub fn upsert_skill_template(
+    app: tauri::AppHandle,
+    template: SkillTemplate,
+) -> Result<DashboardData, AppError> {
+    if !template.id.chars().any(|c| c.is_ascii_alphanumeric()) {
         return Err(AppError::Validation(
-            "Mode must be either 'view' or 'edit'".to_string(),
+            "Template id must contain letters or digits".to_string(),
         ));
     }
+    let id = slugify(&template.id);
+    if SKILL_TEMPLATE_IDS.contains(&id.as_str()) {
+        return Err(AppError::Validation(
+            "Template id conflicts with a built-in template".to_string(),
+        ));
+    }
+    if template.body.trim().is_empty() {
+        return Err(AppError::Validation("Template body is empty".to_string()));
+    }
+    let name = match template.name.trim() {
+        "" => id.clone(),
+        name => name.to_string(),
+    };
+    let clean_template = SkillTemplate {
+        id,
+        name,
+        body: template.body,
+    };
+
     let mut state = load_state(&app)?;
-    state.skill_editor_default_mode = clean;
-    save_state(&app, &state)
+    if let Some(existing) = state
+        .skill_templates
+        .iter_mut()
+        .find(|t| t.id == clean_template.id)
+    {
+        *existing = clean_template;
+    } else {
+        state.skill_templates.push(clean_template);
+    }
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+#[tauri::command]
+pub fn delete_skill_template(
+    app: tauri::AppHandle,
+    template_id: String,
+) -> Result<DashboardData, AppError> {
+    let mut state = load_state(&app)?;
+    state.skill_templates.retain(|t| t.id != template_id);
+    save_state(&app, &state)?;
+    dashboard(&app)
+}
+
+/// Whether `skills_root` already holds a skill folder whose name has the
+/// given slug.
+fn root_has_skill_slug(skills_root: &Path, slug: &str) -> bool {
+    let Ok(entries) = fs::read_dir(skills_root) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        entry.path().is_dir() && slugify(&entry.file_name().to_string_lossy()) == slug
+    })
+}
+
+/// Copy a skill into every other enabled tool that doesn't have a skill
+/// with the same slug yet. A failure for one tool is reported in `skipped`
+/// and doesn't stop the rest.
+#[tauri::command]
+pub fn sync_skill_to_all_tools(
+    app: tauri::AppHandle,
+    source_path: String,
+) -> Result<SyncReport, AppError> {
+    let source_dir = PathBuf::from(&source_path);
+    is_path_under_skills_root(&source_dir, &app)?;
+    let source_skill_file = skill_manifest_path(&source_dir);
+    if !source_skill_file.is_file() {
+        return Err(AppError::Validation(format!(
+            "Not a valid skill (no SKILL.md): {source_path}"
+        )));
+    }
+    let source_tool = find_tool_for_path(&app, &source_dir)?;
+    let source_content = read_skill_text(&source_skill_file)?;
+    let meta = parse_skill_metadata(&source_content, &dir_display_name(&source_dir));
+    let slug = slugify(&meta.name);
+    let folder = skill_folder_name(&dir_display_name(&source_dir), &meta.name);
+
+    let mut report = SyncReport::default();
+    for tool in resolve_tools(&app)? {
+        if !tool.enabled || tool.id == source_tool.id {
+            continue;
+        }
+        let skills_root = PathBuf::from(&tool.skills_path);
+        let skip = |reason: String| SyncSkip {
+            tool_id: tool.id.clone(),
+            reason,
+        };
+        let folder_slug = slugify(&dir_display_name(&source_dir));
+        let has_skill = tool.skills_paths.iter().any(|root| {
+            root_has_skill_slug(Path::new(root), &slug)
+                || root_has_skill_slug(Path::new(root), &folder_slug)
+        });
+        if has_skill {
+            report.skipped.push(skip("Already has this skill".to_string()));
+            continue;
+        }
+        let copy = || -> Result<SkillInfo, AppError> {
+            ensure_dir(&skills_root)?;
+            let target_dir = unique_dir(&skills_root, &folder);
+            copy_dir_atomic(&source_dir, &target_dir, &SkillIgnore::standard())?;
+            load_skill_info(&tool, &target_dir)
+        };
+        match copy() {
+            Ok(skill) => report.copied.push(skill),
+            Err(err) => report.skipped.push(skip(format!("Copy failed: {err}"))),
+        }
+    }
+    Ok(report)
 }
 
 #[tauri::command]
 pub fn copy_skill_to_tool(
     app: tauri::AppHandle,
     request: CopySkillToToolRequest,
-) -> Result<SkillInfo, AppError> {
+) -> Result<CopySkillResult, AppError> {
     let source_dir = PathBuf::from(&request.source_path);
     is_path_under_skills_root(&source_dir, &app)?;
     if !source_dir.exists() || !source_dir.is_dir() {
@@ -939,35 +4376,34 @@ pub fn copy_skill_to_tool(
             request.source_path
         )));
     }
-    let source_skill_file = source_dir.join("SKILL.md");
-    if !source_skill_file.exists() {
+    let source_skill_file = skill_manifest_path(&source_dir);
+    if !source_skill_file.is_file() {
         return Err(AppError::Validation(
-            "Source folder is not a valid skill (missing SKILL.md)".to_string(),
+            "Source is not a valid skill (no SKILL.md)".to_string(),
         ));
     }
 
     let target_tool = find_tool_by_id(&app, &request.target_tool_id)?;
     let target_skills_root = PathBuf::from(&target_tool.skills_path);
     ensure_dir(&target_skills_root)?;
+    is_path_under_skills_root(&target_skills_root, &app)?;
 
     let source_dir_name = dir_display_name(&source_dir);
-    let source_content = fs::read_to_string(&source_skill_file)?;
+    let source_content = read_skill_text(&source_skill_file)?;
     let source_meta = parse_skill_metadata(&source_content, &source_dir_name);
-    let preferred_name = slugify(&source_meta.name);
+    let preferred_name = skill_folder_name(&source_dir_name, &source_meta.name);
     let preferred_target_dir = target_skills_root.join(&preferred_name);
 
     let strategy = request.conflict_strategy.trim().to_lowercase();
-    let target_dir = match strategy.as_str() {
+    let (target_dir, replace) = match strategy.as_str() {
         "overwrite" => {
-            if preferred_target_dir.exists() {
-                trash::delete(&preferred_target_dir)
-                    .map_err(|e| AppError::Io(std::io::Error::other(e.to_string())))?;
-            }
-            preferred_target_dir
-        }
-        "timestampedcopy" | "timestamped_copy" | "timestamped-copy" | "" => {
-            unique_dir_with_timestamp_on_conflict(&target_skills_root, &preferred_name)
+            let replace = fs::symlink_metadata(&preferred_target_dir).is_ok();
+            (preferred_target_dir, replace)
         }
+        "timestampedcopy" | "timestamped_copy" | "timestamped-copy" | "" => (
+            unique_dir_with_timestamp_on_conflict(&target_skills_root, &preferred_name),
+            false,
+        ),
         _ => {
             return Err(AppError::Validation(format!(
                 "Unsupported conflict strategy: {}",
@@ -976,28 +4412,44 @@ pub fn copy_skill_to_tool(
         }
     };
 
-    if target_dir.exists() {
-        return Err(AppError::Validation(format!(
-            "Target path already exists: {}",
-            target_dir.display()
-        )));
-    }
-
-    copy_dir_recursive(&source_dir, &target_dir)?;
+    let (link_mode, fallback_reason) = if replace {
+        if target_dir.canonicalize().ok() == source_dir.canonicalize().ok() {
+            return Err(AppError::Validation(
+                "Source and target are the same skill folder".to_string(),
+            ));
+        }
+        // The new copy is placed next to the existing one and swapped in,
+        // so a failed copy leaves the existing skill as it was.
+        let staging = target_skills_root.join(format!(
+            ".{preferred_name}.copy-{}",
+            std::process::id()
+        ));
+        if fs::symlink_metadata(&staging).is_ok() {
+            fs::remove_dir_all(&staging)?;
+        }
+        let placed = materialize_skill(&source_dir, &staging, request.link_mode)
+            .and_then(|placed| swap_in_dir(&staging, &target_dir).map(|()| placed));
+        if placed.is_err() {
+            let _ = fs::remove_dir_all(&staging);
+        }
+        placed?
+    } else {
+        if target_dir.exists() {
+            return Err(AppError::Validation(format!(
+                "Target path already exists: {}",
+                target_dir.display()
+            )));
+        }
+        materialize_skill(&source_dir, &target_dir, request.link_mode)?
+    };
 
-    let content = fs::read_to_string(target_dir.join("SKILL.md"))?;
-    let skill_meta = parse_skill_metadata(&content, &source_dir_name);
+    // The source sidecar travels with the copy, so the copy stays updatable.
+    let skill = load_skill_info(&target_tool, &target_dir)?;
 
-    Ok(SkillInfo {
-        id: format!("{}:{}", target_tool.id, slugify(&skill_meta.name)),
-        name: skill_meta.name,
-        description: skill_meta.description,
-        path: target_dir.to_string_lossy().to_string(),
-        source: target_tool.id.clone(),
-        enabled_for: vec![target_tool.id.clone()],
-        updated_at: now_iso(),
-        github_repo_url: None,
-        github_skill_path: None,
+    Ok(CopySkillResult {
+        skill,
+        link_mode,
+        fallback_reason,
     })
 }
 
@@ -1006,12 +4458,7 @@ pub async fn create_github_gist(
     app: tauri::AppHandle,
     request: CreateGistRequest,
 ) -> Result<String, AppError> {
-    let state = load_state(&app)?;
-    let token = state
-        .github_token
-        .as_ref()
-        .map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
+    let token = load_github_token(&app)?
         .ok_or_else(|| AppError::Validation("Please set GitHub Token in Settings.".to_string()))?;
 
     let selected_text = request.selected_text.trim();
@@ -1039,23 +4486,34 @@ pub async fn create_github_gist(
         skill_name
     };
     let gist_file_name = format!("{}-excerpt.md", slugify(safe_name));
+    let mut files = serde_json::Map::new();
+    files.insert(gist_file_name, serde_json::json!({ "content": gist_content }));
+    let description = format!(
+        "SkillsYoga excerpt from {}",
+        if skill_name.is_empty() { "skill" } else { skill_name }
+    );
+    post_gist(&token, &description, files).await
+}
+
+/// Create a private gist from `files` (`name -> { "content": ... }`) and
+/// return its URL.
+async fn post_gist(
+    token: &str,
+    description: &str,
+    files: serde_json::Map<String, serde_json::Value>,
+) -> Result<String, AppError> {
     let body = serde_json::json!({
-      "description": format!("SkillsYoga excerpt from {}", if skill_name.is_empty() { "skill" } else { skill_name }),
+      "description": description,
       "public": false,
-      "files": {
-        gist_file_name: { "content": gist_content }
-      }
+      "files": files,
     });
 
-    let response = reqwest::Client::new()
+    let request = http_client()
         .post("https://api.github.com/gists")
         .header("Accept", "application/vnd.github+json")
-        .header("User-Agent", "skillsyoga")
         .bearer_auth(token)
-        .json(&body)
-        .send()
-        .await
-        .map_err(|e| AppError::Network(format!("Failed to create gist: {e}")))?;
+        .json(&body);
+    let response = send_with_retry(request, "Failed to create gist").await?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -1071,7 +4529,7 @@ pub async fn create_github_gist(
     let data: serde_json::Value = response
         .json()
         .await
-        .map_err(|e| AppError::Network(format!("Invalid GitHub response: {e}")))?;
+        .map_err(|e| network_error("Invalid GitHub response", e))?;
     let url = data
         .get("html_url")
         .and_then(|v| v.as_str())
@@ -1079,3 +4537,64 @@ pub async fn create_github_gist(
 
     Ok(url.to_string())
 }
+
+/// Largest total size of the files `create_skill_gist` uploads, in bytes.
+const MAX_GIST_BYTES: u64 = 1024 * 1024;
+
+/// Share a whole skill as one private gist named after the skill: SKILL.md
+/// plus every other text file, skipping binaries and whatever would take the
+/// upload past `MAX_GIST_BYTES`. Gists are flat, so `scripts/run.sh` is
+/// uploaded as `scripts__run.sh`.
+#[tauri::command]
+pub async fn create_skill_gist(app: tauri::AppHandle, path: String) -> Result<SkillGist, AppError> {
+    let skill_root = PathBuf::from(&path);
+    is_path_under_skills_root(&skill_root, &app)?;
+    let manifest = skill_manifest_path(&skill_root);
+    if !manifest.is_file() {
+        return Err(AppError::Validation(format!(
+            "Source is not a valid skill (no SKILL.md): {path}"
+        )));
+    }
+    let token = load_github_token(&app)?
+        .ok_or_else(|| AppError::Validation("Please set GitHub Token in Settings.".to_string()))?;
+
+    let manifest_size = fs::metadata(&manifest)?.len();
+    if manifest_size > MAX_GIST_BYTES {
+        return Err(AppError::Validation(format!(
+            "SKILL.md is too large for a gist ({manifest_size} bytes; \
+             limit is {MAX_GIST_BYTES} bytes)"
+        )));
+    }
+    let content = read_skill_text(&manifest)?;
+    let meta = parse_skill_metadata(&content, &dir_display_name(&skill_root));
+    let mut total = content.len() as u64;
+    let mut files = serde_json::Map::new();
+    files.insert("SKILL.md".to_string(), serde_json::json!({ "content": content }));
+
+    let mut skipped = vec![];
+    for entry in collect_skill_entries(&skill_root, &SkillIgnore::standard())? {
+        let file = skill_root.join(&entry.relative_path);
+        if entry.is_dir || file == manifest {
+            continue;
+        }
+        let name = entry.relative_path.replace('/', "__");
+        // Check the size first so a large file is never read in whole.
+        let fits = fs::metadata(&file).is_ok_and(|m| total + m.len() <= MAX_GIST_BYTES);
+        let bytes = if fits { fs::read(&file).ok() } else { None };
+        let Some(bytes) = bytes.filter(|bytes| {
+            !looks_binary(bytes)
+                && !bytes.iter().all(u8::is_ascii_whitespace)
+                && total + bytes.len() as u64 <= MAX_GIST_BYTES
+                && !files.contains_key(&name)
+        }) else {
+            skipped.push(entry.relative_path);
+            continue;
+        };
+        total += bytes.len() as u64;
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        files.insert(name, serde_json::json!({ "content": text }));
+    }
+
+    let url = post_gist(&token, &meta.name, files).await?;
+    Ok(SkillGist { url, skipped })
+}
@@ -4,27 +4,174 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
+use chrono::{DateTime, SecondsFormat, Utc};
+
 use crate::error::AppError;
+use crate::models::InstallFromRegistryRequest;
 
+/// The current time as an RFC 3339 UTC timestamp.
 pub fn now_iso() -> String {
-    let ts = SystemTime::now()
+    iso_timestamp(SystemTime::now())
+}
+
+/// `time` as an RFC 3339 UTC timestamp such as `2026-01-31T09:30:00Z`.
+/// Whole seconds and a fixed width, so the strings sort chronologically.
+pub fn iso_timestamp(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Seconds since the unix epoch, for folder-name suffixes where the
+/// colons of an ISO timestamp aren't allowed on every platform.
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
-        .unwrap_or(0);
-    ts.to_string()
+        .unwrap_or(0)
 }
 
+/// The user's home directory: `HOME`, or `USERPROFILE` where `HOME` is
+/// unset (usually Windows).
 pub fn home_dir() -> Result<PathBuf, AppError> {
-    let home = env::var("HOME")
-        .map_err(|_| AppError::NotFound("Unable to resolve HOME directory".to_string()))?;
+    let home = non_empty_var("HOME")
+        .or_else(|| non_empty_var("USERPROFILE"))
+        .ok_or_else(|| AppError::NotFound("Unable to resolve HOME directory".to_string()))?;
     Ok(PathBuf::from(home))
 }
 
+fn non_empty_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// The current HOME plus readable per-user directories under the
+/// conventional home bases (`/Users/*`, `/home/*`, and `C:\Users\*` on
+/// Windows). Used to find tools installed under another account or a
+/// relocated HOME.
+pub fn candidate_home_dirs() -> Vec<PathBuf> {
+    let mut homes = vec![];
+    if let Ok(home) = home_dir() {
+        homes.push(home);
+    }
+
+    let mut bases = vec![PathBuf::from("/Users"), PathBuf::from("/home")];
+    if cfg!(windows) {
+        bases.push(PathBuf::from("C:\\Users"));
+    }
+
+    for base in bases {
+        let Ok(entries) = fs::read_dir(&base) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if hidden || !path.is_dir() || fs::read_dir(&path).is_err() {
+                continue;
+            }
+            if !homes.contains(&path) {
+                homes.push(path);
+            }
+        }
+    }
+    homes
+}
+
+/// Expand a user-supplied path: `$VAR` and `${VAR}` anywhere (plus
+/// `%VAR%` on Windows), then a leading `~` or `~/`. `~/.config` honours
+/// `XDG_CONFIG_HOME` when it is set. Unset variables are left as written.
 pub fn expand_home(path: &str) -> Result<PathBuf, AppError> {
-    if let Some(stripped) = path.strip_prefix("~/") {
-        return Ok(home_dir()?.join(stripped));
+    expand_path_with(path, home_dir, non_empty_var)
+}
+
+/// `home` is only called for paths that start with `~`, so absolute paths
+/// expand even when no home directory can be resolved.
+fn expand_path_with(
+    path: &str,
+    home: impl FnOnce() -> Result<PathBuf, AppError>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<PathBuf, AppError> {
+    let path = expand_vars(path, &lookup);
+    let rest = if path == "~" {
+        ""
+    } else if let Some(rest) = path.strip_prefix("~/") {
+        rest
+    } else if let Some(rest) = path.strip_prefix("~\\").filter(|_| cfg!(windows)) {
+        rest
+    } else {
+        return Ok(PathBuf::from(path));
+    };
+
+    if rest == ".config" || rest.starts_with(".config/") {
+        if let Some(config_home) = lookup("XDG_CONFIG_HOME") {
+            let sub = rest[".config".len()..].trim_start_matches('/');
+            let base = PathBuf::from(config_home);
+            return Ok(if sub.is_empty() { base } else { base.join(sub) });
+        }
     }
-    Ok(PathBuf::from(path))
+    let base = home()?;
+    Ok(if rest.is_empty() { base } else { base.join(rest) })
+}
+
+/// Substitute `$VAR`, `${VAR}` and (on Windows) `%VAR%` references using
+/// `lookup`. References to unset variables are kept verbatim.
+fn expand_vars(input: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    let is_name = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find(['$', '%']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        let (name, len) = if let Some(braced) = tail.strip_prefix("${") {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 3),
+                None => ("", 1),
+            }
+        } else if let Some(plain) = tail.strip_prefix('$') {
+            let end = plain.find(|c: char| !is_name(c)).unwrap_or(plain.len());
+            (&plain[..end], end + 1)
+        } else if cfg!(windows) {
+            let inner = &tail[1..];
+            match inner.find('%') {
+                Some(end) if end > 0 && inner[..end].chars().all(is_name) => (&inner[..end], end + 2),
+                _ => ("", 1),
+            }
+        } else {
+            ("", 1)
+        };
+
+        let starts_ok = name.chars().next().is_some_and(|c| !c.is_ascii_digit());
+        match lookup(name).filter(|_| starts_ok) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&tail[..len]),
+        }
+        rest = &tail[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Locate an executable on `PATH`, like `which`. On Windows the `PATHEXT`
+/// extensions are tried as well.
+pub fn find_executable(name: &str) -> Option<PathBuf> {
+    let path_var = env::var_os("PATH")?;
+    let extensions: Vec<String> = if cfg!(windows) {
+        env::var("PATHEXT")
+            .unwrap_or_else(|_| ".EXE;.CMD;.BAT".to_string())
+            .split(';')
+            .map(|ext| ext.to_string())
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+
+    for dir in env::split_paths(&path_var) {
+        for ext in &extensions {
+            let candidate = dir.join(format!("{name}{ext}"));
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+    }
+    None
 }
 
 pub fn ensure_dir(path: &Path) -> Result<(), AppError> {
@@ -54,6 +201,309 @@ pub fn slugify(input: &str) -> String {
     }
 }
 
+/// Reject a write of `len` bytes over the `max_write_bytes` setting.
+pub fn check_write_size(len: usize, limit: u64) -> Result<(), AppError> {
+    if len as u64 > limit {
+        return Err(AppError::Validation(format!(
+            "File is too large to save ({len} bytes; limit is {limit} bytes)"
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a SKILL.md of `len` bytes over the `max_skill_md_bytes` setting.
+pub fn check_skill_md_size(len: usize, limit: u64) -> Result<(), AppError> {
+    if len as u64 > limit {
+        return Err(AppError::Validation(format!(
+            "SKILL.md is too large to save ({len} bytes; limit is {limit} bytes)"
+        )));
+    }
+    Ok(())
+}
+
+/// Folder-safe key for a path: its slug, shortened, plus a 64-bit FNV-1a
+/// hash of the full path. The slug keeps keys readable while the hash
+/// tells apart paths that slug alike (`My_Skill` and `my-skill`, `b-c` and
+/// `b/c`, or non-ASCII names). FNV is used because, unlike `DefaultHasher`,
+/// its output can't change between Rust releases.
+pub fn stable_path_key(path: &str) -> String {
+    let hash = path.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    let slug = slugify(path);
+    let start = slug.len().saturating_sub(48);
+    let slug = slug[start..].trim_start_matches('-');
+    format!("{slug}-{hash:016x}")
+}
+
+/// Whether a folder name looks machine-generated rather than chosen by a
+/// person: blank, hidden, or holding a run of nine or more digits like the
+/// unix timestamps in `skillsyoga-1771641709` folders and temp clones.
+pub fn is_placeholder_folder_name(name: &str) -> bool {
+    let name = name.trim();
+    name.is_empty()
+        || name.starts_with('.')
+        || name
+            .split(|ch: char| !ch.is_ascii_digit())
+            .any(|digits| digits.len() >= 9)
+}
+
+/// Folder name for a copy of a skill: the source folder's own name, which
+/// agents may already refer to, unless it is a placeholder; then the slug
+/// of the skill's `name`.
+pub fn skill_folder_name(source_folder: &str, skill_name: &str) -> String {
+    if is_placeholder_folder_name(source_folder) {
+        slugify(skill_name)
+    } else {
+        source_folder.trim().to_string()
+    }
+}
+
+/// Whether files can be created in `dir`, probed by creating and removing a
+/// hidden marker file. Permission bits alone miss read-only mounts.
+pub fn dir_is_writable(dir: &Path) -> bool {
+    let marker = dir.join(format!(".skillsyoga-write-probe-{}", std::process::id()));
+    let created = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&marker)
+        .is_ok();
+    if created {
+        let _ = fs::remove_file(&marker);
+    }
+    created
+}
+
+/// Normalize a GitHub git remote (`https://`, `git@github.com:` or
+/// `ssh://git@github.com/`) to the `https://github.com/owner/repo` form the
+/// install and update commands accept. Non-GitHub remotes yield `None`.
+pub fn github_https_url(remote: &str) -> Option<String> {
+    let remote = remote.trim();
+    let path = remote
+        .strip_prefix("https://github.com/")
+        .or_else(|| remote.strip_prefix("git@github.com:"))
+        .or_else(|| remote.strip_prefix("ssh://git@github.com/"))?;
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let mut parts = path.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(repo), None) if !owner.is_empty() && !repo.is_empty() => {
+            Some(format!("https://github.com/{owner}/{repo}"))
+        }
+        _ => None,
+    }
+}
+
+/// Check a repository URL before it is handed to `git`: it must parse as
+/// an `http(s)` URL with a host, and must not contain whitespace or start
+/// with `-`, which git would read as an option.
+pub fn validate_repo_url(repo_url: &str) -> Result<(), AppError> {
+    let invalid = |reason: &str| {
+        AppError::Validation(format!("Invalid repository URL ({reason}): {repo_url}"))
+    };
+    if repo_url.starts_with('-') || repo_url.chars().any(|ch| ch.is_whitespace() || ch.is_control())
+    {
+        return Err(invalid("unexpected characters"));
+    }
+    let parsed = reqwest::Url::parse(repo_url).map_err(|_| invalid("not a URL"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("only http and https are supported"));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host"));
+    }
+    Ok(())
+}
+
+/// Major and minor version from `git --version` output such as
+/// `git version 2.39.3 (Apple Git-145)` or `git version 2.41.0.windows.1`.
+pub fn parse_git_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut parts = version.split(|ch: char| ch == '.' || ch.is_whitespace());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn is_registry_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment != "."
+        && segment != ".."
+        && segment
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+}
+
+/// Check a registry install request: `source` must be a GitHub
+/// `owner/repo` and `skill_id` a folder name or repo-relative path, both
+/// made of ASCII letters, digits, `-`, `_` and `.` with no `..` segments.
+/// When the registry gave a full `repo_url`, that is checked instead of
+/// `source`, which is then only a label.
+pub fn validate_registry_request(request: &InstallFromRegistryRequest) -> Result<(), AppError> {
+    if let Some(repo_url) = request.repo_url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        validate_repo_url(repo_url)?;
+    } else {
+        validate_registry_source(&request.source)?;
+    }
+    let skill_id = request.skill_id.trim_matches('/');
+    if !skill_id.split('/').all(is_registry_segment) {
+        return Err(AppError::Validation(format!(
+            "Invalid skill id: {}",
+            request.skill_id
+        )));
+    }
+    if let Some(git_ref) = &request.git_ref {
+        validate_git_ref(git_ref)?;
+    }
+    Ok(())
+}
+
+fn validate_registry_source(source: &str) -> Result<(), AppError> {
+    let mut parts = source.split('/');
+    let valid_source = matches!(
+        (parts.next(), parts.next(), parts.next()),
+        (Some(owner), Some(repo), None) if is_registry_segment(owner) && is_registry_segment(repo)
+    );
+    if !valid_source {
+        return Err(AppError::Validation(format!(
+            "Invalid registry source (expected owner/repo): {source}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check a branch, tag or commit to clone. The ref ends up as a `git`
+/// argument, so besides following `git check-ref-format` rules it must not
+/// start with `-` to be mistaken for an option.
+pub fn validate_git_ref(git_ref: &str) -> Result<(), AppError> {
+    let valid = !git_ref.is_empty()
+        && git_ref.len() <= 255
+        && !git_ref.starts_with(['-', '/', '.'])
+        && !git_ref.ends_with(['/', '.'])
+        && !git_ref.ends_with(".lock")
+        && !git_ref.contains("..")
+        && !git_ref.contains("//")
+        && !git_ref.contains("/.")
+        && git_ref
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.' | '/' | '+'));
+    if !valid {
+        return Err(AppError::Validation(format!("Invalid git ref: {git_ref}")));
+    }
+    Ok(())
+}
+
+/// Parse a `skillsyoga://install?source=owner/repo&skill=skill-id[&tool=id][&ref=branch]`
+/// link into a registry install request, validated with
+/// [`validate_registry_request`]. Unknown query parameters are ignored.
+pub fn parse_install_link(link: &str) -> Result<InstallFromRegistryRequest, AppError> {
+    let invalid = || AppError::Validation(format!("Unsupported link: {link}"));
+    let rest = link.trim().strip_prefix("skillsyoga://install").ok_or_else(invalid)?;
+    let query = rest.trim_start_matches('/').strip_prefix('?').ok_or_else(invalid)?;
+
+    let (mut source, mut skill_id, mut tool_id, mut git_ref) = (None, None, None, None);
+    for pair in query.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = urlencoding::decode(value)
+            .map_err(|_| invalid())?
+            .trim()
+            .to_string();
+        match key {
+            "source" => source = Some(value),
+            "skill" => skill_id = Some(value),
+            "tool" => tool_id = Some(value).filter(|v| !v.is_empty()),
+            "ref" => git_ref = Some(value).filter(|v| !v.is_empty()),
+            _ => {}
+        }
+    }
+
+    let request = InstallFromRegistryRequest {
+        source: source.ok_or_else(|| AppError::Validation("Link is missing `source`".to_string()))?,
+        skill_id: skill_id.ok_or_else(|| AppError::Validation("Link is missing `skill`".to_string()))?,
+        target_tool_id: tool_id,
+        git_ref,
+        repo_url: None,
+    };
+    validate_registry_request(&request)?;
+    Ok(request)
+}
+
+/// Check a registry base URL and strip trailing slashes, so the API paths
+/// can be appended to it.
+pub fn normalize_registry_url(registry_url: &str) -> Result<String, AppError> {
+    let trimmed = registry_url.trim().trim_end_matches('/');
+    let invalid =
+        |reason: &str| AppError::Validation(format!("Invalid registry URL ({reason}): {trimmed}"));
+    let parsed = reqwest::Url::parse(trimmed).map_err(|_| invalid("not a URL"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("only http and https are supported"));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host"));
+    }
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(invalid("query strings are not supported"));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Search URL on the registry at `registry_url` for one page of results. A
+/// blank `tool` is left out so the registry returns skills for every tool.
+pub fn registry_search_url(
+    registry_url: &str,
+    query: &str,
+    limit: u32,
+    offset: u32,
+    tool: Option<&str>,
+) -> Result<String, AppError> {
+    let base = normalize_registry_url(registry_url)?;
+    let mut url = reqwest::Url::parse(&format!("{base}/api/search"))
+        .map_err(|e| AppError::Validation(format!("Invalid registry URL {base}: {e}")))?;
+    {
+        let mut pairs = url.query_pairs_mut();
+        pairs
+            .append_pair("q", query)
+            .append_pair("limit", &limit.to_string())
+            .append_pair("offset", &offset.to_string());
+        if let Some(tool) = tool.map(str::trim).filter(|t| !t.is_empty()) {
+            pairs.append_pair("tool", tool);
+        }
+    }
+    Ok(url.to_string())
+}
+
+/// Length of the longest prefix of `bytes` that can be shown as UTF-8
+/// text, or `None` if the chunk isn't text. An incomplete character cut off
+/// at the end of the chunk is excluded from the prefix (unless the chunk
+/// ends the file) so the next read can start on a character boundary.
+pub fn utf8_chunk_len(bytes: &[u8], at_eof: bool) -> Option<usize> {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => Some(bytes.len()),
+        Err(err) if err.error_len().is_none() && !at_eof && err.valid_up_to() > 0 => {
+            Some(err.valid_up_to())
+        }
+        Err(_) => None,
+    }
+}
+
+/// Replace every occurrence of `secret` in `text` with `***`, e.g. to keep
+/// a token out of error messages built from subprocess output.
+pub fn scrub_secret(text: &str, secret: &str) -> String {
+    if secret.is_empty() {
+        return text.to_string();
+    }
+    text.replace(secret, "***")
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 pub fn unique_dir(base: &Path, preferred: &str) -> PathBuf {
     let candidate = base.join(preferred);
     if !candidate.exists() {
@@ -67,7 +517,7 @@ pub fn unique_dir(base: &Path, preferred: &str) -> PathBuf {
         }
     }
 
-    base.join(format!("{}-{}", preferred, now_iso()))
+    base.join(format!("{}-{}", preferred, unix_timestamp()))
 }
 
 pub fn unique_dir_with_timestamp_on_conflict(base: &Path, preferred: &str) -> PathBuf {
@@ -76,7 +526,7 @@ pub fn unique_dir_with_timestamp_on_conflict(base: &Path, preferred: &str) -> Pa
         return candidate;
     }
 
-    let ts = now_iso();
+    let ts = unix_timestamp();
     let with_ts = base.join(format!("{}-{}", preferred, ts));
     if !with_ts.exists() {
         return with_ts;
@@ -89,7 +539,7 @@ pub fn unique_dir_with_timestamp_on_conflict(base: &Path, preferred: &str) -> Pa
         }
     }
 
-    base.join(format!("{}-{}-{}", preferred, ts, now_iso()))
+    base.join(format!("{}-{}-{}", preferred, ts, unix_timestamp()))
 }
 
 /// Check that `path` is a descendant of one of the known tool skills roots.
@@ -105,9 +555,8 @@ pub fn is_path_under_skills_root(
     })?;
 
     let tools = resolve_tools(app)?;
-    for tool in &tools {
-        let skills_root = PathBuf::from(&tool.skills_path);
-        if let Ok(root) = skills_root.canonicalize() {
+    for skills_root in tools.iter().flat_map(|tool| &tool.skills_paths) {
+        if let Ok(root) = Path::new(skills_root).canonicalize() {
             if canonical.starts_with(&root) {
                 return Ok(());
             }
@@ -120,9 +569,248 @@ pub fn is_path_under_skills_root(
     )))
 }
 
+/// Split GitHub's `X-OAuth-Scopes` header (`repo, gist`) into scopes.
+pub fn parse_oauth_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::DEFAULT_REGISTRY_URL;
+
+    #[test]
+    fn stable_path_key_separates_paths_that_slug_alike() {
+        let pairs = [("/x/My_Skill", "/x/my-skill"), ("/a/b-c", "/a/b/c"), ("/r/技能", "/r/スキル")];
+        for (a, b) in pairs {
+            assert_eq!(slugify(a), slugify(b));
+            assert_ne!(stable_path_key(a), stable_path_key(b), "{a} vs {b}");
+        }
+        assert_eq!(stable_path_key("/x/My_Skill"), stable_path_key("/x/My_Skill"));
+    }
+
+    #[test]
+    fn check_skill_md_size_rejects_only_over_the_limit() {
+        assert!(check_skill_md_size(100, 100).is_ok());
+        match check_skill_md_size(101, 100) {
+            Err(AppError::Validation(message)) => {
+                assert!(message.contains("101 bytes"), "{message}");
+                assert!(message.contains("limit is 100 bytes"), "{message}");
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn github_https_url_normalizes_remotes() {
+        for remote in [
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo.git",
+            "git@github.com:owner/repo.git",
+            "ssh://git@github.com/owner/repo",
+        ] {
+            assert_eq!(
+                github_https_url(remote).as_deref(),
+                Some("https://github.com/owner/repo"),
+                "{remote}"
+            );
+        }
+        assert_eq!(github_https_url("https://gitlab.com/owner/repo"), None);
+        assert_eq!(github_https_url("https://github.com/owner"), None);
+    }
+
+    #[test]
+    fn parse_install_link_accepts_valid_links() {
+        let request =
+            parse_install_link("skillsyoga://install?source=owner/repo&skill=cat%2Fmy-skill&tool=codex")
+                .unwrap();
+        assert_eq!(request.source, "owner/repo");
+        assert_eq!(request.skill_id, "cat/my-skill");
+        assert_eq!(request.target_tool_id.as_deref(), Some("codex"));
+
+        let request = parse_install_link("skillsyoga://install/?skill=x&source=a/b").unwrap();
+        assert_eq!(request.target_tool_id, None);
+        assert_eq!(request.git_ref, None);
+
+        let request = parse_install_link("skillsyoga://install?source=a/b&skill=x&ref=dev");
+        assert_eq!(request.unwrap().git_ref.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn parse_install_link_rejects_bad_input() {
+        for link in [
+            "https://example.com/install?source=a/b&skill=x",
+            "skillsyoga://remove?source=a/b&skill=x",
+            "skillsyoga://install?skill=x",
+            "skillsyoga://install?source=a&skill=x",
+            "skillsyoga://install?source=a/b/c&skill=x",
+            "skillsyoga://install?source=a/b&skill=..%2Fetc",
+            "skillsyoga://install?source=a/b&skill=x%20y",
+            "skillsyoga://install?source=a/b&skill=x&ref=--upload-pack%3Devil",
+        ] {
+            assert!(parse_install_link(link).is_err(), "{link}");
+        }
+    }
+
+    #[test]
+    fn skill_folder_name_keeps_chosen_names() {
+        assert_eq!(skill_folder_name("PDF_Tools", "PDF Tools"), "PDF_Tools");
+        assert_eq!(skill_folder_name("résumé-writer", "Résumé Writer"), "résumé-writer");
+        assert_eq!(skill_folder_name("skillsyoga-1771641709", "Git Workflow"), "git-workflow");
+        assert_eq!(
+            skill_folder_name("skillsyoga-install-4242-1771641709123456789-0", "日本語"),
+            "skill"
+        );
+        assert_eq!(skill_folder_name(".tmp", "Notes"), "notes");
+        assert_eq!(skill_folder_name("v2025", "Notes"), "v2025");
+    }
+
+    #[test]
+    fn registry_search_url_encodes_paging_and_tool() {
+        let url = |query, limit, offset, tool| {
+            registry_search_url(DEFAULT_REGISTRY_URL, query, limit, offset, tool).unwrap()
+        };
+        assert_eq!(
+            url("pdf tools", 20, 0, None),
+            "https://skills.sh/api/search?q=pdf+tools&limit=20&offset=0"
+        );
+        assert_eq!(
+            url("a&b", 50, 100, Some(" claude-code ")),
+            "https://skills.sh/api/search?q=a%26b&limit=50&offset=100&tool=claude-code"
+        );
+        assert!(!url("x", 20, 0, Some("  ")).contains("tool="));
+        assert_eq!(
+            registry_search_url("https://skills.corp.example/registry/", "x", 1, 0, None).unwrap(),
+            "https://skills.corp.example/registry/api/search?q=x&limit=1&offset=0"
+        );
+    }
+
+    #[test]
+    fn registry_request_repo_url_replaces_the_github_source() {
+        let request = |source: &str, repo_url: Option<&str>| InstallFromRegistryRequest {
+            source: source.to_string(),
+            skill_id: "pdf".to_string(),
+            target_tool_id: None,
+            git_ref: None,
+            repo_url: repo_url.map(str::to_string),
+        };
+        assert!(validate_registry_request(&request("team/skills", None)).is_ok());
+        assert!(validate_registry_request(&request("group/sub/skills", None)).is_err());
+        let gitlab = Some("https://git.corp.example/group/sub/skills.git");
+        assert!(validate_registry_request(&request("group/sub/skills", gitlab)).is_ok());
+        let option = Some("--upload-pack=evil");
+        assert!(validate_registry_request(&request("team/skills", option)).is_err());
+    }
+
+    #[test]
+    fn normalize_registry_url_checks_scheme_and_host() {
+        assert_eq!(
+            normalize_registry_url(" http://10.0.0.5:8080/ ").unwrap(),
+            "http://10.0.0.5:8080"
+        );
+        for bad in ["", "skills.sh", "ftp://skills.sh", "https://", "https://x.dev/?a=1"] {
+            assert!(normalize_registry_url(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn validate_git_ref_rejects_option_like_and_malformed_refs() {
+        for ok in ["dev", "main", "v1.2.0", "feature/new-skill", "release+1", "0123abcd"] {
+            assert!(validate_git_ref(ok).is_ok(), "{ok}");
+        }
+        for bad in [
+            "",
+            "-b",
+            "--upload-pack=evil",
+            "a..b",
+            "/main",
+            "main/",
+            "a//b",
+            "a/.hidden",
+            "main.lock",
+            "dev branch",
+            "HEAD@{1}",
+            "a;rm",
+        ] {
+            assert!(validate_git_ref(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn validate_repo_url_accepts_only_http_urls() {
+        for ok in [
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo.git",
+            "http://git.example.com:8080/team/repo",
+        ] {
+            assert!(validate_repo_url(ok).is_ok(), "{ok}");
+        }
+        for bad in [
+            "",
+            "--upload-pack=touch /tmp/pwned",
+            "-c core.sshCommand=evil",
+            "github.com/owner/repo",
+            "file:///etc",
+            "ext::sh -c touch% /tmp/pwned",
+            "ssh://git@github.com/owner/repo",
+            "https://github.com/owner/repo --depth=1",
+            "https://github.com/owner/repo\n--bare",
+            "https://",
+        ] {
+            assert!(validate_repo_url(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn parse_git_version_reads_major_and_minor() {
+        assert_eq!(parse_git_version("git version 2.39.3 (Apple Git-145)\n"), Some((2, 39)));
+        assert_eq!(parse_git_version("git version 2.41.0.windows.1"), Some((2, 41)));
+        assert_eq!(parse_git_version("git version 1.7.1"), Some((1, 7)));
+        assert_eq!(parse_git_version("hub version 2.14.2"), None);
+        assert_eq!(parse_git_version("git version"), None);
+    }
+
+    #[test]
+    fn dir_is_writable_probes_without_leaving_marker() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-writable-{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+        assert!(dir_is_writable(&tmp));
+        assert_eq!(fs::read_dir(&tmp).unwrap().count(), 0);
+        assert!(!dir_is_writable(&tmp.join("missing")));
+        fs::remove_dir_all(&tmp).unwrap();
+    }
+
+    #[test]
+    fn utf8_chunk_len_stops_before_split_character() {
+        let text = "héllo".as_bytes();
+        assert_eq!(utf8_chunk_len(text, true), Some(text.len()));
+        // Cut inside the two-byte `é`.
+        assert_eq!(utf8_chunk_len(&text[..2], false), Some(1));
+        assert_eq!(utf8_chunk_len(&text[..2], true), None);
+        assert_eq!(utf8_chunk_len(&[0xff, 0x00, 0x10], false), None);
+    }
+
+    #[test]
+    fn scrub_secret_masks_every_occurrence() {
+        assert_eq!(
+            scrub_secret("https://ghp_abc@github.com: ghp_abc denied", "ghp_abc"),
+            "https://***@github.com: *** denied"
+        );
+        assert_eq!(scrub_secret("nothing here", ""), "nothing here");
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
 
     #[test]
     fn slugify_basic() {
@@ -158,6 +846,77 @@ mod tests {
         assert_eq!(slugify("中文"), "skill");
     }
 
+    fn test_env(name: &str) -> Option<String> {
+        match name {
+            "XDG_CONFIG_HOME" => Some("/xdg".to_string()),
+            "PROJECT" => Some("demo".to_string()),
+            _ => None,
+        }
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn me() -> Result<PathBuf, AppError> {
+        Ok(PathBuf::from("/home/me"))
+    }
+
+    fn no_home() -> Result<PathBuf, AppError> {
+        Err(AppError::NotFound("no home".to_string()))
+    }
+
+    #[test]
+    fn expand_path_with_handles_tilde_forms() {
+        assert_eq!(expand_path_with("~", me, no_env).unwrap(), PathBuf::from("/home/me"));
+        assert_eq!(
+            expand_path_with("~/skills", me, no_env).unwrap(),
+            PathBuf::from("/home/me/skills")
+        );
+        assert_eq!(expand_path_with("~other/x", me, no_env).unwrap(), PathBuf::from("~other/x"));
+    }
+
+    #[test]
+    fn expand_path_with_only_needs_home_for_tilde() {
+        assert_eq!(expand_path_with("/abs/x", no_home, no_env).unwrap(), PathBuf::from("/abs/x"));
+        assert!(expand_path_with("~/x", no_home, no_env).is_err());
+    }
+
+    #[test]
+    fn expand_path_with_respects_xdg_config_home() {
+        assert_eq!(
+            expand_path_with("~/.config/goose/skills", me, test_env).unwrap(),
+            PathBuf::from("/xdg/goose/skills")
+        );
+        assert_eq!(expand_path_with("~/.config", me, test_env).unwrap(), PathBuf::from("/xdg"));
+        assert_eq!(
+            expand_path_with("~/.configs/x", me, test_env).unwrap(),
+            PathBuf::from("/home/me/.configs/x")
+        );
+        assert_eq!(
+            expand_path_with("~/.config/goose", me, no_env).unwrap(),
+            PathBuf::from("/home/me/.config/goose")
+        );
+    }
+
+    #[test]
+    fn expand_vars_substitutes_dollar_forms() {
+        assert_eq!(expand_vars("/p/$PROJECT/s", &test_env), "/p/demo/s");
+        assert_eq!(expand_vars("/p/${PROJECT}s", &test_env), "/p/demos");
+        assert_eq!(expand_vars("$XDG_CONFIG_HOME/x", &test_env), "/xdg/x");
+        // Unset, malformed and bare references are kept as written.
+        assert_eq!(expand_vars("/p/$MISSING/${NOPE}", &test_env), "/p/$MISSING/${NOPE}");
+        assert_eq!(expand_vars("cost: $5 ${", &test_env), "cost: $5 ${");
+        assert_eq!(expand_vars("100%", &test_env), "100%");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn expand_vars_substitutes_percent_form_on_windows() {
+        assert_eq!(expand_vars("%PROJECT%\\x", &test_env), "demo\\x");
+        assert_eq!(expand_vars("%MISSING%", &test_env), "%MISSING%");
+    }
+
     #[test]
     fn expand_home_non_tilde_passthrough() {
         let p = expand_home("/abs/path").unwrap();
@@ -186,9 +945,34 @@ mod tests {
     }
 
     #[test]
-    fn now_iso_returns_nonempty_digits() {
+    fn find_executable_misses_unknown_binary() {
+        assert!(find_executable("skillsyoga-definitely-not-a-real-binary").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn find_executable_finds_sh() {
+        assert!(find_executable("sh").is_some());
+    }
+
+    #[test]
+    fn now_iso_is_rfc3339_utc() {
         let s = now_iso();
-        assert!(!s.is_empty());
-        assert!(s.chars().all(|c| c.is_ascii_digit()));
+        assert!(DateTime::parse_from_rfc3339(&s).is_ok(), "{s}");
+        assert!(s.ends_with('Z'));
+    }
+
+    #[test]
+    fn iso_timestamps_sort_chronologically() {
+        let at = |secs| iso_timestamp(UNIX_EPOCH + std::time::Duration::from_secs(secs));
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        assert_eq!(at(1_700_000_000), "2023-11-14T22:13:20Z");
+        assert!(at(999_999_999) < at(1_000_000_000));
+    }
+
+    #[test]
+    fn oauth_scopes_are_split_and_trimmed() {
+        assert_eq!(parse_oauth_scopes("repo, gist,read:org"), ["repo", "gist", "read:org"]);
+        assert!(parse_oauth_scopes(" ").is_empty());
     }
 }
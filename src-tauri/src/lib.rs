@@ -7,44 +7,170 @@ mod state;
 mod tools;
 
 use commands::{
-    copy_skill_to_tool, create_github_gist, create_skill_dir, delete_custom_tool, delete_skill, delete_skill_empty_dir,
-    delete_skill_entry, debug_log, discover_skills_paths, get_dashboard_data, install_from_registry, install_skill_from_github,
-    list_skill_files, read_skill_entry, read_skill_file, rename_skill_entry, reorder_tools,
-    reveal_in_finder, save_skill_entry, save_skill_file, search_skills, set_github_token,
-    set_skill_editor_default_mode, set_tool_enabled, update_skill_from_github, upsert_custom_tool,
+    add_project_root, add_tag_to_skills, clean_skill_folder_name, context_budget_report,
+    copy_skill_to_tool, create_github_gist, create_skill_dir, create_skill_from_template,
+    create_skill_gist, create_tool_from_discovered_root, dashboard_delta, debug_log,
+    delete_custom_tool, delete_skill, delete_skill_empty_dir, delete_skill_entry,
+    delete_skill_template, delete_skills, detect_overlapping_tool_roots, diff_state_backup,
+    discover_skills_paths, duplicate_skill, explain_merge, export_as_plugin, export_inventory,
+    export_skill, export_state, find_miscased_manifests, find_similar_skills, fix_manifest_case,
+    get_dashboard_data, get_log_path, get_skill_tree, guess_skill_source, handle_install_link,
+    import_skill_from_zip, import_state, install_all_skills_from_github, install_from_registry,
+    install_skill_from_github, install_skill_from_url, is_skill_writable, list_skill_external_refs,
+    list_skill_files, list_skill_snapshots, move_skill, move_skills, normalize_skill_permissions,
+    open_tool_config, prepare_for_publish, preview_github_install, preview_slug, probe_custom_tool,
+    read_skill_by_id, read_skill_entry, read_skill_entry_meta, read_skill_entry_range,
+    read_skill_file, recent_skills, recover_provenance, remove_project_root, remove_tag_from_skills,
+    rename_skill, rename_skill_entry, reorder_tools, rescan_tool, restore_skill_snapshot,
+    reveal_app_data_dir, reveal_in_finder, save_skill_entry, save_skill_entry_base64,
+    save_skill_file, scan_common_home_locations, search_local_skills, search_skills,
+    set_context_budget, set_default_install_tool, set_editor_settings, set_github_token,
+    set_max_skill_md_bytes, set_max_write_bytes, set_registry_url, set_skill_editor_default_mode,
+    set_skill_enabled, set_skill_merge_mode, set_skill_pinned, set_tool_cli, set_tool_enabled,
+    snapshot_skill, sync_skill_to_all_tools, take_pending_install_links, tool_path_details,
+    update_skill_from_github, upsert_custom_tool, upsert_skill_template, validate_skill,
+    DashboardSnapshots,
 };
 
+use tauri_plugin_deep_link::DeepLinkExt;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let builder = tauri::Builder::default();
+
+    // On Windows and Linux a clicked link starts a second process. This
+    // plugin has to be registered first so that process hands its URLs to
+    // the running one, where they reach `on_open_url`, and exits.
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+        use tauri::Manager;
+
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.unminimize();
+            let _ = window.set_focus();
+        }
+    }));
+
+    builder
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_deep_link::init())
+        .manage(DashboardSnapshots::default())
+        .setup(|app| {
+            // Linux and Windows dev builds only know the scheme once it is
+            // registered at runtime; bundles register it at install time.
+            #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+            app.deep_link().register_all()?;
+
+            let handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    handle_install_link(&handle, url.as_str());
+                }
+            });
+            // A link that launched the app is not delivered to `on_open_url`.
+            if let Some(urls) = app.deep_link().get_current()? {
+                for url in urls {
+                    handle_install_link(app.handle(), url.as_str());
+                }
+            }
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_dashboard_data,
+            rescan_tool,
+            explain_merge,
+            context_budget_report,
+            dashboard_delta,
+            recent_skills,
+            find_similar_skills,
             set_tool_enabled,
+            set_tool_cli,
             read_skill_file,
+            read_skill_by_id,
+            validate_skill,
             list_skill_files,
+            get_skill_tree,
+            list_skill_external_refs,
+            find_miscased_manifests,
+            fix_manifest_case,
+            is_skill_writable,
+            normalize_skill_permissions,
             read_skill_entry,
+            read_skill_entry_range,
+            read_skill_entry_meta,
+            preview_slug,
             save_skill_file,
+            snapshot_skill,
+            list_skill_snapshots,
+            restore_skill_snapshot,
             save_skill_entry,
+            save_skill_entry_base64,
+            set_max_write_bytes,
+            set_max_skill_md_bytes,
+            set_registry_url,
             create_skill_dir,
+            create_skill_from_template,
+            upsert_skill_template,
+            delete_skill_template,
             rename_skill_entry,
+            clean_skill_folder_name,
+            rename_skill,
             delete_skill_entry,
             delete_skill_empty_dir,
             delete_skill,
+            delete_skills,
+            move_skills,
+            move_skill,
+            duplicate_skill,
             install_skill_from_github,
+            install_all_skills_from_github,
+            preview_github_install,
             update_skill_from_github,
+            recover_provenance,
             search_skills,
+            search_local_skills,
             install_from_registry,
+            install_skill_from_url,
+            import_skill_from_zip,
+            take_pending_install_links,
+            guess_skill_source,
+            add_tag_to_skills,
+            remove_tag_from_skills,
             copy_skill_to_tool,
+            sync_skill_to_all_tools,
             upsert_custom_tool,
+            probe_custom_tool,
+            create_tool_from_discovered_root,
             discover_skills_paths,
+            scan_common_home_locations,
             delete_custom_tool,
+            add_project_root,
+            remove_project_root,
+            tool_path_details,
+            detect_overlapping_tool_roots,
+            diff_state_backup,
+            export_state,
+            import_state,
             reorder_tools,
             reveal_in_finder,
+            open_tool_config,
+            reveal_app_data_dir,
             set_github_token,
             set_skill_editor_default_mode,
+            set_skill_pinned,
+            set_skill_enabled,
+            set_editor_settings,
+            set_skill_merge_mode,
+            set_default_install_tool,
+            set_context_budget,
+            prepare_for_publish,
+            export_skill,
+            export_as_plugin,
+            export_inventory,
             create_github_gist,
-            debug_log
+            create_skill_gist,
+            debug_log,
+            get_log_path
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,5 +1,5 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8,9 +8,17 @@ pub struct ToolInfo {
     pub name: String,
     pub kind: String,
     pub config_path: String,
+    /// The first of `skills_paths`; new skills are installed here.
     pub skills_path: String,
+    /// Every root scanned for skills, in priority order.
+    pub skills_paths: Vec<String>,
     pub detected: bool,
+    /// What established `detected`: `marker`, `binary`, `skillsDir`,
+    /// `configDir`, or `none`.
+    pub detection_method: String,
     pub enabled: bool,
+    /// Terminal tool rather than a GUI app.
+    pub cli: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +35,44 @@ pub struct SkillInfo {
     pub github_repo_url: Option<String>,
     #[serde(default)]
     pub github_skill_path: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub license: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// False when the SKILL.md was renamed so this tool no longer loads it.
+    #[serde(default = "default_skill_enabled")]
+    pub enabled: bool,
+    /// Size of the SKILL.md in bytes.
+    #[serde(default)]
+    pub skill_md_bytes: u64,
+    /// Other directories merged into this entry whose SKILL.md differs.
+    /// Only set on dashboard entries when duplicates are merged.
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+}
+
+fn string_or_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(path) => vec![path],
+        OneOrMany::Many(paths) => paths,
+    })
+}
+
+fn default_skill_enabled() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,12 +85,204 @@ pub struct SourceInfo {
     pub tags: Vec<String>,
 }
 
+/// One skill in an inventory export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryRow {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub tools: Vec<String>,
+    pub path: String,
+    pub word_count: usize,
+    pub updated_at: String,
+}
+
+/// Tools whose skills roots resolve to the same directory (`kind: "same"`)
+/// or where `nested_root` lies inside `root` (`kind: "nested"`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootOverlap {
+    pub kind: String,
+    pub root: String,
+    pub nested_root: Option<String>,
+    pub tool_ids: Vec<String>,
+}
+
+/// Combined SKILL.md body size of a tool's skills against the tool's
+/// context budget. Sizes are in bytes of markdown body (frontmatter
+/// excluded).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetReport {
+    pub tool_id: String,
+    pub budget_bytes: u64,
+    pub total_bytes: u64,
+    pub total_words: usize,
+    pub over_budget: bool,
+    /// Largest skills first.
+    pub skills: Vec<BudgetEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetEntry {
+    pub path: String,
+    pub name: String,
+    pub body_bytes: u64,
+    pub words: usize,
+    /// This skill alone exceeds the budget.
+    pub over_budget: bool,
+}
+
+/// A zipped checkpoint of a skill folder. `created_at` is an RFC 3339
+/// timestamp, like `updated_at` on skills.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillSnapshot {
+    pub id: String,
+    pub created_at: String,
+    pub size_bytes: u64,
+}
+
+/// Skills that the configured merge mode shows as a single dashboard entry.
+/// `members[0]` is the entry that is displayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeGroup {
+    pub key: String,
+    pub name: String,
+    pub identical: bool,
+    pub members: Vec<MergeMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeMember {
+    pub path: String,
+    pub tool_id: String,
+}
+
+/// A skill directory whose manifest is named with the wrong case, e.g.
+/// `Skill.md`, and so is skipped by the scan on case-sensitive filesystems.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MiscaseHit {
+    pub dir: String,
+    pub file_name: String,
+}
+
+/// An absolute link found in one of a skill's markdown files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRef {
+    pub relative_path: String,
+    pub line: usize,
+    pub url: String,
+    pub text: Option<String>,
+}
+
+/// A byte range of a skill file. `encoding` is `utf8` when `content` is
+/// text and `base64` for binary data; `length` is the number of bytes
+/// actually covered, so the next chunk starts at `offset + length`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillEntryChunk {
+    pub content: String,
+    pub encoding: String,
+    pub offset: u64,
+    pub length: u64,
+    pub total_size: u64,
+    pub eof: bool,
+}
+
+/// A whole skill file with its metadata. `encoding` is `utf8` for text,
+/// `base64` for binary data, or `none` when the file is too large to
+/// return and `content` is empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillEntryContent {
+    pub content: String,
+    pub encoding: String,
+    pub size: u64,
+    pub modified_at: Option<String>,
+    pub is_binary: bool,
+}
+
+/// Two installed skills whose bodies look alike. `score` is the Jaccard
+/// similarity of their word shingles, in `0.0..=1.0`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarPair {
+    pub a_path: String,
+    pub a_name: String,
+    pub b_path: String,
+    pub b_name: String,
+    pub score: f32,
+}
+
+/// An installed skill with a line matching a local search. `file` is
+/// relative to the skill folder and `line` is 1-based.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalSearchHit {
+    pub skill: SkillInfo,
+    pub file: String,
+    pub line: usize,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionChange {
+    pub relative_path: String,
+    /// Octal modes, e.g. `"0777"` -> `"0644"`.
+    pub from: String,
+    pub to: String,
+}
+
+/// Result of normalizing a skill's file modes. `supported` is false on
+/// platforms without Unix permissions, where nothing is changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionReport {
+    pub supported: bool,
+    pub changed: Vec<PermissionChange>,
+}
+
+/// Where an installed skill came from, as stored in its sidecar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceInfo {
+    pub repo_url: String,
+    pub skill_path: Option<String>,
+    pub commit: Option<String>,
+}
+
+/// Where a tool looks for skills, compared with the path it ships with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolPathDetail {
+    pub tool_id: String,
+    pub name: String,
+    pub kind: String,
+    /// Shipped default for built-in tools; `None` for custom tools.
+    pub default_skills_path: Option<String>,
+    /// Path actually scanned.
+    pub skills_path: String,
+    pub exists: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DashboardStats {
     pub installed_skills: usize,
     pub detected_tools: usize,
     pub enabled_tools: usize,
+    /// Skills left out of the scan because they couldn't be loaded; each
+    /// one is also listed in the dashboard warnings.
+    #[serde(default)]
+    pub skipped_skills: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +295,45 @@ pub struct DashboardData {
     pub app_data_dir: String,
     pub has_github_token: bool,
     pub skill_editor_default_mode: String,
+    pub editor_settings: EditorSettings,
+    pub skill_merge_mode: String,
+    pub default_install_tool_id: Option<String>,
+    pub skill_templates: Vec<SkillTemplate>,
+    pub max_skill_md_bytes: u64,
+    /// Non-fatal problems hit while building the dashboard, e.g. a tool
+    /// whose skills root could not be scanned in time.
+    pub warnings: Vec<String>,
+    /// Opaque token to pass to `dashboard_delta` for the next update.
+    pub token: String,
+}
+
+/// One tool's freshly scanned skills, for patching that slice of the
+/// dashboard, with stats recomputed across all tools.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolRescan {
+    pub tool_id: String,
+    pub skills: Vec<SkillInfo>,
+    pub stats: DashboardStats,
+}
+
+/// Changes since the dashboard identified by a previous token. When that
+/// token is unknown or expired, `reset` is set and `added`/`tools` carry the
+/// full current lists instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardDelta {
+    pub token: String,
+    pub reset: bool,
+    pub added: Vec<SkillInfo>,
+    pub changed: Vec<SkillInfo>,
+    /// Paths of skills that are no longer listed.
+    pub removed: Vec<String>,
+    /// Tools that were added or whose state (e.g. enabled toggle) changed.
+    pub tools: Vec<ToolInfo>,
+    pub removed_tools: Vec<String>,
+    pub stats: DashboardStats,
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,16 +343,92 @@ pub struct AppState {
     pub custom_tools: Vec<CustomToolInput>,
     #[serde(default)]
     pub tool_order: Vec<String>,
+    /// Only used when no OS keychain is available; see `load_github_token`.
     #[serde(default)]
     pub github_token: Option<String>,
     #[serde(default = "default_skill_editor_default_mode")]
     pub skill_editor_default_mode: String,
+    /// Skill directory paths the user pinned to the top of the dashboard.
+    #[serde(default)]
+    pub pinned_skills: HashSet<String>,
+    #[serde(default)]
+    pub editor_settings: EditorSettings,
+    /// How the dashboard collapses duplicate skills: `none`, `name`, or `slug`.
+    #[serde(default = "default_skill_merge_mode")]
+    pub skill_merge_mode: String,
+    /// Largest single file write accepted from the frontend, in bytes.
+    #[serde(default = "default_max_write_bytes")]
+    pub max_write_bytes: u64,
+    /// Largest SKILL.md accepted on save, in bytes. Agents load the whole
+    /// file into context, so this is much lower than `max_write_bytes`.
+    #[serde(default = "default_max_skill_md_bytes")]
+    pub max_skill_md_bytes: u64,
+    /// Tool used by install commands when no target is given.
+    #[serde(default)]
+    pub default_install_tool_id: Option<String>,
+    /// User overrides of the built-in tools' CLI/GUI classification.
+    #[serde(default)]
+    pub tool_cli_overrides: HashMap<String, bool>,
+    /// Per-tool context budgets in bytes, overriding the built-in defaults.
+    #[serde(default)]
+    pub context_budgets: HashMap<String, u64>,
+    /// Ids of skills the user disabled for their tool; a skill id embeds
+    /// its tool id. Agents only see the manifest rename, and this keeps the
+    /// choice in state so backups and imports carry it.
+    #[serde(default)]
+    pub disabled_skills: HashSet<String>,
+    /// Base URL of the skills registry used for search.
+    #[serde(default = "default_registry_url")]
+    pub registry_url: String,
+    /// User-defined templates offered next to the built-in ones.
+    #[serde(default)]
+    pub skill_templates: Vec<SkillTemplate>,
+    /// Project directories whose skills roots are listed as `project` tools.
+    #[serde(default)]
+    pub project_roots: Vec<ProjectRoot>,
 }
 
 fn default_skill_editor_default_mode() -> String {
     "view".to_string()
 }
 
+fn default_skill_merge_mode() -> String {
+    "none".to_string()
+}
+
+fn default_max_write_bytes() -> u64 {
+    4 * 1024 * 1024
+}
+
+fn default_max_skill_md_bytes() -> u64 {
+    256 * 1024
+}
+
+/// Registry used for search until the user points the app at another one.
+pub const DEFAULT_REGISTRY_URL: &str = "https://skills.sh";
+
+fn default_registry_url() -> String {
+    DEFAULT_REGISTRY_URL.to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EditorSettings {
+    pub word_wrap: bool,
+    pub tab_size: u8,
+    pub preview_by_default: bool,
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self {
+            word_wrap: true,
+            tab_size: 2,
+            preview_by_default: false,
+        }
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self {
@@ -84,16 +437,104 @@ impl Default for AppState {
             tool_order: vec![],
             github_token: None,
             skill_editor_default_mode: default_skill_editor_default_mode(),
+            pinned_skills: HashSet::new(),
+            editor_settings: EditorSettings::default(),
+            skill_merge_mode: default_skill_merge_mode(),
+            max_write_bytes: default_max_write_bytes(),
+            max_skill_md_bytes: default_max_skill_md_bytes(),
+            default_install_tool_id: None,
+            tool_cli_overrides: HashMap::new(),
+            context_budgets: HashMap::new(),
+            disabled_skills: HashSet::new(),
+            registry_url: default_registry_url(),
+            skill_templates: vec![],
+            project_roots: vec![],
         }
     }
 }
 
+/// A path a bulk operation could not handle, with the reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathFailure {
+    pub path: String,
+    pub error: String,
+}
+
+/// Outcome of a bulk delete: paths moved to the trash and paths that
+/// could not be deleted, with the reason.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteReport {
+    pub deleted: Vec<String>,
+    pub failed: Vec<PathFailure>,
+}
+
+/// Outcome of `move_skills`: the skills in their new place and the source
+/// paths that could not be moved.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveReport {
+    pub moved: Vec<SkillInfo>,
+    pub failed: Vec<PathFailure>,
+}
+
+/// Outcome of `install_all_skills_from_github`: the skills installed and,
+/// by their path in the repository, the ones that failed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallReport {
+    pub installed: Vec<SkillInfo>,
+    pub failed: Vec<PathFailure>,
+}
+
+/// A gist made by `create_skill_gist`. `skipped` lists, by relative path,
+/// the skill files left out: binary, empty, over the size limit, or
+/// flattening to a name already taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillGist {
+    pub url: String,
+    pub skipped: Vec<String>,
+}
+
+/// A tool toggle whose value differs between current state and a backup.
+/// `None` means the tool has no explicit toggle (it follows detection).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleChange {
+    pub tool_id: String,
+    pub current: Option<bool>,
+    pub backup: Option<bool>,
+}
+
+/// What restoring a state backup would change. Custom tools are compared
+/// by id; the GitHub token is only reported as changed, never revealed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDiff {
+    pub added_tools: Vec<String>,
+    pub removed_tools: Vec<String>,
+    /// Custom tools present in both whose name or paths differ.
+    pub changed_tools: Vec<String>,
+    /// Ids of projects registered with `add_project_root`.
+    pub added_projects: Vec<String>,
+    pub removed_projects: Vec<String>,
+    pub toggle_changes: Vec<ToggleChange>,
+    pub tool_order_changed: bool,
+    pub github_token_changed: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveSkillRequest {
     pub content: String,
     pub target_tool_id: String,
     pub existing_path: Option<String>,
+    /// Name to derive the folder from when creating a skill whose content
+    /// has no frontmatter `name`.
+    #[serde(default)]
+    pub folder_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,7 +542,24 @@ pub struct SaveSkillRequest {
 pub struct InstallSkillRequest {
     pub repo_url: String,
     pub skill_path: Option<String>,
-    pub target_tool_id: String,
+    /// Falls back to the default install tool when absent.
+    #[serde(default)]
+    pub target_tool_id: Option<String>,
+    /// Branch, tag or commit to install instead of the default branch.
+    #[serde(default, rename = "ref")]
+    pub git_ref: Option<String>,
+}
+
+/// A skill `install_skill_from_github` would install, as found in the repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallPreview {
+    pub name: String,
+    pub description: String,
+    /// Folder within the repository; `None` when the skill is the repo root.
+    pub skill_path: Option<String>,
+    pub target_path: Option<String>,
+    pub files: Vec<SkillFileEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,13 +570,50 @@ pub struct UpdateSkillFromGithubRequest {
     pub skill_path: Option<String>,
 }
 
+/// A project registered with `add_project_root`. The skills roots under
+/// `path` are found once, when the project is added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRoot {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub skills_paths: Vec<String>,
+}
+
+/// A user-defined SKILL.md template. `body` is the whole file, with
+/// `{{name}}` and `{{description}}` filled in when a skill is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillTemplate {
+    pub id: String,
+    pub name: String,
+    pub body: String,
+}
+
+/// Who a GitHub token authenticates as. `has_gist_scope` is `None` for
+/// fine-grained tokens, which don't report OAuth scopes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GithubTokenInfo {
+    pub login: String,
+    pub scopes: Vec<String>,
+    pub has_gist_scope: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomToolInput {
     pub id: String,
     pub name: String,
     pub config_path: String,
-    pub skills_path: String,
+    /// One or more skills roots; older state files store a single string.
+    /// Each must be absolute or `~`-based; project-local roots are added
+    /// with `add_project_root` instead.
+    #[serde(deserialize_with = "string_or_list")]
+    pub skills_path: Vec<String>,
+    #[serde(default)]
+    pub cli: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -129,19 +624,49 @@ pub struct SearchSkillResult {
     pub name: String,
     pub installs: u64,
     pub source: String,
+    /// Clone URL for registries that don't host skills on GitHub. When
+    /// absent, `source` is a GitHub `owner/repo`.
+    #[serde(default, alias = "repo_url")]
+    pub repo_url: Option<String>,
+    /// Enabled tools that already have a skill with this slug. Filled in
+    /// locally; the registry never sends it.
+    #[serde(default)]
+    pub installed_in: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SearchSkillsResponse {
     pub skills: Vec<SearchSkillResult>,
+    #[serde(default, alias = "count")]
+    pub total: Option<u64>,
+}
+
+/// One page of registry search results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSkillsPage {
+    pub skills: Vec<SearchSkillResult>,
+    /// Total matches reported by the registry; `None` when it doesn't say.
+    pub total: Option<u64>,
+    pub limit: u32,
+    pub offset: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InstallFromRegistryRequest {
     pub source: String,
+    /// Skill folder name, or a repo-relative path such as `category/skill-id`.
     pub skill_id: String,
-    pub target_tool_id: String,
+    /// Falls back to the default install tool when absent.
+    #[serde(default)]
+    pub target_tool_id: Option<String>,
+    /// Branch, tag or commit to install instead of the default branch.
+    #[serde(default, rename = "ref")]
+    pub git_ref: Option<String>,
+    /// Repository to clone verbatim instead of `https://github.com/{source}`.
+    #[serde(default)]
+    pub repo_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -160,6 +685,18 @@ pub struct SkillFileEntry {
     pub is_dir: bool,
 }
 
+/// One file or folder in a skill's tree; `children` is empty for files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillTreeNode {
+    pub name: String,
+    pub relative_path: String,
+    pub is_dir: bool,
+    pub children: Vec<SkillTreeNode>,
+}
+
+/// Text-only write: `content` is written as UTF-8. Use
+/// `SaveSkillEntryBase64Request` for binary files.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveSkillEntryRequest {
@@ -168,6 +705,14 @@ pub struct SaveSkillEntryRequest {
     pub content: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveSkillEntryBase64Request {
+    pub path: String,
+    pub relative_path: String,
+    pub content_base64: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CopySkillToToolRequest {
@@ -175,15 +720,103 @@ pub struct CopySkillToToolRequest {
     pub target_tool_id: String,
     #[serde(default = "default_copy_conflict_strategy")]
     pub conflict_strategy: String,
+    #[serde(default)]
+    pub link_mode: LinkMode,
+}
+
+/// How `copy_skill_to_tool` materializes the skill in the target tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkMode {
+    /// Independent physical copy.
+    #[default]
+    Copy,
+    /// Directory symlink pointing at the source; edits stay in sync.
+    Symlink,
+    /// Directory tree whose files are hard links to the source files.
+    Hardlink,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopySkillResult {
+    pub skill: SkillInfo,
+    /// Mode actually used; differs from the request when linking failed
+    /// and the skill was copied instead.
+    pub link_mode: LinkMode,
+    pub fallback_reason: Option<String>,
+}
+
+/// Outcome of `sync_skill_to_all_tools`: the copies made, and the enabled
+/// tools left alone with the reason (already has the skill, or the copy
+/// failed).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    pub copied: Vec<SkillInfo>,
+    pub skipped: Vec<SyncSkip>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSkip {
+    pub tool_id: String,
+    pub reason: String,
 }
 
 fn default_copy_conflict_strategy() -> String {
     "timestampedCopy".to_string()
 }
 
+/// What `probe_custom_tool` found at a custom tool's paths, after `~`
+/// expansion. `overlapping_tools` names the tools with a skills path equal
+/// to, inside, or containing this one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomToolProbe {
+    pub config_path: String,
+    pub config_exists: bool,
+    pub skills_path: String,
+    pub skills_exists: bool,
+    pub skills_is_dir: bool,
+    pub skill_count: usize,
+    pub overlapping_tools: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DiscoveredSkillsRoot {
     pub path: String,
     pub skill_count: usize,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillIssue {
+    /// Either `error` or `warning`.
+    pub severity: String,
+    /// Frontmatter field the issue relates to, if any.
+    pub field: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishBundle {
+    pub path: String,
+    pub skill_path: String,
+    pub warnings: Vec<SkillIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceGuess {
+    pub source_id: String,
+    pub source_name: String,
+    pub repo_url: String,
+    /// Path of the matching skill inside the source repository.
+    pub skill_path: String,
+    /// 0.0–1.0; 1.0 means the SKILL.md content is identical.
+    pub confidence: f32,
+    pub reason: String,
+}
@@ -1,25 +1,41 @@
 use std::{
-    collections::{HashMap, HashSet},
-    fs, io,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    ffi::OsStr,
+    fs,
+    hash::{Hash, Hasher},
+    io,
     path::{Path, PathBuf},
-    sync::{Mutex, OnceLock},
-    time::{SystemTime, UNIX_EPOCH},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime},
 };
 
 use crate::error::AppError;
-use crate::helpers::now_iso;
-use crate::models::{DiscoveredSkillsRoot, SkillInfo, ToolInfo};
+use crate::helpers::{iso_timestamp, now_iso, slugify};
+#[cfg(unix)]
+use crate::models::PermissionChange;
+use crate::models::{
+    DiscoveredSkillsRoot, MergeGroup, MergeMember, ProvenanceInfo, SkillFileEntry, SkillInfo,
+    SkillIssue, SkillTreeNode, ToolInfo,
+};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
 use serde::{Deserialize, Serialize};
 
-const SOURCE_META_FILE: &str = ".skillsyoga-source.json";
+pub const SOURCE_META_FILE: &str = ".skillsyoga-source.json";
 
 /// Entry in the skill-metadata cache. A cached `SkillInfo` is reusable when
 /// both the SKILL.md and optional source-metadata file still match their
 /// recorded mtimes — i.e. nothing has been touched on disk since last scan.
+/// `content_hash` is the hash of the SKILL.md text that scan parsed.
 #[derive(Clone)]
 struct CachedSkill {
     skill_md_mtime: Option<SystemTime>,
     source_meta_mtime: Option<SystemTime>,
+    content_hash: u64,
     skill: SkillInfo,
 }
 
@@ -32,11 +48,200 @@ fn file_mtime(path: &Path) -> Option<SystemTime> {
     fs::metadata(path).ok().and_then(|m| m.modified().ok())
 }
 
+/// Size of the file at `path` in bytes, or 0 when it can't be read.
+pub fn file_len(path: &Path) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or_default()
+}
+
 fn mtime_to_string(mtime: Option<SystemTime>) -> String {
-    mtime
-        .and_then(|s| s.duration_since(UNIX_EPOCH).ok())
-        .map(|d| d.as_secs().to_string())
-        .unwrap_or_else(now_iso)
+    mtime.map(iso_timestamp).unwrap_or_else(now_iso)
+}
+
+/// Skills each tool's latest scan couldn't load, keyed by skills root, as
+/// `path: error` messages.
+fn scan_skips() -> &'static Mutex<HashMap<PathBuf, Vec<String>>> {
+    static SKIPS: OnceLock<Mutex<HashMap<PathBuf, Vec<String>>>> = OnceLock::new();
+    SKIPS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The skills `collect_skills_from_tool` skipped on its latest scan of
+/// `tool`, as `path: error` messages.
+pub fn skipped_skills(tool: &ToolInfo) -> Vec<String> {
+    let skips = scan_skips()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    tool.skills_paths
+        .iter()
+        .filter_map(|root| skips.get(Path::new(root)))
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// Files already reported as converted from a legacy encoding, plus any
+/// notices not yet picked up by the dashboard.
+#[derive(Default)]
+struct EncodingNotices {
+    seen: HashSet<PathBuf>,
+    pending: Vec<String>,
+}
+
+fn encoding_notices() -> &'static Mutex<EncodingNotices> {
+    static NOTICES: OnceLock<Mutex<EncodingNotices>> = OnceLock::new();
+    NOTICES.get_or_init(|| Mutex::new(EncodingNotices::default()))
+}
+
+/// Notices about manifests decoded from a non-UTF-8 encoding since the last
+/// call. Each file is reported once per session.
+pub fn take_encoding_notices() -> Vec<String> {
+    let mut notices = encoding_notices()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    std::mem::take(&mut notices.pending)
+}
+
+/// How far into a file `looks_binary` sniffs for NUL bytes.
+pub const BINARY_SNIFF_BYTES: usize = 8000;
+
+/// Whether a bundled file should be treated as binary: it has a NUL byte
+/// near the start or isn't valid UTF-8.
+pub fn looks_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) || std::str::from_utf8(bytes).is_err()
+}
+
+/// Decode manifest bytes to UTF-8. Returns the source encoding's name when
+/// the bytes were not UTF-8. A UTF-8 BOM is dropped; UTF-16 is recognised
+/// by its BOM, anything else is guessed with chardetng.
+pub fn decode_text(bytes: &[u8]) -> (String, Option<&'static str>) {
+    if let Some((encoding, bom_len)) = encoding_rs::Encoding::for_bom(bytes) {
+        let body = &bytes[bom_len..];
+        if encoding == encoding_rs::UTF_8 {
+            if let Ok(text) = std::str::from_utf8(body) {
+                return (text.to_string(), None);
+            }
+        } else {
+            let (text, _) = encoding.decode_without_bom_handling(body);
+            return (text.into_owned(), Some(encoding.name()));
+        }
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return (text.to_string(), None);
+    }
+
+    let mut detector = chardetng::EncodingDetector::new(chardetng::Iso2022JpDetection::Deny);
+    detector.feed(bytes, true);
+    let encoding = detector.guess(None, chardetng::Utf8Detection::Deny);
+    let (text, _) = encoding.decode_without_bom_handling(bytes);
+    (text.into_owned(), Some(encoding.name()))
+}
+
+/// Read a SKILL.md as UTF-8 text, decoding legacy encodings instead of
+/// failing. The first time a file needs converting a notice is queued for
+/// the dashboard; saving the file afterwards writes it back as UTF-8.
+pub fn read_skill_text(path: &Path) -> Result<String, AppError> {
+    let bytes = fs::read(path)?;
+    let (text, encoding) = decode_text(&bytes);
+    if let Some(encoding) = encoding {
+        let mut notices = encoding_notices()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if notices.seen.insert(path.to_path_buf()) {
+            notices.pending.push(format!(
+                "{} is encoded as {encoding}; it was converted for display and will be saved as UTF-8",
+                path.display()
+            ));
+        }
+    }
+    Ok(text)
+}
+
+/// File name a disabled skill's SKILL.md is renamed to, so agents stop
+/// loading it while the folder stays in place.
+pub const DISABLED_SKILL_MD: &str = "SKILL.md.disabled";
+
+/// A skill directory's manifest: `SKILL.md`, or `SKILL.md.disabled` when
+/// only the disabled one exists.
+pub fn skill_manifest_path(skill_dir: &Path) -> PathBuf {
+    let active = skill_dir.join("SKILL.md");
+    let disabled = skill_dir.join(DISABLED_SKILL_MD);
+    if !active.exists() && disabled.is_file() {
+        disabled
+    } else {
+        active
+    }
+}
+
+/// Nest a flat entry list (as from `collect_skill_entries`, parents before
+/// their contents) into a tree. Siblings are sorted folders first, then by
+/// name; an entry whose parent folder isn't listed is dropped.
+pub fn build_skill_tree(entries: &[SkillFileEntry]) -> Vec<SkillTreeNode> {
+    fn sort(nodes: &mut [SkillTreeNode]) {
+        nodes.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+        for node in nodes {
+            sort(&mut node.children);
+        }
+    }
+
+    let mut roots: Vec<SkillTreeNode> = vec![];
+    for entry in entries {
+        let (parents, name) = match entry.relative_path.rsplit_once('/') {
+            Some((parents, name)) => (Some(parents), name),
+            None => (None, entry.relative_path.as_str()),
+        };
+        let mut siblings = &mut roots;
+        let mut found_parent = true;
+        for part in parents.into_iter().flat_map(|p| p.split('/')) {
+            match siblings.iter().position(|node| node.is_dir && node.name == part) {
+                Some(index) => siblings = &mut siblings[index].children,
+                None => {
+                    found_parent = false;
+                    break;
+                }
+            }
+        }
+        if found_parent {
+            siblings.push(SkillTreeNode {
+                name: name.to_string(),
+                relative_path: entry.relative_path.clone(),
+                is_dir: entry.is_dir,
+                children: vec![],
+            });
+        }
+    }
+    sort(&mut roots);
+    roots
+}
+
+fn is_enabled_manifest(skill_md_path: &Path) -> bool {
+    skill_md_path.file_name() != Some(OsStr::new(DISABLED_SKILL_MD))
+}
+
+/// Enable or disable a skill by renaming its manifest between `SKILL.md`
+/// and `SKILL.md.disabled`. Already being in the requested state is fine;
+/// having both files is not, since either rename would clobber one.
+pub fn set_manifest_enabled(skill_dir: &Path, enabled: bool) -> Result<PathBuf, AppError> {
+    let active = skill_dir.join("SKILL.md");
+    let disabled = skill_dir.join(DISABLED_SKILL_MD);
+    let (from, to) = if enabled {
+        (disabled, active)
+    } else {
+        (active, disabled)
+    };
+    match (from.is_file(), to.is_file()) {
+        (true, true) => Err(AppError::Validation(format!(
+            "Both SKILL.md and {DISABLED_SKILL_MD} exist in {}",
+            skill_dir.display()
+        ))),
+        (false, true) => Ok(to),
+        (false, false) => Err(AppError::NotFound(format!(
+            "SKILL.md not found in {}",
+            skill_dir.display()
+        ))),
+        (true, false) => {
+            fs::rename(&from, &to)?;
+            Ok(to)
+        }
+    }
 }
 
 fn build_skill_info(
@@ -45,14 +250,42 @@ fn build_skill_info(
     skill_md_path: &Path,
     skill_md_mtime: Option<SystemTime>,
 ) -> Result<SkillInfo, AppError> {
-    let content = fs::read_to_string(skill_md_path)?;
+    let content = read_skill_text(skill_md_path)?;
+    Ok(skill_info_from_content(tool, skill_dir, skill_md_path, skill_md_mtime, &content))
+}
+
+/// A skill's id: `tool_id:dir_name` for skills in the tool's first root,
+/// and `tool_id#N:dir_name` for its `N`th (zero-based) one, so folders of
+/// the same name in two roots of one tool stay distinct.
+pub fn skill_id(tool: &ToolInfo, skill_dir: &Path) -> String {
+    let dir_name = dir_display_name(skill_dir);
+    let root_index = tool
+        .skills_paths
+        .iter()
+        .map(Path::new)
+        .position(|root| skill_dir == root || skill_dir.parent() == Some(root))
+        .unwrap_or(0);
+    if root_index == 0 {
+        format!("{}:{dir_name}", tool.id)
+    } else {
+        format!("{}#{root_index}:{dir_name}", tool.id)
+    }
+}
+
+fn skill_info_from_content(
+    tool: &ToolInfo,
+    skill_dir: &Path,
+    skill_md_path: &Path,
+    skill_md_mtime: Option<SystemTime>,
+    content: &str,
+) -> SkillInfo {
     let dir_name = dir_display_name(skill_dir);
-    let skill_meta = parse_skill_metadata(&content, &dir_name);
+    let skill_meta = parse_skill_metadata(content, &dir_name);
     let source_meta = read_skill_source_meta(skill_dir);
     let modified = mtime_to_string(skill_md_mtime);
 
-    Ok(SkillInfo {
-        id: format!("{}:{}", tool.id, dir_name),
+    SkillInfo {
+        id: skill_id(tool, skill_dir),
         name: skill_meta.name,
         description: skill_meta.description,
         path: skill_dir.to_string_lossy().to_string(),
@@ -61,11 +294,22 @@ fn build_skill_info(
         updated_at: modified,
         github_repo_url: source_meta.as_ref().map(|meta| meta.repo_url.clone()),
         github_skill_path: source_meta.and_then(|meta| meta.skill_path),
-    })
+        pinned: false,
+        tags: skill_meta.tags,
+        license: skill_meta.license,
+        version: skill_meta.version,
+        author: skill_meta.author,
+        enabled: is_enabled_manifest(skill_md_path),
+        skill_md_bytes: file_len(skill_md_path),
+        conflicts: vec![],
+    }
 }
 
 /// Return a cached `SkillInfo` if the files on disk match the recorded
 /// mtimes; otherwise parse fresh and update the cache in place.
+///
+/// The cache lock is never held across file reads, so a scan stuck on a
+/// slow mount can't block other tools' scans through the shared cache.
 fn load_skill_cached(
     tool: &ToolInfo,
     skill_dir: &Path,
@@ -74,28 +318,62 @@ fn load_skill_cached(
     let md_mtime = file_mtime(skill_md_path);
     let src_mtime = file_mtime(&skill_dir.join(SOURCE_META_FILE));
 
-    if let Ok(mut cache) = skill_cache().lock() {
+    if let Ok(cache) = skill_cache().lock() {
         if let Some(entry) = cache.get(skill_dir) {
-            if entry.skill_md_mtime == md_mtime && entry.source_meta_mtime == src_mtime {
+            // A rename keeps the mtime, so enabling or disabling a skill is
+            // only noticed through the manifest name.
+            if entry.skill_md_mtime == md_mtime
+                && entry.source_meta_mtime == src_mtime
+                && entry.skill.enabled == is_enabled_manifest(skill_md_path)
+            {
                 return Ok(entry.skill.clone());
             }
         }
+    }
+
+    let content = read_skill_text(skill_md_path)?;
+    let skill = skill_info_from_content(tool, skill_dir, skill_md_path, md_mtime, &content);
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
 
-        let skill = build_skill_info(tool, skill_dir, skill_md_path, md_mtime)?;
+    // A poisoned lock just means we skip caching; the fresh parse is still
+    // returned rather than turning a transient panic into a failure here.
+    if let Ok(mut cache) = skill_cache().lock() {
         cache.insert(
             skill_dir.to_path_buf(),
             CachedSkill {
                 skill_md_mtime: md_mtime,
                 source_meta_mtime: src_mtime,
+                content_hash: hasher.finish(),
                 skill: skill.clone(),
             },
         );
-        return Ok(skill);
     }
+    Ok(skill)
+}
+
+/// Build a fresh `SkillInfo` for a skill directory owned by `tool`.
+pub fn load_skill_info(tool: &ToolInfo, skill_dir: &Path) -> Result<SkillInfo, AppError> {
+    let skill_md_path = skill_manifest_path(skill_dir);
+    let mtime = file_mtime(&skill_md_path);
+    build_skill_info(tool, skill_dir, &skill_md_path, mtime)
+}
+
+/// Every skill in the metadata cache, as of its tool's most recent scan.
+pub fn cached_skills() -> Vec<SkillInfo> {
+    skill_cache()
+        .lock()
+        .map(|cache| cache.values().map(|entry| entry.skill.clone()).collect())
+        .unwrap_or_default()
+}
 
-    // Lock poisoned — fall back to a non-cached parse. Avoids turning a
-    // transient panic in another thread into a permanent failure here.
-    build_skill_info(tool, skill_dir, skill_md_path, md_mtime)
+/// Hash of the SKILL.md text the most recent scan of `skill_dir` parsed.
+pub fn cached_content_hash(skill_dir: &Path) -> Option<u64> {
+    skill_cache()
+        .lock()
+        .ok()?
+        .get(skill_dir)
+        .map(|entry| entry.content_hash)
 }
 
 /// Drop cache entries beneath `scope_root` that weren't observed in the
@@ -113,6 +391,9 @@ struct SkillSourceMeta {
     repo_url: String,
     #[serde(default)]
     skill_path: Option<String>,
+    /// Commit the skill was installed from, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    commit: Option<String>,
 }
 
 /// Extract the display name from a directory path, falling back to "skill".
@@ -127,10 +408,16 @@ pub fn dir_display_name(path: &Path) -> String {
 pub struct SkillMeta {
     pub name: String,
     pub description: String,
+    pub tags: Vec<String>,
+    pub license: Option<String>,
+    pub version: Option<String>,
+    pub author: Option<String>,
 }
 
 /// Split content into optional YAML frontmatter and markdown body.
 fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    // Editors on Windows like to prepend a BOM, which isn't whitespace.
+    let content = content.trim_start_matches('\u{feff}');
     let trimmed = content.trim_start();
     if !trimmed.starts_with("---") {
         return (None, content);
@@ -151,6 +438,97 @@ fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
     }
 }
 
+/// Frontmatter fields read from a SKILL.md. Scalars of any YAML type are
+/// taken as strings (so `version: 1.2` works), and `tags` may be a
+/// sequence or a comma-separated string. The spec's nested
+/// `metadata.author` / `metadata.version` are used when the top-level
+/// fields are absent.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Frontmatter {
+    #[serde(deserialize_with = "yaml_scalar")]
+    name: Option<String>,
+    #[serde(deserialize_with = "yaml_scalar")]
+    description: Option<String>,
+    #[serde(deserialize_with = "yaml_scalar")]
+    license: Option<String>,
+    #[serde(deserialize_with = "yaml_scalar")]
+    version: Option<String>,
+    #[serde(deserialize_with = "yaml_scalar")]
+    author: Option<String>,
+    #[serde(deserialize_with = "yaml_string_list")]
+    tags: Vec<String>,
+    metadata: FrontmatterMetadata,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FrontmatterMetadata {
+    #[serde(deserialize_with = "yaml_scalar")]
+    author: Option<String>,
+    #[serde(deserialize_with = "yaml_scalar")]
+    version: Option<String>,
+}
+
+fn scalar_to_string(value: serde_norway::Value) -> Option<String> {
+    let text = match value {
+        serde_norway::Value::String(s) => s,
+        serde_norway::Value::Number(n) => n.to_string(),
+        serde_norway::Value::Bool(b) => b.to_string(),
+        _ => return None,
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn yaml_scalar<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    Ok(scalar_to_string(serde_norway::Value::deserialize(d)?))
+}
+
+fn yaml_string_list<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+    Ok(match serde_norway::Value::deserialize(d)? {
+        serde_norway::Value::Sequence(items) => {
+            items.into_iter().filter_map(scalar_to_string).collect()
+        }
+        other => scalar_to_string(other)
+            .map(|s| {
+                s.split(',')
+                    .map(|item| item.trim().to_string())
+                    .filter(|item| !item.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
+/// Deserialize frontmatter with a YAML parser. Empty frontmatter yields
+/// the defaults.
+fn parse_frontmatter(frontmatter: &str) -> Result<Frontmatter, serde_norway::Error> {
+    if frontmatter.trim().is_empty() {
+        return Ok(Frontmatter::default());
+    }
+    let mut parsed: Frontmatter = serde_norway::from_str(frontmatter)?;
+    parsed.author = parsed.author.or(parsed.metadata.author.take());
+    parsed.version = parsed.version.or(parsed.metadata.version.take());
+    Ok(parsed)
+}
+
+/// Frontmatter fields, tolerating invalid YAML. Plenty of real skills use
+/// unquoted colons in descriptions (`description: Use when: ...`), which a
+/// YAML parser rejects; for those the line-based reader below recovers the
+/// top-level fields instead of dropping the whole frontmatter.
+fn frontmatter_fields(frontmatter: &str) -> Frontmatter {
+    parse_frontmatter(frontmatter).unwrap_or_else(|_| Frontmatter {
+        name: yaml_string_value(frontmatter, "name"),
+        description: yaml_string_value(frontmatter, "description"),
+        license: yaml_string_value(frontmatter, "license"),
+        version: yaml_string_value(frontmatter, "version"),
+        author: yaml_string_value(frontmatter, "author"),
+        tags: yaml_list_value(frontmatter, "tags"),
+        metadata: FrontmatterMetadata::default(),
+    })
+}
+
 /// Extract a top-level YAML string value, handling inline values and
 /// multi-line folded/literal blocks with indented continuation lines.
 /// Supports: `key: value`, `key: "value"`, `key: 'value'`,
@@ -193,15 +571,207 @@ fn yaml_string_value(frontmatter: &str, key: &str) -> Option<String> {
     None
 }
 
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches('"').trim_matches('\'').to_string()
+}
+
+/// Extract a top-level YAML list value. Supports inline flow sequences
+/// (`tags: [a, b]`), block sequences (`tags:` followed by `- a` lines), and
+/// a plain comma-separated scalar (`tags: a, b`).
+fn yaml_list_value(frontmatter: &str, key: &str) -> Vec<String> {
+    let prefix = format!("{}:", key);
+    let mut lines = frontmatter.lines();
+
+    while let Some(line) = lines.next() {
+        if !line.starts_with(&prefix) {
+            continue;
+        }
+        let after_key = line[prefix.len()..].trim();
+
+        let items: Vec<String> = if after_key.is_empty() {
+            lines
+                .take_while(|cont| cont.starts_with(' ') || cont.starts_with('\t') || cont.starts_with('-'))
+                .filter_map(|cont| cont.trim().strip_prefix('-').map(unquote))
+                .collect()
+        } else {
+            let inner = after_key
+                .strip_prefix('[')
+                .and_then(|v| v.strip_suffix(']'))
+                .unwrap_or(after_key);
+            inner.split(',').map(unquote).collect()
+        };
+        return items.into_iter().filter(|item| !item.is_empty()).collect();
+    }
+    vec![]
+}
+
+/// Replace, insert, or (with `value: None`) remove a top-level frontmatter
+/// field, leaving every other line of the file untouched. `value` is the
+/// already-rendered YAML after `key: `. Continuation lines of the old value
+/// (indented lines or `- ` sequence items) are replaced with it. Content
+/// without frontmatter gets a new frontmatter block when a value is set.
+pub fn set_frontmatter_field(content: &str, key: &str, value: Option<&str>) -> String {
+    let rendered = value.map(|v| format!("{key}: {v}"));
+    let lead = content.len() - content.trim_start().len();
+    let rest = &content[lead..];
+
+    let close = rest
+        .strip_prefix("---")
+        .and_then(|after_open| after_open.find("\n---").map(|pos| pos + 3));
+    let Some(close) = close else {
+        return match rendered {
+            Some(line) => format!("---\n{line}\n---\n\n{content}"),
+            None => content.to_string(),
+        };
+    };
+
+    // lines[0] is the remainder of the opening `---` line.
+    let mut lines: Vec<&str> = rest[3..close].split('\n').collect();
+    let prefix = format!("{key}:");
+    let start = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.starts_with(&prefix))
+        .map(|idx| idx + 1);
+
+    match start {
+        Some(start) => {
+            let mut end = start + 1;
+            while end < lines.len()
+                && (lines[end].starts_with(' ') || lines[end].starts_with('\t') || lines[end].starts_with('-'))
+            {
+                end += 1;
+            }
+            match rendered.as_deref() {
+                Some(line) => {
+                    lines.splice(start..end, [line]);
+                }
+                None => {
+                    lines.drain(start..end);
+                }
+            }
+        }
+        None => {
+            if let Some(line) = rendered.as_deref() {
+                lines.push(line);
+            }
+        }
+    }
+
+    format!("{}---{}{}", &content[..lead], lines.join("\n"), &rest[close..])
+}
+
+/// Render a list as an inline YAML flow sequence, e.g. `[a, b]`.
+pub fn yaml_inline_list(items: &[String]) -> String {
+    format!("[{}]", items.join(", "))
+}
+
+/// Number of whitespace-separated words in the markdown body, ignoring
+/// frontmatter.
+pub fn body_word_count(content: &str) -> usize {
+    split_frontmatter(content).1.split_whitespace().count()
+}
+
+/// Size in bytes of the markdown body, ignoring frontmatter.
+pub fn body_len(content: &str) -> usize {
+    split_frontmatter(content).1.len()
+}
+
+/// Absolute `http(s)://` links in markdown, as `(line, url, link text)`.
+/// Covers `[text](url)`, `<url>` autolinks and bare URLs; trailing
+/// sentence punctuation is not treated as part of a bare URL. Lines are
+/// 1-based.
+pub fn extract_external_links(content: &str) -> Vec<(usize, String, Option<String>)> {
+    let mut links = vec![];
+    for (idx, line) in content.lines().enumerate() {
+        let mut from = 0;
+        while let Some(found) = ["https://", "http://"]
+            .iter()
+            .filter_map(|scheme| line[from..].find(scheme))
+            .min()
+        {
+            let start = from + found;
+            let len = line[start..]
+                .find(|c: char| c.is_whitespace() || matches!(c, ')' | '>' | '"' | '\'' | ']'))
+                .unwrap_or(line.len() - start);
+            let url = line[start..start + len].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+            from = start + len.max(1);
+            if url.len() <= "https://".len() {
+                continue;
+            }
+            let before = &line[..start];
+            let text = before
+                .strip_suffix("](")
+                .and_then(|head| head.rfind('[').map(|open| head[open + 1..].to_string()))
+                .filter(|text| !text.trim().is_empty());
+            links.push((idx + 1, url.to_string(), text));
+        }
+    }
+    links
+}
+
+/// Hashed 3-word shingles of the markdown body, lowercased with punctuation
+/// dropped. Bodies under three words fall back to single-word shingles so
+/// they still compare by vocabulary.
+pub fn body_shingles(content: &str) -> HashSet<u64> {
+    let body = split_frontmatter(content).1.to_lowercase();
+    let words: Vec<&str> = body
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let width = if words.len() < 3 { 1 } else { 3 };
+    words
+        .windows(width)
+        .map(|window| {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect()
+}
+
+/// Jaccard similarity of two shingle sets, in `0.0..=1.0`. Two empty bodies
+/// count as dissimilar rather than identical.
+pub fn shingle_similarity(a: &HashSet<u64>, b: &HashSet<u64>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// The frontmatter `name`, without any heading or folder-name fallback.
+pub fn frontmatter_name(content: &str) -> Option<String> {
+    split_frontmatter(content)
+        .0
+        .and_then(|fm| frontmatter_fields(fm).name)
+}
+
+/// `content` with `name` added at the top of its frontmatter when the
+/// frontmatter has no `name`. Content without frontmatter, or already
+/// naming the skill, comes back unchanged.
+pub fn with_frontmatter_name(content: &str, name: &str) -> Result<String, AppError> {
+    if split_frontmatter(content).0.is_none() || frontmatter_name(content).is_some() {
+        return Ok(content.to_string());
+    }
+    // `split_frontmatter` found the opening `---`, so this can't miss.
+    let open_end = content.find("---").unwrap_or(0) + 3;
+    let (open, rest) = content.split_at(open_end);
+    let newline = if rest.starts_with("\r\n") { "\r\n" } else { "\n" };
+    Ok(format!("{open}{newline}name: {}{rest}", yaml_quote(name)?))
+}
+
 /// Parse SKILL.md content following the Agent Skills spec.
-/// Extracts `name` and `description` from YAML frontmatter.
+/// Extracts `name`, `description` and the optional fields from YAML
+/// frontmatter.
 /// Falls back to first `#` heading for name and first body paragraph
 /// for description when frontmatter fields are missing (legacy files).
 pub fn parse_skill_metadata(content: &str, fallback_name: &str) -> SkillMeta {
     let (frontmatter, body) = split_frontmatter(content);
 
-    let fm_name = frontmatter.and_then(|fm| yaml_string_value(fm, "name"));
-    let fm_desc = frontmatter.and_then(|fm| yaml_string_value(fm, "description"));
+    let fields = frontmatter.map(frontmatter_fields).unwrap_or_default();
+    let fm_name = fields.name;
+    let fm_desc = fields.description;
 
     let name = fm_name.unwrap_or_else(|| {
         body.lines()
@@ -225,126 +795,802 @@ pub fn parse_skill_metadata(content: &str, fallback_name: &str) -> SkillMeta {
         "No description".to_string()
     });
 
-    SkillMeta { name, description }
+    SkillMeta {
+        name,
+        description,
+        tags: fields.tags,
+        license: fields.license,
+        version: fields.version,
+        author: fields.author,
+    }
 }
 
-pub fn collect_skills_from_tool(tool: &ToolInfo) -> Result<Vec<SkillInfo>, AppError> {
-    let root = PathBuf::from(&tool.skills_path);
-    if !root.exists() || !root.is_dir() {
-        return Ok(vec![]);
+/// Maximum `name` length allowed by the Agent Skills spec.
+const SPEC_NAME_MAX_LEN: usize = 64;
+/// Maximum `description` length allowed by the Agent Skills spec.
+const SPEC_DESCRIPTION_MAX_LEN: usize = 1024;
+
+fn skill_issue(severity: &str, field: Option<&str>, message: String) -> SkillIssue {
+    SkillIssue {
+        severity: severity.to_string(),
+        field: field.map(|f| f.to_string()),
+        message,
     }
+}
 
-    let mut skills = vec![];
-    let mut live_paths: HashSet<PathBuf> = HashSet::new();
+/// Check SKILL.md content against the Agent Skills spec. Unlike
+/// `parse_skill_metadata`, nothing falls back silently: each problem is
+/// reported as an `error` (agents will likely ignore the skill) or a
+/// `warning` (works, but not spec-compliant).
+pub fn validate_skill_content(content: &str) -> Vec<SkillIssue> {
+    let mut issues = vec![];
+    let (frontmatter, body) = split_frontmatter(content);
 
-    let root_skill_file = root.join("SKILL.md");
-    if root_skill_file.exists() {
-        let info = load_skill_cached(tool, &root, &root_skill_file)?;
-        live_paths.insert(root.clone());
-        skills.push(info);
-    }
+    let Some(fm) = frontmatter else {
+        let message = if content.trim_start().starts_with("---") {
+            "Frontmatter is not closed (expected a `---` line after it)"
+        } else {
+            "Missing YAML frontmatter (expected a leading `---` block)"
+        };
+        issues.push(skill_issue("error", None, message.to_string()));
+        return issues;
+    };
 
-    let entries = fs::read_dir(&root)?;
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
-        if !path.is_dir() {
-            continue;
+    if let Err(err) = parse_frontmatter(fm) {
+        issues.push(skill_issue(
+            "error",
+            None,
+            format!("Invalid YAML in frontmatter: {err}"),
+        ));
+    }
+    let fields = frontmatter_fields(fm);
+
+    match fields.name {
+        None => issues.push(skill_issue(
+            "error",
+            Some("name"),
+            "Missing `name` in frontmatter".to_string(),
+        )),
+        Some(name) => {
+            if name.chars().count() > SPEC_NAME_MAX_LEN {
+                issues.push(skill_issue(
+                    "error",
+                    Some("name"),
+                    format!("`name` exceeds {SPEC_NAME_MAX_LEN} characters"),
+                ));
+            }
+            let spec_chars = name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+            if !spec_chars || name.starts_with('-') || name.ends_with('-') {
+                issues.push(skill_issue(
+                    "warning",
+                    Some("name"),
+                    "`name` should use only lowercase letters, digits, and hyphens".to_string(),
+                ));
+            }
         }
+    }
 
-        let skill_file = path.join("SKILL.md");
-        if !skill_file.exists() {
-            continue;
+    match fields.description {
+        None => issues.push(skill_issue(
+            "error",
+            Some("description"),
+            "Missing `description` in frontmatter".to_string(),
+        )),
+        Some(desc) => {
+            if desc.chars().count() > SPEC_DESCRIPTION_MAX_LEN {
+                issues.push(skill_issue(
+                    "error",
+                    Some("description"),
+                    format!("`description` exceeds {SPEC_DESCRIPTION_MAX_LEN} characters"),
+                ));
+            }
         }
+    }
 
-        let info = load_skill_cached(tool, &path, &skill_file)?;
-        live_paths.insert(path);
-        skills.push(info);
+    if body.trim().is_empty() {
+        issues.push(skill_issue(
+            "warning",
+            None,
+            "SKILL.md body is empty".to_string(),
+        ));
     }
 
-    prune_skill_cache(&root, &live_paths);
-    Ok(skills)
+    issues
 }
 
-pub fn discover_skills_roots(root: &Path) -> Vec<DiscoveredSkillsRoot> {
-    if !root.exists() || !root.is_dir() {
-        return vec![];
+/// Longest snippet `find_matching_line` returns, in characters.
+const SNIPPET_MAX_CHARS: usize = 160;
+
+/// The first line of `text` containing `needle` (already lowercased),
+/// ignoring case: its 1-based number and the trimmed line, cut down to
+/// `SNIPPET_MAX_CHARS` around the match.
+pub fn find_matching_line(text: &str, needle: &str) -> Option<(usize, String)> {
+    text.lines().enumerate().find_map(|(index, line)| {
+        let line = line.trim();
+        let lower = line.to_lowercase();
+        let at = lower.find(needle)?;
+        let chars: Vec<char> = line.chars().collect();
+        if chars.len() <= SNIPPET_MAX_CHARS {
+            return Some((index + 1, line.to_string()));
+        }
+        // Lowercasing can change byte lengths, so locate the match by chars.
+        let match_char = lower[..at].chars().count().min(chars.len());
+        let start = match_char.saturating_sub(SNIPPET_MAX_CHARS / 4);
+        let end = (start + SNIPPET_MAX_CHARS).min(chars.len());
+        let start = end.saturating_sub(SNIPPET_MAX_CHARS);
+        let mut snippet: String = chars[start..end].iter().collect();
+        if start > 0 {
+            snippet.insert(0, '…');
+        }
+        if end < chars.len() {
+            snippet.push('…');
+        }
+        Some((index + 1, snippet))
+    })
+}
+
+/// The trimmed title, its slug and the one-line description for a new
+/// skill, checked against the spec's length limits.
+fn template_fields(name: &str, description: &str) -> Result<(String, String, String), AppError> {
+    let title = name.trim();
+    let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.is_empty() || description.is_empty() {
+        return Err(AppError::Validation(
+            "A skill needs both a name and a description".to_string(),
+        ));
+    }
+    let slug = slugify(title);
+    if slug.len() > SPEC_NAME_MAX_LEN {
+        return Err(AppError::Validation(format!(
+            "Skill name is too long (the folder name is limited to {SPEC_NAME_MAX_LEN} characters)"
+        )));
     }
+    if description.chars().count() > SPEC_DESCRIPTION_MAX_LEN {
+        return Err(AppError::Validation(format!(
+            "Description exceeds {SPEC_DESCRIPTION_MAX_LEN} characters"
+        )));
+    }
+    Ok((title.to_string(), slug, description))
+}
 
-    let mut stack: Vec<(PathBuf, usize)> = vec![(root.to_path_buf(), 0)];
-    let mut discovered: HashMap<PathBuf, usize> = HashMap::new();
+/// `value` as a YAML scalar, quoted when it would otherwise parse as
+/// something else (`a: b`, a leading `#`, `"` or `-`, ...).
+fn yaml_quote(value: &str) -> Result<String, AppError> {
+    let yaml = serde_norway::to_string(value)
+        .map_err(|e| AppError::Validation(format!("Invalid frontmatter value: {e}")))?;
+    Ok(yaml.trim_end().to_string())
+}
 
-    while let Some((dir, depth)) = stack.pop() {
-        let has_skill_file = dir.join("SKILL.md").is_file();
+/// A new SKILL.md from a user-defined template body: `{{name}}` becomes
+/// the slug of `name` and `{{description}}` the one-line description.
+/// Inside the frontmatter both are YAML-escaped; the rest of the body gets
+/// them as is.
+pub fn fill_skill_template(
+    body: &str,
+    name: &str,
+    description: &str,
+) -> Result<String, AppError> {
+    let (_, slug, description) = template_fields(name, description)?;
+    let fill = |text: &str, name: &str, description: &str| {
+        text.replace("{{name}}", name)
+            .replace("{{description}}", description)
+    };
+    let frontmatter_end = body
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.find("\n---").map(|end| end + "---\n".len()));
+    let Some(end) = frontmatter_end else {
+        return Ok(fill(body, &slug, &description));
+    };
+    let (frontmatter, rest) = body.split_at(end);
+    Ok(format!(
+        "{}{}",
+        fill(frontmatter, &yaml_quote(&slug)?, &yaml_quote(&description)?),
+        fill(rest, &slug, &description)
+    ))
+}
 
-        let mut child_skill_count = 0usize;
-        let entries = match fs::read_dir(&dir) {
-            Ok(entries) => entries,
-            Err(_) => continue,
-        };
+/// Built-in starting points for `render_skill_template`; the first is the
+/// default.
+pub const SKILL_TEMPLATE_IDS: &[&str] = &["basic", "tool-use", "workflow"];
 
-        for entry in entries.flatten() {
-            let child = entry.path();
-            if !child.is_dir() {
-                continue;
-            }
+/// A new SKILL.md from one of `SKILL_TEMPLATE_IDS`, with spec-compliant
+/// frontmatter: `name` is the slug of `name`, and `description` is folded
+/// onto one line. The human-readable `name` heads the body.
+pub fn render_skill_template(
+    template_id: &str,
+    name: &str,
+    description: &str,
+) -> Result<String, AppError> {
+    let (title, slug, description) = template_fields(name, description)?;
+    let body = match template_id {
+        "basic" => "\
+## Instructions
 
-            let name = entry.file_name().to_string_lossy().to_string();
-            if name.starts_with('.') {
-                continue;
-            }
+Describe step by step what the agent should do when this skill applies.
 
-            if child.join("SKILL.md").is_file() {
-                child_skill_count += 1;
-            }
+## Examples
 
-            if depth < 6 {
-                let lower = name.to_ascii_lowercase();
-                if lower != "node_modules" && lower != "target" && lower != "dist" && lower != "build" {
-                    stack.push((child, depth + 1));
-                }
-            }
-        }
+- Example request and what a good response looks like.
+",
+        "tool-use" => "\
+## When to use
 
-        if has_skill_file || child_skill_count > 0 {
-            let count = if child_skill_count > 0 { child_skill_count } else { 1 };
-            discovered
-                .entry(dir)
-                .and_modify(|existing| *existing = (*existing).max(count))
-                .or_insert(count);
-        }
-    }
+Use this skill when the task needs the tool or script it describes.
 
-    let mut out: Vec<DiscoveredSkillsRoot> = discovered
-        .into_iter()
-        .map(|(path, skill_count)| DiscoveredSkillsRoot {
-            path: path.to_string_lossy().to_string(),
-            skill_count,
-        })
-        .collect();
+## Usage
 
-    out.sort_by(|a, b| {
-        b.skill_count
-            .cmp(&a.skill_count)
-            .then_with(|| a.path.len().cmp(&b.path.len()))
-            .then_with(|| a.path.cmp(&b.path))
-    });
-    out
+1. Check the inputs the tool needs.
+2. Run the tool, for example `scripts/run.sh <args>`.
+3. Read the output and report the result.
+
+## Notes
+
+- List flags, limits and common errors here.
+",
+        "workflow" => "\
+## When to use
+
+Use this skill for the multi-step process it describes.
+
+## Steps
+
+1. First step.
+2. Second step.
+3. Check the result before finishing.
+
+## Done when
+
+- Describe what a finished result looks like.
+",
+        other => {
+            return Err(AppError::Validation(format!(
+                "Unknown skill template: {other} (expected one of {})",
+                SKILL_TEMPLATE_IDS.join(", ")
+            )));
+        }
+    };
+    Ok(format!(
+        "---\nname: {slug}\ndescription: {}\n---\n\n# {title}\n\n{body}",
+        yaml_quote(&description)?
+    ))
 }
 
-pub fn merge_skills(mut list: Vec<SkillInfo>) -> Vec<SkillInfo> {
-    list.sort_by(|a, b| {
-        a.name
-            .cmp(&b.name)
-            .then_with(|| a.source.cmp(&b.source))
-            .then_with(|| a.path.cmp(&b.path))
-    });
-    list
+/// Render a repository README for a published skill.
+pub fn render_skill_readme(meta: &SkillMeta, slug: &str) -> String {
+    format!(
+        "# {name}\n\n{description}\n\n## Installation\n\nCopy `skills/{slug}/` into your agent's skills directory, e.g. `~/.claude/skills/{slug}/`.\n\n## Contents\n\n- `skills/{slug}/SKILL.md` — skill instructions\n",
+        name = meta.name,
+        description = meta.description,
+    )
 }
 
-pub fn discover_skill_dir(root: &Path, depth: usize) -> Option<PathBuf> {
-    if depth > 4 {
-        return None;
+/// Scan a tool's skills root.
+///
+/// If the root itself contains a SKILL.md, the whole root is treated as a
+/// single skill and its subfolders are not scanned — any nested SKILL.md
+/// files belong to that skill (e.g. bundled examples), not to the tool.
+/// Otherwise every immediate subfolder with a SKILL.md is one skill.
+/// Skills disabled for this tool still appear, with `enabled` unset.
+/// A skill that can't be loaded is left out and recorded for
+/// `skipped_skills` rather than failing the whole scan. Each of the tool's
+/// skills roots is scanned in turn.
+pub fn collect_skills_from_tool(tool: &ToolInfo) -> Result<Vec<SkillInfo>, AppError> {
+    let mut skills = vec![];
+    for root in &tool.skills_paths {
+        skills.extend(collect_skills_from_root(tool, Path::new(root))?);
+    }
+    Ok(skills)
+}
+
+fn collect_skills_from_root(tool: &ToolInfo, root: &Path) -> Result<Vec<SkillInfo>, AppError> {
+    let root = root.to_path_buf();
+    if !root.exists() || !root.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut skipped = vec![];
+    let mut candidates = vec![];
+    let root_skill_file = skill_manifest_path(&root);
+    if root_skill_file.exists() {
+        candidates.push((root.clone(), root_skill_file));
+    } else {
+        for entry in fs::read_dir(&root)? {
+            let path = match entry {
+                Ok(entry) => entry.path(),
+                Err(err) => {
+                    skipped.push(format!("{}: {err}", root.display()));
+                    continue;
+                }
+            };
+            if !path.is_dir() {
+                continue;
+            }
+
+            let skill_file = skill_manifest_path(&path);
+            if skill_file.exists() {
+                candidates.push((path, skill_file));
+            }
+        }
+    }
+
+    let mut skills = vec![];
+    let mut live_paths: HashSet<PathBuf> = HashSet::new();
+    for (dir, skill_file) in candidates {
+        match load_skill_cached(tool, &dir, &skill_file) {
+            Ok(info) => {
+                live_paths.insert(dir);
+                skills.push(info);
+            }
+            Err(err) => skipped.push(format!("{}: {err}", dir.display())),
+        }
+    }
+
+    prune_skill_cache(&root, &live_paths);
+    scan_skips()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(root, skipped);
+    Ok(skills)
+}
+
+/// A file in `dir` whose name matches `SKILL.md` only case-insensitively
+/// (`Skill.md`, `skill.md`, ...). Returns `None` when the canonical name is
+/// present, since that one is picked up by the scan. Names are compared as
+/// listed by `read_dir`, so this also works on case-insensitive filesystems.
+pub fn miscased_manifest(dir: &Path) -> Option<PathBuf> {
+    let mut variant = None;
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name == "SKILL.md" {
+            return None;
+        }
+        if variant.is_none() && name.eq_ignore_ascii_case("SKILL.md") && entry.path().is_file() {
+            variant = Some(entry.path());
+        }
+    }
+    variant
+}
+
+/// Rename a miscased manifest to `SKILL.md`. Goes through a temporary name
+/// so the rename also takes effect on case-insensitive filesystems.
+pub fn fix_manifest_name(manifest: &Path) -> Result<PathBuf, AppError> {
+    let dir = manifest
+        .parent()
+        .ok_or_else(|| AppError::InvalidPath(format!("Invalid path: {}", manifest.display())))?;
+    let target = dir.join("SKILL.md");
+    let staging = dir.join(format!(".SKILL.md.rename-{}", std::process::id()));
+    fs::rename(manifest, &staging)?;
+    if let Err(err) = fs::rename(&staging, &target) {
+        let _ = fs::rename(&staging, manifest);
+        return Err(err.into());
+    }
+    Ok(target)
+}
+
+/// Skills roots (per tool) with a scan worker still running, so a root
+/// stuck on an unreachable mount gets at most one worker however often the
+/// dashboard refreshes.
+fn scans_in_flight() -> &'static Mutex<HashSet<Vec<String>>> {
+    static IN_FLIGHT: OnceLock<Mutex<HashSet<Vec<String>>>> = OnceLock::new();
+    IN_FLIGHT.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Clears a tool's `scans_in_flight` entry when its worker ends, panics
+/// included.
+struct InFlightScan(Vec<String>);
+
+impl Drop for InFlightScan {
+    fn drop(&mut self) {
+        scans_in_flight()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.0);
+    }
+}
+
+/// Start a worker scanning `tool`, or `None` when one is still running.
+fn spawn_tool_scan(tool: &ToolInfo) -> Option<mpsc::Receiver<Result<Vec<SkillInfo>, AppError>>> {
+    let key = tool.skills_paths.clone();
+    let inserted = scans_in_flight()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(key.clone());
+    if !inserted {
+        return None;
+    }
+    let guard = InFlightScan(key);
+    let (tx, rx) = mpsc::channel();
+    let worker_tool = tool.clone();
+    thread::spawn(move || {
+        let result = collect_skills_from_tool(&worker_tool);
+        drop(guard);
+        let _ = tx.send(result);
+    });
+    Some(rx)
+}
+
+fn wait_for_tool_scan(
+    tool: &ToolInfo,
+    rx: Option<mpsc::Receiver<Result<Vec<SkillInfo>, AppError>>>,
+    wait: Duration,
+    timeout: Duration,
+) -> Result<Vec<SkillInfo>, AppError> {
+    let Some(rx) = rx else {
+        return Err(AppError::Io(io::Error::new(
+            io::ErrorKind::WouldBlock,
+            format!("an earlier scan of {} is still running", tool.skills_path),
+        )));
+    };
+    match rx.recv_timeout(wait) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(AppError::Io(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "scanning {} timed out after {}s",
+                tool.skills_path,
+                timeout.as_secs()
+            ),
+        ))),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(AppError::Io(io::Error::other(
+            format!("scanning {} panicked", tool.skills_path),
+        ))),
+    }
+}
+
+/// Run `collect_skills_from_tool` for each tool on its own worker thread,
+/// all started up front so independent roots are read concurrently, and
+/// give up on any tool not done within `timeout`. A skills root on an
+/// offline network mount can block inside `read_dir` indefinitely; the
+/// stuck worker is left to finish on its own while the caller moves on,
+/// and later calls skip that tool until it does.
+/// Results are in the order of `tools`, whichever scan finishes first.
+pub fn collect_skills_with_timeout(
+    tools: &[ToolInfo],
+    timeout: Duration,
+) -> Vec<Result<Vec<SkillInfo>, AppError>> {
+    let deadline = Instant::now() + timeout;
+    let receivers: Vec<_> = tools.iter().map(spawn_tool_scan).collect();
+    tools
+        .iter()
+        .zip(receivers)
+        .map(|(tool, rx)| {
+            let wait = deadline.saturating_duration_since(Instant::now());
+            wait_for_tool_scan(tool, rx, wait, timeout)
+        })
+        .collect()
+}
+
+/// Count skills directly inside a skills root: 1 if the root itself holds a
+/// SKILL.md, otherwise the number of immediate subfolders that do.
+pub fn count_skills_in_root(root: &Path) -> usize {
+    if skill_manifest_path(root).is_file() {
+        return 1;
+    }
+    fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| skill_manifest_path(&entry.path()).is_file())
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+pub fn discover_skills_roots(root: &Path) -> Vec<DiscoveredSkillsRoot> {
+    if !root.exists() || !root.is_dir() {
+        return vec![];
+    }
+
+    let mut stack: Vec<(PathBuf, usize)> = vec![(root.to_path_buf(), 0)];
+    let mut discovered: HashMap<PathBuf, usize> = HashMap::new();
+
+    while let Some((dir, depth)) = stack.pop() {
+        let has_skill_file = dir.join("SKILL.md").is_file();
+
+        let mut child_skill_count = 0usize;
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            let child = entry.path();
+            if !child.is_dir() {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+
+            if child.join("SKILL.md").is_file() {
+                child_skill_count += 1;
+            }
+
+            if depth < 6 {
+                let lower = name.to_ascii_lowercase();
+                if lower != "node_modules" && lower != "target" && lower != "dist" && lower != "build" {
+                    stack.push((child, depth + 1));
+                }
+            }
+        }
+
+        if has_skill_file || child_skill_count > 0 {
+            let count = if child_skill_count > 0 { child_skill_count } else { 1 };
+            discovered
+                .entry(dir)
+                .and_modify(|existing| *existing = (*existing).max(count))
+                .or_insert(count);
+        }
+    }
+
+    let mut out: Vec<DiscoveredSkillsRoot> = discovered
+        .into_iter()
+        .map(|(path, skill_count)| DiscoveredSkillsRoot {
+            path: path.to_string_lossy().to_string(),
+            skill_count,
+        })
+        .collect();
+
+    out.sort_by(|a, b| {
+        b.skill_count
+            .cmp(&a.skill_count)
+            .then_with(|| a.path.len().cmp(&b.path.len()))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    out
+}
+
+/// Skills roots inside a project: each `suffixes` entry (tool skills paths
+/// relative to home, like `.claude/skills`) that exists under `project`, plus
+/// whatever `discover_skills_roots` finds. Roots nested in another root are
+/// dropped so no skill is scanned twice.
+pub fn project_skill_roots(project: &Path, suffixes: &[String]) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = suffixes
+        .iter()
+        .map(|suffix| project.join(suffix))
+        .filter(|root| root.is_dir())
+        .chain(discover_skills_roots(project).into_iter().map(|root| PathBuf::from(root.path)))
+        .collect();
+    candidates.sort_by_key(|root| root.components().count());
+
+    let mut roots: Vec<PathBuf> = vec![];
+    for root in candidates {
+        if !roots.iter().any(|kept| root.starts_with(kept)) {
+            roots.push(root);
+        }
+    }
+    roots
+}
+
+/// Key under which `merge_skills` collapses entries for a given merge mode,
+/// or `None` when every directory should stay a separate entry.
+///
+/// - `name`: lowercased display name, so same-named skills from different
+///   tools show up once.
+/// - `slug`: slugified folder name. Two skills that merely share a display
+///   name but live in differently-named folders stay separate.
+fn merge_key(skill: &SkillInfo, mode: &str) -> Option<String> {
+    match mode {
+        "name" => Some(skill.name.to_lowercase()),
+        "slug" => Some(slugify(&dir_display_name(Path::new(&skill.path)))),
+        _ => None,
+    }
+}
+
+fn sort_for_display(list: &mut [SkillInfo]) {
+    list.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.source.cmp(&b.source))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+}
+
+/// Group skills that collapse into one entry under `mode`, in display
+/// order. Each group lists its primary first: the most recently updated
+/// entry, ties going to the one that sorts first. The rest follow in
+/// display order. Skills with no merge key come out as groups of one.
+fn merge_buckets(mut list: Vec<SkillInfo>, mode: &str) -> Vec<(Option<String>, Vec<SkillInfo>)> {
+    sort_for_display(&mut list);
+
+    let mut groups: Vec<(Option<String>, Vec<SkillInfo>)> = vec![];
+    let mut index_by_key: HashMap<String, usize> = HashMap::new();
+    for skill in list {
+        let Some(key) = merge_key(&skill, mode) else {
+            groups.push((None, vec![skill]));
+            continue;
+        };
+        match index_by_key.get(&key) {
+            Some(&idx) => groups[idx].1.push(skill),
+            None => {
+                index_by_key.insert(key.clone(), groups.len());
+                groups.push((Some(key), vec![skill]));
+            }
+        }
+    }
+
+    for (_, skills) in &mut groups {
+        let newest = skills
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.updated_at.cmp(&b.updated_at))
+            .map_or(0, |(idx, _)| idx);
+        let primary = skills.remove(newest);
+        skills.insert(0, primary);
+    }
+    groups
+}
+
+fn manifest_bytes(skill: &SkillInfo) -> Option<Vec<u8>> {
+    fs::read(skill_manifest_path(Path::new(&skill.path))).ok()
+}
+
+/// Sort skills for display (pinned first, then by name, source, path) and
+/// collapse duplicates according to `mode` (`none`, `name`, or `slug`).
+/// The most recently updated entry of each group is kept as the primary
+/// and absorbs the other entries' `enabled_for` tools. Other entries whose
+/// SKILL.md differs from the primary's are listed in its `conflicts`, so
+/// two different skills claiming one name aren't silently hidden.
+pub fn merge_skills(list: Vec<SkillInfo>, mode: &str) -> Vec<SkillInfo> {
+    let mut merged: Vec<SkillInfo> = merge_buckets(list, mode)
+        .into_iter()
+        .map(|(_, skills)| {
+            let mut skills = skills.into_iter();
+            let mut primary = skills.next().expect("merge groups are never empty");
+            let mut primary_bytes = None;
+            for skill in skills {
+                for tool_id in &skill.enabled_for {
+                    if !primary.enabled_for.contains(tool_id) {
+                        primary.enabled_for.push(tool_id.clone());
+                    }
+                }
+                let primary_bytes = primary_bytes.get_or_insert_with(|| manifest_bytes(&primary));
+                if primary_bytes.is_none() || manifest_bytes(&skill) != *primary_bytes {
+                    primary.conflicts.push(skill.path);
+                }
+            }
+            primary
+        })
+        .collect();
+    sort_for_display(&mut merged);
+    merged
+}
+
+/// The groups `merge_skills` would collapse under `mode`, each listing its
+/// entries with the primary first. Only groups with more than
+/// one entry are returned. `identical` is true when every entry's SKILL.md
+/// has the same bytes; a `false` flags same-name skills that actually
+/// differ.
+pub fn merge_groups(list: Vec<SkillInfo>, mode: &str) -> Vec<MergeGroup> {
+    let groups: Vec<(String, Vec<SkillInfo>)> = merge_buckets(list, mode)
+        .into_iter()
+        .filter_map(|(key, skills)| Some((key?, skills)))
+        .collect();
+
+    groups
+        .into_iter()
+        .filter(|(_, skills)| skills.len() > 1)
+        .map(|(key, skills)| {
+            let contents: Vec<Option<Vec<u8>>> = skills
+                .iter()
+                .map(manifest_bytes)
+                .collect();
+            let identical = contents[0].is_some() && contents.iter().all(|c| c == &contents[0]);
+            MergeGroup {
+                key,
+                name: skills[0].name.clone(),
+                identical,
+                members: skills
+                    .into_iter()
+                    .map(|skill| MergeMember {
+                        path: skill.path,
+                        tool_id: skill.source,
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Fingerprints of the skills and tools a dashboard response was built
+/// from, keyed by skill path and tool id. Used to compute deltas between
+/// two dashboard builds without keeping whole responses around.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardSnapshot {
+    skills: HashMap<String, u64>,
+    tools: HashMap<String, u64>,
+}
+
+/// Keys that differ between two snapshots.
+#[derive(Debug, Default, PartialEq)]
+pub struct SnapshotDiff {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed_tools: Vec<String>,
+    pub removed_tools: Vec<String>,
+}
+
+/// Hash of everything the UI shows for a value. Serialization can't fail
+/// for these plain structs; an empty buffer would only cause a spurious
+/// "changed" entry.
+fn fingerprint<T: Serialize>(value: &T, extra: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(value).unwrap_or_default().hash(&mut hasher);
+    extra.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Snapshot a dashboard's skills and tools. Skills are fingerprinted on
+/// the SKILL.md content hash their scan recorded as well as their metadata,
+/// so edits within the same mtime second are still picked up without
+/// reading every SKILL.md again.
+pub fn snapshot_dashboard(skills: &[SkillInfo], tools: &[ToolInfo]) -> DashboardSnapshot {
+    DashboardSnapshot {
+        skills: skills
+            .iter()
+            .map(|skill| {
+                let content = cached_content_hash(Path::new(&skill.path)).unwrap_or_default();
+                (skill.path.clone(), fingerprint(skill, &content.to_le_bytes()))
+            })
+            .collect(),
+        tools: tools
+            .iter()
+            .map(|tool| (tool.id.clone(), fingerprint(tool, &[])))
+            .collect(),
+    }
+}
+
+fn diff_keys(
+    prev: &HashMap<String, u64>,
+    next: &HashMap<String, u64>,
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let mut added = vec![];
+    let mut changed = vec![];
+    for (key, hash) in next {
+        match prev.get(key) {
+            None => added.push(key.clone()),
+            Some(old) if old != hash => changed.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    let mut removed: Vec<String> = prev
+        .keys()
+        .filter(|key| !next.contains_key(*key))
+        .cloned()
+        .collect();
+    added.sort();
+    changed.sort();
+    removed.sort();
+    (added, changed, removed)
+}
+
+pub fn diff_snapshots(prev: &DashboardSnapshot, next: &DashboardSnapshot) -> SnapshotDiff {
+    let (added, changed, removed) = diff_keys(&prev.skills, &next.skills);
+    // Added tools are reported alongside changed ones: either way the UI
+    // just needs the fresh `ToolInfo`.
+    let (mut changed_tools, mut modified_tools, removed_tools) = diff_keys(&prev.tools, &next.tools);
+    changed_tools.append(&mut modified_tools);
+    changed_tools.sort();
+    SnapshotDiff {
+        added,
+        changed,
+        removed,
+        changed_tools,
+        removed_tools,
+    }
+}
+
+pub fn discover_skill_dir(root: &Path, depth: usize) -> Option<PathBuf> {
+    if depth > 4 {
+        return None;
     }
 
     if root.join("SKILL.md").exists() {
@@ -372,6 +1618,34 @@ pub fn discover_skill_dir(root: &Path, depth: usize) -> Option<PathBuf> {
     None
 }
 
+/// Collect every directory under `root` (to `max_depth`) containing a
+/// SKILL.md. Hidden directories are skipped, and a skill directory isn't
+/// descended into — nested SKILL.md files belong to their enclosing skill.
+pub fn discover_all_skill_dirs(root: &Path, max_depth: usize) -> Vec<PathBuf> {
+    let mut found = vec![];
+    let mut stack = vec![(root.to_path_buf(), 0usize)];
+    while let Some((dir, depth)) = stack.pop() {
+        if dir.join("SKILL.md").is_file() {
+            found.push(dir);
+            continue;
+        }
+        if depth >= max_depth {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() && !entry.file_name().to_string_lossy().starts_with('.') {
+                stack.push((path, depth + 1));
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
 /// DFS to find a directory matching `name` that contains a SKILL.md file.
 /// Handles varied repo structures (skills/{id}/, {id}/, or nested).
 pub fn discover_skill_dir_by_name(root: &Path, name: &str, depth: usize) -> Option<PathBuf> {
@@ -409,7 +1683,65 @@ pub fn discover_skill_dir_by_name(root: &Path, name: &str, depth: usize) -> Opti
     None
 }
 
-pub fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
+/// Per-skill ignore file, in `.gitignore` syntax.
+pub const SKILL_IGNORE_FILE: &str = ".skillignore";
+
+/// What a skill copy leaves out. `SkillIgnore::standard()` skips `.git`
+/// and anything matched by a `.skillignore`; a `.skillignore` in a
+/// subfolder applies beneath it and can re-include (`!pattern`) what a
+/// parent excluded, as with `.gitignore`. The default copies everything.
+#[derive(Clone, Default)]
+pub struct SkillIgnore {
+    active: bool,
+    matchers: Vec<Gitignore>,
+}
+
+impl SkillIgnore {
+    pub fn standard() -> Self {
+        Self {
+            active: true,
+            matchers: vec![],
+        }
+    }
+
+    /// `self` plus the rules of `dir`'s own `.skillignore`, if it has one.
+    /// An unreadable or invalid file is ignored rather than failing the copy.
+    pub fn nested(&self, dir: &Path) -> Option<Self> {
+        let file = dir.join(SKILL_IGNORE_FILE);
+        if !self.active || !file.is_file() {
+            return None;
+        }
+        let mut builder = GitignoreBuilder::new(dir);
+        builder.add(&file);
+        let matcher = builder.build().ok()?;
+        let mut next = self.clone();
+        next.matchers.push(matcher);
+        Some(next)
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if !self.active {
+            return false;
+        }
+        if path.file_name() == Some(OsStr::new(".git")) {
+            return true;
+        }
+        // The deepest `.skillignore` with an opinion wins.
+        for matcher in self.matchers.iter().rev() {
+            match matcher.matched(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+}
+
+/// Copy `src` into `dst`, leaving out whatever `ignore` excludes.
+pub fn copy_dir_recursive(src: &Path, dst: &Path, ignore: &SkillIgnore) -> io::Result<()> {
+    let nested = ignore.nested(src);
+    let ignore = nested.as_ref().unwrap_or(ignore);
     if !dst.exists() {
         fs::create_dir_all(dst)?;
     }
@@ -418,10 +1750,13 @@ pub fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
         let entry = entry?;
         let file_type = entry.file_type()?;
         let src_path = entry.path();
+        if ignore.is_ignored(&src_path, file_type.is_dir()) {
+            continue;
+        }
         let dst_path = dst.join(entry.file_name());
 
         if file_type.is_dir() {
-            copy_dir_recursive(&src_path, &dst_path)?;
+            copy_dir_recursive(&src_path, &dst_path, ignore)?;
         } else {
             fs::copy(&src_path, &dst_path)?;
         }
@@ -430,35 +1765,399 @@ pub fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
     Ok(())
 }
 
-fn read_skill_source_meta(skill_dir: &Path) -> Option<SkillSourceMeta> {
-    let meta_path = skill_dir.join(SOURCE_META_FILE);
-    let content = fs::read_to_string(meta_path).ok()?;
-    serde_json::from_str::<SkillSourceMeta>(&content).ok()
-}
+/// Copy `src` over the existing directory `dst`: files from `src` replace
+/// their counterparts, entries only present in `dst` are kept. Where one
+/// side has a file and the other a directory, the `src` entry wins.
+/// Entries excluded by `ignore` are neither copied nor touched in `dst`.
+pub fn overlay_dir(src: &Path, dst: &Path, ignore: &SkillIgnore) -> io::Result<()> {
+    let nested = ignore.nested(src);
+    let ignore = nested.as_ref().unwrap_or(ignore);
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let src_path = entry.path();
+        let is_dir = entry.file_type()?.is_dir();
+        if ignore.is_ignored(&src_path, is_dir) {
+            continue;
+        }
+        let dst_path = dst.join(entry.file_name());
 
-fn normalize_optional_rel_path(value: Option<&str>) -> Option<String> {
-    let clean = value
-        .map(|v| v.trim().replace('\\', "/"))
-        .filter(|v| !v.is_empty())?;
-    Some(clean.trim_matches('/').to_string())
-}
+        if let Ok(existing) = fs::symlink_metadata(&dst_path) {
+            if existing.is_dir() && !is_dir {
+                fs::remove_dir_all(&dst_path)?;
+            } else if !existing.is_dir() && (is_dir || existing.file_type().is_symlink()) {
+                fs::remove_file(&dst_path)?;
+            }
+        }
 
-pub fn write_skill_source_meta(
-    skill_dir: &Path,
-    repo_url: &str,
-    skill_path: Option<&str>,
-) -> Result<(), AppError> {
-    let repo = repo_url.trim();
-    if repo.is_empty() {
-        return Ok(());
+        if is_dir {
+            overlay_dir(&src_path, &dst_path, ignore)?;
+        } else {
+            fs::copy(&src_path, &dst_path)?;
+        }
     }
-    let meta = SkillSourceMeta {
-        repo_url: repo.to_string(),
-        skill_path: normalize_optional_rel_path(skill_path),
-    };
-    let serialized = serde_json::to_string_pretty(&meta)
-        .map_err(|e| AppError::Validation(format!("Failed to serialize source metadata: {e}")))?;
-    fs::write(skill_dir.join(SOURCE_META_FILE), serialized)?;
+    Ok(())
+}
+
+/// Copy `src` to `dst` all-or-nothing: the tree is copied into a hidden
+/// sibling staging directory and renamed into place only once every file
+/// made it. On failure the staging directory is removed and `dst` is left
+/// untouched, so a full disk can't leave a half-copied skill behind.
+/// `dst` must not exist yet.
+pub fn copy_dir_atomic(src: &Path, dst: &Path, ignore: &SkillIgnore) -> io::Result<()> {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let parent = dst
+        .parent()
+        .ok_or_else(|| io::Error::other(format!("No parent directory: {}", dst.display())))?;
+    fs::create_dir_all(parent)?;
+    let staging = parent.join(format!(
+        ".{}.partial-{}-{}",
+        dir_display_name(dst),
+        std::process::id(),
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = copy_dir_recursive(src, &staging, ignore).and_then(|()| fs::rename(&staging, dst));
+    if result.is_err() && staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+/// Replace the file at `path` with `contents` all-or-nothing: the bytes go
+/// to a hidden sibling file that is flushed to disk and renamed over
+/// `path`, so a crash or full disk leaves the previous file intact rather
+/// than a truncated one. An existing file's permissions are kept, and a
+/// symlink is written through to its target.
+pub fn write_file_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    replace_file(path, |file| io::Write::write_all(file, contents))
+}
+
+fn replace_file(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> io::Result<()>,
+) -> io::Result<()> {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let is_symlink = fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink());
+    let path = if is_symlink {
+        fs::canonicalize(path)?
+    } else {
+        path.to_path_buf()
+    };
+    let parent = path
+        .parent()
+        .ok_or_else(|| io::Error::other(format!("No parent directory: {}", path.display())))?;
+    let staging = parent.join(format!(
+        ".{}.partial-{}-{}",
+        dir_display_name(&path),
+        std::process::id(),
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = (|| {
+        let mut file = fs::File::create(&staging)?;
+        write(&mut file)?;
+        file.sync_all()?;
+        drop(file);
+        if let Ok(meta) = fs::metadata(&path) {
+            fs::set_permissions(&staging, meta.permissions())?;
+        }
+        fs::rename(&staging, &path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&staging);
+    }
+    result
+}
+
+/// Write the contents of `src` to a new zip archive at `dst`, with paths
+/// relative to `src` and placed under `prefix` when it is non-empty. Files
+/// are streamed into the archive one at a time. Symlinks are followed;
+/// Unix file modes are kept.
+pub fn zip_dir(src: &Path, dst: &Path, prefix: &str, ignore: &SkillIgnore) -> io::Result<()> {
+    let mut writer = zip::ZipWriter::new(fs::File::create(dst)?);
+    let prefix = prefix.trim_matches('/');
+    if !prefix.is_empty() {
+        writer.add_directory(prefix, zip_options(src)?)?;
+    }
+    zip_tree(&mut writer, src, src, prefix, ignore)?;
+    writer.finish()?;
+    Ok(())
+}
+
+fn zip_options(path: &Path) -> io::Result<zip::write::SimpleFileOptions> {
+    #[allow(unused_mut)]
+    let mut options = zip::write::SimpleFileOptions::default();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        options = options.unix_permissions(fs::metadata(path)?.permissions().mode());
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(options)
+}
+
+fn zip_tree(
+    writer: &mut zip::ZipWriter<fs::File>,
+    dir: &Path,
+    src: &Path,
+    prefix: &str,
+    ignore: &SkillIgnore,
+) -> io::Result<()> {
+    let nested = ignore.nested(dir);
+    let ignore = nested.as_ref().unwrap_or(ignore);
+    let mut entries: Vec<_> = fs::read_dir(dir)?.collect::<io::Result<_>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let is_dir = path.is_dir();
+        if ignore.is_ignored(&path, is_dir) {
+            continue;
+        }
+        let rel = path
+            .strip_prefix(src)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let name = if prefix.is_empty() {
+            rel
+        } else {
+            format!("{prefix}/{rel}")
+        };
+        let options = zip_options(&path)?;
+        if is_dir {
+            writer.add_directory(name, options)?;
+            zip_tree(writer, &path, src, prefix, ignore)?;
+        } else {
+            writer.start_file(name, options)?;
+            io::copy(&mut fs::File::open(&path)?, writer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Extract a zip archive written by [`zip_dir`] into `dst`. Entries that
+/// would land outside `dst` are rejected by the zip crate.
+pub fn unzip_into(archive: &Path, dst: &Path) -> io::Result<()> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(archive)?)?;
+    archive.extract(dst)?;
+    Ok(())
+}
+
+/// Where an archive entry lands relative to the extraction root, or an
+/// error for names that could escape it: absolute paths, `..` segments and
+/// drive prefixes, with `\` treated as a separator as well.
+fn archive_entry_path(name: &str) -> Result<PathBuf, AppError> {
+    let name = name.replace('\\', "/");
+    if name.starts_with('/') {
+        return Err(AppError::InvalidPath(format!(
+            "Archive entry must not be absolute: {name}"
+        )));
+    }
+    let mut path = PathBuf::new();
+    for segment in name.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                return Err(AppError::InvalidPath(format!(
+                    "Archive entry must not contain path traversal: {name}"
+                )));
+            }
+            _ if segment.contains(':') => {
+                return Err(AppError::InvalidPath(format!(
+                    "Archive entry has an invalid name: {name}"
+                )));
+            }
+            _ => path.push(segment),
+        }
+    }
+    Ok(path)
+}
+
+/// Extract an untrusted archive into `dst`. Every entry name is checked
+/// before anything is written, so one unsafe entry rejects the whole
+/// archive. Entries are written as plain files and folders; symlinks are
+/// not recreated. Archives that unpack to more than `max_bytes` are
+/// rejected, whatever sizes their headers claim.
+pub fn unzip_checked(archive: &Path, dst: &Path, max_bytes: u64) -> Result<(), AppError> {
+    use std::io::Read;
+
+    let too_large = || {
+        AppError::Validation(format!("Archive unpacks to more than {max_bytes} bytes"))
+    };
+    let mut archive = zip::ZipArchive::new(fs::File::open(archive)?).map_err(io::Error::from)?;
+    let mut paths = Vec::with_capacity(archive.len());
+    let mut declared: u64 = 0;
+    for idx in 0..archive.len() {
+        let entry = archive.by_index_raw(idx).map_err(io::Error::from)?;
+        let name = entry.name().map_err(io::Error::from)?;
+        declared = declared.saturating_add(entry.size());
+        if declared > max_bytes {
+            return Err(too_large());
+        }
+        paths.push((archive_entry_path(&name)?, entry.is_dir()));
+    }
+
+    fs::create_dir_all(dst)?;
+    let mut written: u64 = 0;
+    for (idx, (rel, is_dir)) in paths.into_iter().enumerate() {
+        let target = dst.join(rel);
+        if is_dir {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let entry = archive.by_index(idx).map_err(io::Error::from)?;
+        // Sizes in the headers can lie, so count what is actually written.
+        let remaining = max_bytes - written;
+        written += io::copy(&mut entry.take(remaining + 1), &mut fs::File::create(&target)?)?;
+        if written > max_bytes {
+            return Err(too_large());
+        }
+    }
+    Ok(())
+}
+
+/// Reset modes under `root` to 0755 for directories and 0644 for files,
+/// except that files starting with a shebang or living under `scripts/`
+/// are made executable (0755). Symlinks are left alone. Returns every
+/// entry whose mode changed.
+#[cfg(unix)]
+pub fn normalize_permissions(root: &Path) -> io::Result<Vec<PermissionChange>> {
+    use std::io::Read;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn wants_exec(path: &Path, rel: &Path) -> bool {
+        if rel.components().next().is_some_and(|c| c.as_os_str() == "scripts") {
+            return true;
+        }
+        let mut head = [0u8; 2];
+        fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut head))
+            .is_ok_and(|()| &head == b"#!")
+    }
+
+    let mut changed = vec![];
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let meta = fs::symlink_metadata(&path)?;
+            if meta.file_type().is_symlink() {
+                continue;
+            }
+            let rel = path.strip_prefix(root).unwrap_or(&path);
+            let target = if meta.is_dir() {
+                stack.push(path.clone());
+                0o755
+            } else if wants_exec(&path, rel) {
+                0o755
+            } else {
+                0o644
+            };
+            let current = meta.permissions().mode() & 0o7777;
+            if current != target {
+                fs::set_permissions(&path, fs::Permissions::from_mode(target))?;
+                changed.push(PermissionChange {
+                    relative_path: rel.to_string_lossy().replace('\\', "/"),
+                    from: format!("{current:04o}"),
+                    to: format!("{target:04o}"),
+                });
+            }
+        }
+    }
+    changed.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    Ok(changed)
+}
+
+/// Recreate the directory tree of `src` at `dst`, hard-linking every file
+/// instead of copying it. Entries excluded by `ignore` are skipped, as in
+/// `copy_dir_recursive`.
+pub fn hardlink_dir_recursive(src: &Path, dst: &Path, ignore: &SkillIgnore) -> io::Result<()> {
+    let nested = ignore.nested(src);
+    let ignore = nested.as_ref().unwrap_or(ignore);
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let src_path = entry.path();
+        let is_dir = entry.file_type()?.is_dir();
+        if ignore.is_ignored(&src_path, is_dir) {
+            continue;
+        }
+        let dst_path = dst.join(entry.file_name());
+        if is_dir {
+            hardlink_dir_recursive(&src_path, &dst_path, ignore)?;
+        } else {
+            fs::hard_link(&src_path, &dst_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Create a directory symlink at `dst` pointing to `src`. On Windows this
+/// needs Developer Mode or elevation, so callers should expect failures.
+pub fn symlink_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(src, dst)
+    }
+    #[cfg(windows)]
+    {
+        std::os::windows::fs::symlink_dir(src, dst)
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (src, dst);
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "symlinks are not supported on this platform",
+        ))
+    }
+}
+
+fn read_skill_source_meta(skill_dir: &Path) -> Option<SkillSourceMeta> {
+    let meta_path = skill_dir.join(SOURCE_META_FILE);
+    let content = fs::read_to_string(meta_path).ok()?;
+    serde_json::from_str::<SkillSourceMeta>(&content).ok()
+}
+
+fn normalize_optional_rel_path(value: Option<&str>) -> Option<String> {
+    let clean = value
+        .map(|v| v.trim().replace('\\', "/"))
+        .filter(|v| !v.is_empty())?;
+    Some(clean.trim_matches('/').to_string())
+}
+
+/// The provenance recorded in a skill's sidecar, if any.
+pub fn read_provenance(skill_dir: &Path) -> Option<ProvenanceInfo> {
+    read_skill_source_meta(skill_dir).map(|meta| ProvenanceInfo {
+        repo_url: meta.repo_url,
+        skill_path: meta.skill_path,
+        commit: meta.commit,
+    })
+}
+
+pub fn write_skill_source_meta(
+    skill_dir: &Path,
+    repo_url: &str,
+    skill_path: Option<&str>,
+    commit: Option<&str>,
+) -> Result<(), AppError> {
+    let repo = repo_url.trim();
+    if repo.is_empty() {
+        return Ok(());
+    }
+    let meta = SkillSourceMeta {
+        repo_url: repo.to_string(),
+        skill_path: normalize_optional_rel_path(skill_path),
+        commit: commit.map(str::trim).filter(|c| !c.is_empty()).map(str::to_string),
+    };
+    let serialized = serde_json::to_string_pretty(&meta)
+        .map_err(|e| AppError::Validation(format!("Failed to serialize source metadata: {e}")))?;
+    fs::write(skill_dir.join(SOURCE_META_FILE), serialized)?;
     Ok(())
 }
 
@@ -523,6 +2222,64 @@ mod tests {
         assert_eq!(meta.description, "No description");
     }
 
+    #[test]
+    fn parse_skill_metadata_reads_license_version_author() {
+        let content = "---\nname: A\nlicense: MIT\nversion: \"1.2.0\"\nauthor: Jane\n---\n";
+        let meta = parse_skill_metadata(content, "f");
+        assert_eq!(meta.license.as_deref(), Some("MIT"));
+        assert_eq!(meta.version.as_deref(), Some("1.2.0"));
+        assert_eq!(meta.author.as_deref(), Some("Jane"));
+
+        let bare = parse_skill_metadata("---\nname: A\nlicense:\n---\n", "f");
+        assert_eq!(bare.license, None);
+        assert_eq!(bare.author, None);
+    }
+
+    #[test]
+    fn parse_skill_metadata_reads_inline_and_block_tags() {
+        let inline = "---\nname: A\ntags: [git, \"review\"]\n---\n";
+        assert_eq!(parse_skill_metadata(inline, "f").tags, vec!["git", "review"]);
+
+        let block = "---\nname: A\ntags:\n  - git\n  - review\ndescription: d\n---\n";
+        let meta = parse_skill_metadata(block, "f");
+        assert_eq!(meta.tags, vec!["git", "review"]);
+        assert_eq!(meta.description, "d");
+
+        assert!(parse_skill_metadata("# No tags", "f").tags.is_empty());
+    }
+
+    #[test]
+    fn set_frontmatter_field_replaces_block_value_in_place() {
+        let content = "---\nname: A\ntags:\n  - old\ndescription: d\n---\n\nBody\n";
+        let out = set_frontmatter_field(content, "tags", Some("[new]"));
+        assert_eq!(out, "---\nname: A\ntags: [new]\ndescription: d\n---\n\nBody\n");
+    }
+
+    #[test]
+    fn set_frontmatter_field_touches_only_the_top_level_key() {
+        let content = "---\nname: pdf\nmetadata:\n  name: inner\nnames: [a]\n---\n\nname: body\n";
+        let out = set_frontmatter_field(content, "name", Some("pdf-copy"));
+        assert_eq!(
+            out,
+            "---\nname: pdf-copy\nmetadata:\n  name: inner\nnames: [a]\n---\n\nname: body\n"
+        );
+    }
+
+    #[test]
+    fn set_frontmatter_field_inserts_and_removes() {
+        let content = "---\nname: A\n---\nBody";
+        let added = set_frontmatter_field(content, "tags", Some("[x]"));
+        assert_eq!(added, "---\nname: A\ntags: [x]\n---\nBody");
+        assert_eq!(set_frontmatter_field(&added, "tags", None), content);
+    }
+
+    #[test]
+    fn set_frontmatter_field_creates_frontmatter_when_missing() {
+        let out = set_frontmatter_field("# Title\n", "tags", Some("[x]"));
+        assert_eq!(out, "---\ntags: [x]\n---\n\n# Title\n");
+        assert_eq!(parse_skill_metadata(&out, "f").name, "Title");
+    }
+
     #[test]
     fn dir_display_name_uses_last_component() {
         assert_eq!(dir_display_name(Path::new("/tmp/foo/bar-baz")), "bar-baz");
@@ -544,23 +2301,972 @@ mod tests {
     }
 
     #[test]
-    fn skill_cache_reuses_entry_when_mtimes_match() {
-        let tmp = env::temp_dir().join(format!("skillsyoga-cache-test-{}", std::process::id()));
-        fs::create_dir_all(&tmp).unwrap();
-        let skill_dir = tmp.join("my-skill");
-        fs::create_dir_all(&skill_dir).unwrap();
-        let skill_md = skill_dir.join("SKILL.md");
-        fs::write(&skill_md, "---\nname: Cached\ndescription: one\n---\n").unwrap();
+    fn validate_skill_content_accepts_spec_compliant_skill() {
+        let content = "---\nname: my-skill\ndescription: Does a thing\n---\n\nBody text.";
+        assert!(validate_skill_content(content).is_empty());
+    }
+
+    #[test]
+    fn validate_skill_content_requires_frontmatter() {
+        let issues = validate_skill_content("# Heading\n\nBody");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "error");
+    }
+
+    #[test]
+    fn validate_skill_content_reports_missing_fields_and_empty_body() {
+        let issues = validate_skill_content("---\nname: My Skill\n---\n");
+        let fields: Vec<Option<&str>> = issues.iter().map(|i| i.field.as_deref()).collect();
+        assert!(fields.contains(&Some("name")));
+        assert!(fields.contains(&Some("description")));
+        assert!(issues.iter().any(|i| i.field.is_none() && i.severity == "warning"));
+    }
+
+    #[test]
+    fn validate_skill_content_flags_invalid_yaml_and_unclosed_frontmatter() {
+        let content = "---\nname: ok\ndescription: \"unterminated\njust some words\n---\n\nBody";
+        let issues = validate_skill_content(content);
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert!(messages.iter().any(|m| m.contains("Invalid YAML")), "{messages:?}");
+
+        let issues = validate_skill_content("---\nname: ok\n\nBody without closing");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("not closed"));
+
+        let folded = "---\nname: ok\ndescription: >\n  folded text: fine\n# comment\n---\n\nBody";
+        assert!(validate_skill_content(folded).is_empty());
+    }
+
+    #[test]
+    fn parse_skill_metadata_reads_folded_blocks_with_blank_lines() {
+        let content = "---\nname: folded\ndescription: >\n  para one\n  continues\n\n  para two\n\
+                       version: 1.2\n---\n\nBody";
+        let meta = parse_skill_metadata(content, "fallback");
+        assert_eq!(meta.name, "folded");
+        assert_eq!(meta.description, "para one continues\npara two");
+        assert_eq!(meta.version.as_deref(), Some("1.2"));
+    }
+
+    #[test]
+    fn parse_skill_metadata_reads_inline_flow_sequences() {
+        let content = "---\nname: flow\ndescription: d\ntags: [pdf, \"data, tools\", 'x']\n---\n";
+        let meta = parse_skill_metadata(content, "fallback");
+        assert_eq!(meta.tags, vec!["pdf", "data, tools", "x"]);
+    }
+
+    #[test]
+    fn parse_skill_metadata_falls_back_when_yaml_is_invalid() {
+        let content = "---\nname: colon\ndescription: Use when: the user asks\n---\n\nBody";
+        let meta = parse_skill_metadata(content, "fallback");
+        assert_eq!(meta.name, "colon");
+        assert_eq!(meta.description, "Use when: the user asks");
+    }
 
-        let tool = ToolInfo {
+    #[test]
+    fn validate_skill_content_flags_long_description() {
+        let content = format!("---\nname: ok\ndescription: {}\n---\n\nBody", "x".repeat(1100));
+        let issues = validate_skill_content(&content);
+        assert!(issues
+            .iter()
+            .any(|i| i.field.as_deref() == Some("description") && i.severity == "error"));
+    }
+
+    #[test]
+    fn count_skills_in_root_counts_child_skills() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-count-test-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("a")).unwrap();
+        fs::create_dir_all(tmp.join("b")).unwrap();
+        fs::create_dir_all(tmp.join("not-a-skill")).unwrap();
+        fs::write(tmp.join("a/SKILL.md"), "# A").unwrap();
+        fs::write(tmp.join("b/SKILL.md"), "# B").unwrap();
+        assert_eq!(count_skills_in_root(&tmp), 2);
+
+        fs::write(tmp.join("SKILL.md"), "# Root").unwrap();
+        assert_eq!(count_skills_in_root(&tmp), 1);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn discover_all_skill_dirs_finds_every_skill_without_descending() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-discover-all-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("skills/one/nested")).unwrap();
+        fs::create_dir_all(tmp.join("skills/two")).unwrap();
+        fs::create_dir_all(tmp.join(".hidden/three")).unwrap();
+        fs::write(tmp.join("skills/one/SKILL.md"), "# One").unwrap();
+        fs::write(tmp.join("skills/one/nested/SKILL.md"), "# Nested").unwrap();
+        fs::write(tmp.join("skills/two/SKILL.md"), "# Two").unwrap();
+        fs::write(tmp.join(".hidden/three/SKILL.md"), "# Three").unwrap();
+
+        let found = discover_all_skill_dirs(&tmp, 4);
+        assert_eq!(found, vec![tmp.join("skills/one"), tmp.join("skills/two")]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hardlink_dir_recursive_links_nested_files() {
+        use std::os::unix::fs::MetadataExt;
+
+        let tmp = env::temp_dir().join(format!("skillsyoga-hardlink-{}", std::process::id()));
+        let src = tmp.join("src");
+        let dst = tmp.join("dst");
+        fs::create_dir_all(src.join("refs")).unwrap();
+        fs::create_dir_all(src.join(".git")).unwrap();
+        fs::write(src.join("SKILL.md"), "# Linked").unwrap();
+        fs::write(src.join("refs/notes.md"), "notes").unwrap();
+        fs::write(src.join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+
+        hardlink_dir_recursive(&src, &dst, &SkillIgnore::standard()).unwrap();
+        let a = fs::metadata(src.join("refs/notes.md")).unwrap();
+        let b = fs::metadata(dst.join("refs/notes.md")).unwrap();
+        assert_eq!(a.ino(), b.ino());
+        assert!(!dst.join(".git").exists());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_dir_points_at_source() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-symlink-{}", std::process::id()));
+        let src = tmp.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("SKILL.md"), "# Linked").unwrap();
+
+        let dst = tmp.join("dst");
+        symlink_dir(&src, &dst).unwrap();
+        assert!(fs::symlink_metadata(&dst).unwrap().file_type().is_symlink());
+        assert_eq!(fs::read_to_string(dst.join("SKILL.md")).unwrap(), "# Linked");
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    fn test_tool(skills_path: &Path) -> ToolInfo {
+        ToolInfo {
             id: "tool".into(),
             name: "tool".into(),
             kind: "builtin".into(),
             config_path: String::new(),
-            skills_path: tmp.to_string_lossy().to_string(),
+            skills_path: skills_path.to_string_lossy().to_string(),
+            skills_paths: vec![skills_path.to_string_lossy().to_string()],
             detected: true,
+            detection_method: "skillsDir".into(),
+            enabled: true,
+            cli: false,
+        }
+    }
+
+    #[test]
+    fn collect_skills_treats_root_skill_as_single_skill() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-root-skill-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("examples")).unwrap();
+        fs::write(tmp.join("SKILL.md"), "---\nname: Root\ndescription: root\n---\n").unwrap();
+        fs::write(tmp.join("examples/SKILL.md"), "---\nname: Example\ndescription: child\n---\n").unwrap();
+
+        let skills = collect_skills_from_tool(&test_tool(&tmp)).unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "Root");
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn collect_skills_gives_same_named_folders_in_two_roots_distinct_ids() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-two-roots-{}", std::process::id()));
+        for root in ["one", "two"] {
+            fs::create_dir_all(tmp.join(root).join("lint")).unwrap();
+            fs::write(tmp.join(root).join("lint/SKILL.md"), "---\nname: Lint\n---\n").unwrap();
+        }
+        let mut tool = test_tool(&tmp.join("one"));
+        tool.skills_paths.push(tmp.join("two").to_string_lossy().to_string());
+
+        let mut ids: Vec<String> = collect_skills_from_tool(&tool)
+            .unwrap()
+            .into_iter()
+            .map(|skill| skill.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["tool#1:lint", "tool:lint"]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn collect_skills_lists_subfolders_without_root_skill() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-sub-skills-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("a")).unwrap();
+        fs::create_dir_all(tmp.join("b")).unwrap();
+        fs::write(tmp.join("a/SKILL.md"), "# A").unwrap();
+        fs::write(tmp.join("b/SKILL.md"), "# B").unwrap();
+
+        let mut names: Vec<String> = collect_skills_from_tool(&test_tool(&tmp))
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["A", "B"]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn collect_skills_reports_disabled_skills() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-disabled-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("noisy")).unwrap();
+        fs::write(tmp.join("noisy/SKILL.md"), "# Noisy").unwrap();
+        let tool = test_tool(&tmp);
+        assert!(collect_skills_from_tool(&tool).unwrap()[0].enabled);
+
+        set_manifest_enabled(&tmp.join("noisy"), false).unwrap();
+        assert!(!tmp.join("noisy/SKILL.md").exists());
+        let skills = collect_skills_from_tool(&tool).unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "Noisy");
+        assert!(!skills[0].enabled);
+
+        // Repeating a toggle is a no-op; both manifests present is refused.
+        set_manifest_enabled(&tmp.join("noisy"), false).unwrap();
+        set_manifest_enabled(&tmp.join("noisy"), true).unwrap();
+        assert!(collect_skills_from_tool(&tool).unwrap()[0].enabled);
+        fs::write(tmp.join("noisy").join(DISABLED_SKILL_MD), "# Old").unwrap();
+        assert!(set_manifest_enabled(&tmp.join("noisy"), false).is_err());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn cached_skills_tracks_the_latest_scan() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-cached-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("kept")).unwrap();
+        fs::create_dir_all(tmp.join("gone")).unwrap();
+        fs::write(tmp.join("kept/SKILL.md"), "# Kept").unwrap();
+        fs::write(tmp.join("gone/SKILL.md"), "# Gone").unwrap();
+        let tool = test_tool(&tmp);
+        let cached_paths = || -> Vec<String> {
+            cached_skills()
+                .into_iter()
+                .map(|s| s.path)
+                .filter(|path| Path::new(path).starts_with(&tmp))
+                .collect()
+        };
+
+        collect_skills_from_tool(&tool).unwrap();
+        assert_eq!(cached_paths().len(), 2);
+        fs::remove_dir_all(tmp.join("gone")).unwrap();
+        collect_skills_from_tool(&tool).unwrap();
+        assert_eq!(cached_paths(), vec![tmp.join("kept").to_string_lossy().to_string()]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn parallel_scan_matches_serial_scan() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-parallel-{}", std::process::id()));
+        let tools: Vec<ToolInfo> = (0..6)
+            .map(|i| {
+                let root = tmp.join(format!("tool-{i}"));
+                for j in 0..=i {
+                    fs::create_dir_all(root.join(format!("s{j}"))).unwrap();
+                    let content = format!("---\nname: shared-{j}\ndescription: from {i}\n---\n");
+                    fs::write(root.join(format!("s{j}/SKILL.md")), content).unwrap();
+                }
+                ToolInfo {
+                    id: format!("tool-{i}"),
+                    ..test_tool(&root)
+                }
+            })
+            .collect();
+        let timeout = Duration::from_secs(30);
+
+        let serial: Vec<SkillInfo> = tools
+            .iter()
+            .flat_map(|tool| collect_skills_from_tool(tool).unwrap())
+            .collect();
+        let parallel: Vec<SkillInfo> = collect_skills_with_timeout(&tools, timeout)
+            .into_iter()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        let ids = |skills: &[SkillInfo]| -> Vec<String> {
+            skills.iter().map(|s| s.id.clone()).collect()
+        };
+        assert_eq!(ids(&parallel), ids(&serial));
+
+        let key = |skills: Vec<SkillInfo>| -> Vec<(String, String, Vec<String>)> {
+            merge_skills(skills, "name")
+                .into_iter()
+                .map(|s| (s.id, s.description, s.enabled_for))
+                .collect()
+        };
+        assert_eq!(key(parallel), key(serial));
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    fn test_skill(name: &str, source: &str, pinned: bool) -> SkillInfo {
+        SkillInfo {
+            id: format!("{source}:{name}"),
+            name: name.to_string(),
+            description: String::new(),
+            path: format!("/skills/{source}/{name}"),
+            source: source.to_string(),
+            enabled_for: vec![source.to_string()],
+            updated_at: String::new(),
+            github_repo_url: None,
+            github_skill_path: None,
+            pinned,
+            tags: vec![],
+            license: None,
+            version: None,
+            author: None,
             enabled: true,
+            skill_md_bytes: 0,
+            conflicts: vec![],
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn normalize_permissions_keeps_scripts_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = env::temp_dir().join(format!("skillsyoga-perms-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("scripts")).unwrap();
+        fs::write(tmp.join("SKILL.md"), "---\nname: x\n---\n").unwrap();
+        fs::write(tmp.join("run.sh"), "#!/bin/sh\necho hi\n").unwrap();
+        fs::write(tmp.join("scripts/helper.py"), "print('hi')\n").unwrap();
+        let mode = |rel: &str| fs::metadata(tmp.join(rel)).unwrap().permissions().mode() & 0o777;
+        for (rel, m) in [("SKILL.md", 0o777), ("run.sh", 0o644), ("scripts/helper.py", 0o600)] {
+            fs::set_permissions(tmp.join(rel), fs::Permissions::from_mode(m)).unwrap();
+        }
+
+        let changed = normalize_permissions(&tmp).unwrap();
+        let paths: Vec<&str> = changed.iter().map(|c| c.relative_path.as_str()).collect();
+        assert!(paths.contains(&"SKILL.md"));
+        assert_eq!(mode("SKILL.md"), 0o644);
+        assert_eq!(mode("run.sh"), 0o755);
+        assert_eq!(mode("scripts/helper.py"), 0o755);
+        assert!(normalize_permissions(&tmp).unwrap().is_empty());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    fn copied_files(root: &Path) -> Vec<String> {
+        let mut files = vec![];
+        let mut stack = vec![root.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            for entry in fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                } else {
+                    let rel = path.strip_prefix(root).unwrap();
+                    files.push(rel.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn copy_dir_recursive_honors_skillignore_globs() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-skillignore-{}", std::process::id()));
+        let src = tmp.join("src");
+        for dir in [".git", "node_modules/pkg", "fixtures/big", "docs", "lib/fixtures"] {
+            fs::create_dir_all(src.join(dir)).unwrap();
+        }
+        for file in [
+            "SKILL.md",
+            ".git/HEAD",
+            "node_modules/pkg/index.js",
+            "fixtures/big/data.bin",
+            "docs/debug.log",
+            "docs/keep.log",
+            "docs/guide.md",
+            "lib/fixtures/sample.txt",
+            "notes.tmp",
+        ] {
+            fs::write(src.join(file), "x").unwrap();
+        }
+        fs::write(
+            src.join(SKILL_IGNORE_FILE),
+            "# build output\nnode_modules/\n/fixtures/\n*.log\n!keep.log\n*.tmp\n",
+        )
+        .unwrap();
+
+        copy_dir_recursive(&src, &tmp.join("out"), &SkillIgnore::standard()).unwrap();
+        assert_eq!(
+            copied_files(&tmp.join("out")),
+            vec![
+                ".skillignore",
+                "SKILL.md",
+                "docs/guide.md",
+                "docs/keep.log",
+                "lib/fixtures/sample.txt",
+            ]
+        );
+
+        copy_dir_recursive(&src, &tmp.join("all"), &SkillIgnore::default()).unwrap();
+        assert_eq!(copied_files(&tmp.join("all")).len(), 10);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn nested_skillignore_applies_below_its_folder() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-ignore-nested-{}", std::process::id()));
+        let src = tmp.join("src");
+        fs::create_dir_all(src.join("scripts/cache")).unwrap();
+        let files = ["SKILL.md", "cache.json", "scripts/run.sh", "scripts/out.txt", "scripts/cache/a"];
+        for file in files {
+            fs::write(src.join(file), "x").unwrap();
+        }
+        fs::write(src.join(SKILL_IGNORE_FILE), "*.txt\n").unwrap();
+        fs::write(src.join("scripts").join(SKILL_IGNORE_FILE), "cache*\n!out.txt\n").unwrap();
+
+        copy_dir_recursive(&src, &tmp.join("out"), &SkillIgnore::standard()).unwrap();
+        assert_eq!(
+            copied_files(&tmp.join("out")),
+            vec![
+                ".skillignore",
+                "SKILL.md",
+                "cache.json",
+                "scripts/.skillignore",
+                "scripts/out.txt",
+                "scripts/run.sh",
+            ]
+        );
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn copy_dir_atomic_leaves_nothing_behind_on_failure() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-atomic-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("src/nested")).unwrap();
+        fs::write(tmp.join("src/SKILL.md"), "skill").unwrap();
+        fs::write(tmp.join("src/nested/file.txt"), "data").unwrap();
+        fs::create_dir_all(tmp.join("out")).unwrap();
+
+        let keep_all = SkillIgnore::default();
+        copy_dir_atomic(&tmp.join("src"), &tmp.join("out/copy"), &keep_all).unwrap();
+        assert_eq!(fs::read_to_string(tmp.join("out/copy/nested/file.txt")).unwrap(), "data");
+
+        assert!(copy_dir_atomic(&tmp.join("missing"), &tmp.join("out/broken"), &keep_all).is_err());
+        let names: Vec<String> = fs::read_dir(tmp.join("out"))
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["copy".to_string()]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn miscased_manifest_detects_and_fixes_variants() {
+        let tmp = std::env::temp_dir().join(format!("skillsyoga-miscase-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("lower")).unwrap();
+        fs::create_dir_all(tmp.join("ok")).unwrap();
+        fs::write(tmp.join("lower/skill.md"), "# Lower").unwrap();
+        fs::write(tmp.join("ok/SKILL.md"), "# Ok").unwrap();
+
+        assert!(miscased_manifest(&tmp.join("ok")).is_none());
+        let hit = miscased_manifest(&tmp.join("lower")).unwrap();
+        assert_eq!(hit.file_name().unwrap(), "skill.md");
+
+        let fixed = fix_manifest_name(&hit).unwrap();
+        assert_eq!(fixed, tmp.join("lower/SKILL.md"));
+        let names: Vec<String> = fs::read_dir(tmp.join("lower"))
+            .unwrap()
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["SKILL.md".to_string()]);
+        assert!(miscased_manifest(&tmp.join("lower")).is_none());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn extract_external_links_finds_markdown_auto_and_bare_links() {
+        let content = "See [the docs](https://example.com/docs) first.\n\
+                       Autolink <http://a.test/x> and bare https://b.test/y.\n\
+                       Relative [file](./ref.md) is ignored.";
+        assert_eq!(
+            extract_external_links(content),
+            vec![
+                (1, "https://example.com/docs".to_string(), Some("the docs".to_string())),
+                (2, "http://a.test/x".to_string(), None),
+                (2, "https://b.test/y".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn shingle_similarity_ignores_case_punctuation_and_frontmatter() {
+        let a = body_shingles("---\nname: one\n---\nUse the tool, then check the output carefully.");
+        let b = body_shingles("---\nname: two\n---\nuse the tool then check the OUTPUT carefully");
+        let c = body_shingles("Completely different instructions about deployment pipelines.");
+        assert_eq!(shingle_similarity(&a, &b), 1.0);
+        assert!(shingle_similarity(&a, &c) < 0.1);
+        assert_eq!(shingle_similarity(&HashSet::new(), &HashSet::new()), 0.0);
+    }
+
+    #[test]
+    fn body_word_count_skips_frontmatter() {
+        assert_eq!(body_word_count("---\nname: a b c\n---\n\nOne two  three."), 3);
+        assert_eq!(body_word_count("no frontmatter here"), 3);
+        assert_eq!(body_len("---\nname: a\n---\n\nbody"), 4);
+    }
+
+    #[test]
+    fn frontmatter_name_ignores_heading_fallback() {
+        assert_eq!(
+            frontmatter_name("---\nname: Real Name\n---\n# Heading"),
+            Some("Real Name".to_string())
+        );
+        assert_eq!(frontmatter_name("# Just a heading\n\nProse."), None);
+        assert_eq!(frontmatter_name("---\ndescription: only\n---\n"), None);
+    }
+
+    #[test]
+    fn with_frontmatter_name_fills_only_a_missing_name() {
+        assert_eq!(
+            with_frontmatter_name("---\ndescription: d\n---\nBody", "my-skill").unwrap(),
+            "---\nname: my-skill\ndescription: d\n---\nBody"
+        );
+        assert_eq!(
+            with_frontmatter_name("---\r\ndescription: d\r\n---\r\n", "a: b").unwrap(),
+            "---\r\nname: 'a: b'\r\ndescription: d\r\n---\r\n"
+        );
+        let named = "---\nname: kept\ndescription: d\n---\n";
+        assert_eq!(with_frontmatter_name(named, "other").unwrap(), named);
+        assert_eq!(with_frontmatter_name("# Heading", "other").unwrap(), "# Heading");
+    }
+
+    #[test]
+    fn skill_tree_nests_entries_folders_first() {
+        let entry = |relative_path: &str, is_dir: bool| SkillFileEntry {
+            relative_path: relative_path.to_string(),
+            is_dir,
         };
+        let entries = [
+            entry("SKILL.md", false),
+            entry("a.txt", false),
+            entry("scripts", true),
+            entry("scripts/run.sh", false),
+            entry("scripts/lib", true),
+            entry("scripts/lib/util.sh", false),
+            entry("orphan/file.md", false),
+        ];
+        let tree = build_skill_tree(&entries);
+        let names: Vec<&str> = tree.iter().map(|node| node.name.as_str()).collect();
+        assert_eq!(names, ["scripts", "SKILL.md", "a.txt"]);
+        let scripts = &tree[0].children;
+        assert_eq!(scripts[0].relative_path, "scripts/lib");
+        assert_eq!(scripts[0].children[0].relative_path, "scripts/lib/util.sh");
+        assert_eq!(scripts[1].name, "run.sh");
+        assert!(scripts[1].children.is_empty());
+    }
+
+    #[test]
+    fn binary_files_are_sniffed() {
+        assert!(!looks_binary(b"plain text\n"));
+        assert!(!looks_binary("caf\u{e9}".as_bytes()));
+        assert!(looks_binary(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"));
+        assert!(looks_binary(b"caf\xe9"));
+    }
+
+    #[test]
+    fn decode_text_handles_legacy_encodings() {
+        assert_eq!(decode_text(b"name: plain"), ("name: plain".to_string(), None));
+        assert_eq!(decode_text(b"\xEF\xBB\xBFname: bom"), ("name: bom".to_string(), None));
+
+        let utf16: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain("name: wide".encode_utf16().flat_map(|u| u.to_le_bytes()))
+            .collect();
+        assert_eq!(decode_text(&utf16), ("name: wide".to_string(), Some("UTF-16LE")));
+
+        let (text, encoding) = decode_text(b"name: caf\xE9 cr\xE8me br\xFBl\xE9e");
+        assert_eq!(text, "name: café crème brûlée");
+        assert!(encoding.is_some());
+    }
+
+    #[test]
+    fn frontmatter_after_a_bom_is_parsed() {
+        let content = "\u{feff}---\nname: Bom Skill\ndescription: d\n---\n";
+        let meta = parse_skill_metadata(content, "dir");
+        assert_eq!(meta.name, "Bom Skill");
+        assert_eq!(meta.description, "d");
+        let meta = parse_skill_metadata("\u{feff}# Heading Only\n", "dir");
+        assert_eq!(meta.name, "Heading Only");
+    }
+
+    #[test]
+    fn skill_templates_pass_validation() {
+        for template_id in SKILL_TEMPLATE_IDS {
+            let description = "Fill forms: with\n care.";
+            let content = render_skill_template(template_id, "PDF Tools", description).unwrap();
+            let issues = validate_skill_content(&content);
+            assert!(issues.is_empty(), "{template_id}: {issues:?}");
+            let meta = parse_skill_metadata(&content, "dir");
+            assert_eq!(meta.name, "pdf-tools");
+            assert_eq!(meta.description, "Fill forms: with care.");
+        }
+        assert!(render_skill_template("basic", "  ", "d").is_err());
+        assert!(render_skill_template("missing", "n", "d").is_err());
+    }
+
+    #[test]
+    fn matching_line_is_found_ignoring_case() {
+        let text = "---\nname: db\n---\n  Connect to Postgres first.\n";
+        assert_eq!(
+            find_matching_line(text, "postgres"),
+            Some((4, "Connect to Postgres first.".to_string()))
+        );
+        assert_eq!(find_matching_line(text, "mysql"), None);
+
+        let long = format!("{} postgres {}", "a".repeat(300), "b".repeat(300));
+        let (line, snippet) = find_matching_line(&long, "postgres").unwrap();
+        assert_eq!(line, 1);
+        assert!(snippet.contains("postgres"));
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert_eq!(snippet.chars().count(), SNIPPET_MAX_CHARS + 2);
+    }
+
+    #[test]
+    fn custom_template_placeholders_are_filled() {
+        let body = "---\nname: {{name}}\ndescription: {{description}}\n---\n\n# {{name}}\n";
+        let content = fill_skill_template(body, "PDF Tools", "Fill\n forms.").unwrap();
+        assert_eq!(
+            content,
+            "---\nname: pdf-tools\ndescription: Fill forms.\n---\n\n# pdf-tools\n"
+        );
+        assert!(fill_skill_template(body, "n", " ").is_err());
+    }
+
+    #[test]
+    fn custom_template_escapes_yaml_in_frontmatter_only() {
+        let body = "---\nname: {{name}}\ndescription: {{description}}\n---\n\n{{description}}\n";
+        let content = fill_skill_template(body, "Forms", "Fill forms: with care").unwrap();
+        assert_eq!(
+            content,
+            "---\nname: forms\ndescription: 'Fill forms: with care'\n---\n\nFill forms: with care\n"
+        );
+        assert!(validate_skill_content(&content)
+            .iter()
+            .all(|issue| issue.severity != "error"));
+    }
+
+    #[test]
+    fn diff_snapshots_reports_added_changed_and_removed() {
+        let alpha = test_skill("alpha", "cursor", false);
+        let beta = test_skill("beta", "cursor", false);
+        let tool = test_tool(Path::new("/skills/cursor"));
+        let prev = snapshot_dashboard(&[alpha.clone(), beta.clone()], std::slice::from_ref(&tool));
+
+        let mut pinned_alpha = alpha.clone();
+        pinned_alpha.pinned = true;
+        let gamma = test_skill("gamma", "cursor", false);
+        let mut disabled = tool.clone();
+        disabled.enabled = false;
+        let next = snapshot_dashboard(&[pinned_alpha, gamma.clone()], &[disabled]);
+
+        let diff = diff_snapshots(&prev, &next);
+        assert_eq!(diff.added, vec![gamma.path]);
+        assert_eq!(diff.changed, vec![alpha.path]);
+        assert_eq!(diff.removed, vec![beta.path]);
+        assert_eq!(diff.changed_tools, vec!["tool".to_string()]);
+        assert!(diff.removed_tools.is_empty());
+        assert_eq!(diff_snapshots(&next, &next), SnapshotDiff::default());
+    }
+
+    #[test]
+    fn merge_skills_sorts_pinned_first() {
+        let merged = merge_skills(
+            vec![
+                test_skill("alpha", "cursor", false),
+                test_skill("zeta", "cursor", true),
+                test_skill("beta", "codex", false),
+            ],
+            "none",
+        );
+        let names: Vec<&str> = merged.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["zeta", "alpha", "beta"]);
+    }
+
+    #[test]
+    fn merge_skills_none_keeps_every_directory() {
+        let merged = merge_skills(
+            vec![
+                test_skill("alpha", "cursor", false),
+                test_skill("alpha", "codex", false),
+            ],
+            "none",
+        );
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn merge_skills_slug_keeps_same_name_different_folders_apart() {
+        let mut a = test_skill("Git Workflow", "cursor", false);
+        a.path = "/skills/cursor/git-workflow".to_string();
+        let mut b = test_skill("Git Workflow", "cursor", false);
+        b.path = "/skills/cursor/team-git-conventions".to_string();
+
+        let by_slug = merge_skills(vec![a.clone(), b.clone()], "slug");
+        assert_eq!(by_slug.len(), 2);
+
+        let by_name = merge_skills(vec![a, b], "name");
+        assert_eq!(by_name.len(), 1);
+    }
+
+    #[test]
+    fn merge_skills_slug_unions_tools_for_same_folder() {
+        let mut a = test_skill("alpha", "cursor", false);
+        a.path = "/skills/cursor/alpha".to_string();
+        let mut b = test_skill("alpha v2", "codex", false);
+        b.path = "/skills/codex/alpha".to_string();
+
+        let merged = merge_skills(vec![a, b], "slug");
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].enabled_for, vec!["cursor", "codex"]);
+    }
+
+    #[test]
+    fn merge_skills_keeps_newest_primary_and_reports_conflicts() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-conflicts-{}", std::process::id()));
+        let skill = |tool: &str, body: &str, updated_at: &str| {
+            let dir = tmp.join(tool).join("git-workflow");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("SKILL.md"), body).unwrap();
+            let mut skill = test_skill("git-workflow", tool, false);
+            skill.path = dir.to_string_lossy().to_string();
+            skill.updated_at = updated_at.to_string();
+            skill
+        };
+        let a = skill("claude", "# Ours", "2026-01-01T00:00:00Z");
+        let b = skill("codex", "# Theirs", "2026-03-01T00:00:00Z");
+        let c = skill("cursor", "# Theirs", "2026-02-01T00:00:00Z");
+
+        let merged = merge_skills(vec![a.clone(), b.clone(), c], "name");
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].path, b.path);
+        assert_eq!(merged[0].enabled_for, vec!["codex", "claude", "cursor"]);
+        assert_eq!(merged[0].conflicts, vec![a.path]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn interrupted_write_leaves_the_previous_file() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-atomic-write-{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+        let skill_md = tmp.join("SKILL.md");
+        fs::write(&skill_md, "---\nname: Intact\n---\n").unwrap();
+
+        let result = replace_file(&skill_md, |file| {
+            io::Write::write_all(file, b"---\nname: Trunc")?;
+            Err(io::Error::other("disk full"))
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&skill_md).unwrap(), "---\nname: Intact\n---\n");
+        assert_eq!(fs::read_dir(&tmp).unwrap().count(), 1, "staging file left behind");
+
+        write_file_atomic(&skill_md, b"---\nname: Saved\n---\n").unwrap();
+        assert_eq!(fs::read_to_string(&skill_md).unwrap(), "---\nname: Saved\n---\n");
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn atomic_write_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = env::temp_dir().join(format!("skillsyoga-atomic-mode-{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+        let script = tmp.join("run.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        write_file_atomic(&script, b"#!/bin/sh\necho hi\n").unwrap();
+        assert_eq!(fs::metadata(&script).unwrap().permissions().mode() & 0o777, 0o755);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn unreadable_skill_is_skipped_not_fatal() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-skip-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("good")).unwrap();
+        fs::write(tmp.join("good/SKILL.md"), "# Good").unwrap();
+        // A SKILL.md that can't be read as a file.
+        fs::create_dir_all(tmp.join("broken/SKILL.md")).unwrap();
+
+        let tool = test_tool(&tmp);
+        let skills = collect_skills_from_tool(&tool).unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "Good");
+        let skipped = skipped_skills(&tool);
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].contains("broken"), "{skipped:?}");
+
+        fs::remove_dir_all(tmp.join("broken")).unwrap();
+        collect_skills_from_tool(&tool).unwrap();
+        assert!(skipped_skills(&tool).is_empty());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn source_meta_sidecar_is_reported_by_scan() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-sidecar-{}", std::process::id()));
+        fs::create_dir_all(tmp.join("remote")).unwrap();
+        fs::create_dir_all(tmp.join("local")).unwrap();
+        fs::write(tmp.join("remote/SKILL.md"), "# Remote").unwrap();
+        fs::write(tmp.join("local/SKILL.md"), "# Local").unwrap();
+        write_skill_source_meta(
+            &tmp.join("remote"),
+            "https://github.com/owner/repo",
+            Some("skills/remote/"),
+            None,
+        )
+        .unwrap();
+
+        let skills = collect_skills_from_tool(&test_tool(&tmp)).unwrap();
+        let remote = skills.iter().find(|s| s.name == "Remote").unwrap();
+        assert_eq!(remote.github_repo_url.as_deref(), Some("https://github.com/owner/repo"));
+        assert_eq!(remote.github_skill_path.as_deref(), Some("skills/remote"));
+        let local = skills.iter().find(|s| s.name == "Local").unwrap();
+        assert_eq!(local.github_repo_url, None);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn overlay_dir_replaces_source_files_and_keeps_local_ones() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-overlay-{}", std::process::id()));
+        let src = tmp.join("src");
+        let dst = tmp.join("dst");
+        fs::create_dir_all(src.join("scripts")).unwrap();
+        fs::create_dir_all(src.join(".git")).unwrap();
+        fs::write(src.join("SKILL.md"), "new").unwrap();
+        fs::write(src.join("scripts/run.sh"), "new run").unwrap();
+        fs::write(src.join("notes"), "file now").unwrap();
+        fs::create_dir_all(dst.join("scripts")).unwrap();
+        fs::create_dir_all(dst.join("notes")).unwrap();
+        fs::write(dst.join("SKILL.md"), "old").unwrap();
+        fs::write(dst.join("scripts/local.sh"), "mine").unwrap();
+        fs::write(dst.join("LOCAL.md"), "mine").unwrap();
+
+        overlay_dir(&src, &dst, &SkillIgnore::standard()).unwrap();
+
+        assert_eq!(fs::read_to_string(dst.join("SKILL.md")).unwrap(), "new");
+        assert_eq!(fs::read_to_string(dst.join("scripts/run.sh")).unwrap(), "new run");
+        assert_eq!(fs::read_to_string(dst.join("scripts/local.sh")).unwrap(), "mine");
+        assert_eq!(fs::read_to_string(dst.join("LOCAL.md")).unwrap(), "mine");
+        assert_eq!(fs::read_to_string(dst.join("notes")).unwrap(), "file now");
+        assert!(!dst.join(".git").exists());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn zip_dir_round_trips_nested_files() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-zip-{}", std::process::id()));
+        let src = tmp.join("skill");
+        fs::create_dir_all(src.join("scripts/empty")).unwrap();
+        fs::write(src.join("SKILL.md"), "# Zipped").unwrap();
+        fs::write(src.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+
+        let archive = tmp.join("skill.zip");
+        zip_dir(&src, &archive, "", &SkillIgnore::default()).unwrap();
+        let out = tmp.join("out");
+        unzip_into(&archive, &out).unwrap();
+
+        assert_eq!(fs::read_to_string(out.join("SKILL.md")).unwrap(), "# Zipped");
+        assert_eq!(fs::read_to_string(out.join("scripts/run.sh")).unwrap(), "#!/bin/sh\n");
+        assert!(out.join("scripts/empty").is_dir());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn zip_dir_nests_entries_under_prefix() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-zip-prefix-{}", std::process::id()));
+        let src = tmp.join("My Skill");
+        fs::create_dir_all(src.join(".git")).unwrap();
+        fs::write(src.join("SKILL.md"), "# Shared").unwrap();
+        fs::write(src.join(".git/HEAD"), "ref").unwrap();
+
+        let archive = tmp.join("shared.zip");
+        zip_dir(&src, &archive, "my-skill", &SkillIgnore::standard()).unwrap();
+        let out = tmp.join("out");
+        unzip_into(&archive, &out).unwrap();
+
+        assert_eq!(fs::read_to_string(out.join("my-skill/SKILL.md")).unwrap(), "# Shared");
+        assert_eq!(copied_files(&out), vec!["my-skill/SKILL.md"]);
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn unzip_checked_rejects_path_traversal() {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let tmp = env::temp_dir().join(format!("skillsyoga-zip-slip-{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+        let write_archive = |path: &Path, names: &[&str]| {
+            let mut writer = zip::ZipWriter::new(fs::File::create(path).unwrap());
+            for name in names {
+                writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+                writer.write_all(b"x").unwrap();
+            }
+            writer.finish().unwrap();
+        };
+
+        let evil_names = ["../up.txt", "skill/../../up.txt", "/abs.txt", "..\\win.txt", "C:/x"];
+        for evil in evil_names {
+            let archive = tmp.join("evil.zip");
+            write_archive(&archive, &["skill/SKILL.md", evil]);
+            let out = tmp.join("evil-out");
+            assert!(unzip_checked(&archive, &out, 1024).is_err(), "{evil}");
+            assert!(!out.join("skill/SKILL.md").exists(), "{evil}");
+        }
+        assert!(!tmp.join("up.txt").exists());
+
+        let archive = tmp.join("good.zip");
+        write_archive(&archive, &["skill/SKILL.md", "./skill/scripts/run.sh"]);
+        unzip_checked(&archive, &tmp.join("good-out"), 1024).unwrap();
+        assert_eq!(
+            copied_files(&tmp.join("good-out")),
+            vec!["skill/SKILL.md", "skill/scripts/run.sh"]
+        );
+        assert!(unzip_checked(&archive, &tmp.join("big-out"), 1).is_err());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn merge_groups_reports_collapsed_entries_and_identity() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-merge-groups-{}", std::process::id()));
+        for (tool, body) in [("cursor", "same"), ("codex", "same"), ("amp", "other")] {
+            fs::create_dir_all(tmp.join(tool).join("alpha")).unwrap();
+            fs::write(tmp.join(tool).join("alpha/SKILL.md"), body).unwrap();
+        }
+        let skill_at = |tool: &str| {
+            let mut skill = test_skill("alpha", tool, false);
+            skill.path = tmp.join(tool).join("alpha").to_string_lossy().to_string();
+            skill
+        };
+
+        let groups = merge_groups(vec![skill_at("cursor"), skill_at("codex")], "name");
+        assert_eq!(groups.len(), 1);
+        assert!(groups[0].identical);
+        assert_eq!(groups[0].members[0].tool_id, "codex");
+
+        let groups = merge_groups(vec![skill_at("cursor"), skill_at("amp")], "slug");
+        assert_eq!(groups.len(), 1);
+        assert!(!groups[0].identical);
+
+        assert!(merge_groups(vec![skill_at("cursor"), skill_at("amp")], "none").is_empty());
+        assert!(merge_groups(vec![test_skill("solo", "cursor", false)], "name").is_empty());
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn skill_cache_reuses_entry_when_mtimes_match() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-cache-test-{}", std::process::id()));
+        fs::create_dir_all(&tmp).unwrap();
+        let skill_dir = tmp.join("my-skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+        let skill_md = skill_dir.join("SKILL.md");
+        fs::write(&skill_md, "---\nname: Cached\ndescription: one\n---\n").unwrap();
+
+        let tool = test_tool(&tmp);
 
         let first = load_skill_cached(&tool, &skill_dir, &skill_md).unwrap();
         // Mutate content, but keep mtime identical by writing nothing new:
@@ -573,4 +3279,18 @@ mod tests {
 
         fs::remove_dir_all(&tmp).ok();
     }
+
+    #[test]
+    fn project_skill_roots_finds_dot_roots_and_drops_nested_ones() {
+        let tmp = env::temp_dir().join(format!("skillsyoga-project-{}", std::process::id()));
+        for dir in [".claude/skills/a", "tools/skills/b", "tools/skills/c"] {
+            fs::create_dir_all(tmp.join(dir)).unwrap();
+            fs::write(tmp.join(dir).join("SKILL.md"), "# Skill").unwrap();
+        }
+        let suffixes = [".claude/skills".to_string(), ".cursor/skills".to_string()];
+
+        let roots = project_skill_roots(&tmp, &suffixes);
+        assert_eq!(roots, [tmp.join(".claude/skills"), tmp.join("tools/skills")]);
+        fs::remove_dir_all(&tmp).ok();
+    }
 }
@@ -1,10 +1,13 @@
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::Manager;
 
 use crate::error::AppError;
 use crate::helpers::ensure_dir;
-use crate::models::AppState;
+use crate::models::{AppState, StateDiff, ToggleChange};
 
 pub fn app_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
     app.path()
@@ -27,6 +30,261 @@ pub fn load_state(app: &tauri::AppHandle) -> Result<AppState, AppError> {
     Ok(state)
 }
 
+/// Keychain account the GitHub token is stored under; the service is the
+/// app identifier.
+const GITHUB_TOKEN_ACCOUNT: &str = "github-token";
+
+fn github_token_entry(app: &tauri::AppHandle) -> Option<keyring::Entry> {
+    keyring::Entry::new(&app.config().identifier, GITHUB_TOKEN_ACCOUNT).ok()
+}
+
+/// Set once moving a plaintext token into the keychain has failed, so
+/// systems without a reachable keychain don't retry on every read.
+static TOKEN_MIGRATION_FAILED: AtomicBool = AtomicBool::new(false);
+
+/// The saved GitHub token, from the OS keychain or, where no keychain is
+/// available, from `state.json`. A token still in `state.json` from before
+/// keychain support is moved into the keychain on first read.
+pub fn load_github_token(app: &tauri::AppHandle) -> Result<Option<String>, AppError> {
+    let mut state = load_state(app)?;
+    let plaintext = state
+        .github_token
+        .as_deref()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_string);
+    let entry = github_token_entry(app);
+    if let Some(token) = plaintext {
+        if !TOKEN_MIGRATION_FAILED.load(Ordering::Relaxed) {
+            if entry.is_some_and(|entry| entry.set_password(&token).is_ok()) {
+                state.github_token = None;
+                save_state(app, &state)?;
+            } else {
+                TOKEN_MIGRATION_FAILED.store(true, Ordering::Relaxed);
+            }
+        }
+        return Ok(Some(token));
+    }
+    Ok(entry
+        .and_then(|entry| entry.get_password().ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty()))
+}
+
+/// Save (or with `None`, clear) the GitHub token. It goes to the OS
+/// keychain when one is available and to `state.json` only as a fallback;
+/// clearing removes it from both. A keychain that can't be reached only
+/// fails the clear when it still holds the token.
+pub fn store_github_token(app: &tauri::AppHandle, token: Option<&str>) -> Result<(), AppError> {
+    let entry = github_token_entry(app);
+    let mut state = load_state(app)?;
+    let Some(token) = token else {
+        state.github_token = None;
+        save_state(app, &state)?;
+        let Some(entry) = entry else {
+            return Ok(());
+        };
+        return match entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) if entry.get_password().is_ok() => Err(AppError::Io(std::io::Error::other(
+                format!("Failed to remove the GitHub token from the keychain: {e}"),
+            ))),
+            Err(_) => Ok(()),
+        };
+    };
+    state.github_token = if entry.is_some_and(|e| e.set_password(token).is_ok()) {
+        None
+    } else {
+        Some(token.to_string())
+    };
+    save_state(app, &state)
+}
+
+/// Read and parse a state file that is not the live `state.json`, e.g. a
+/// backup picked by the user.
+pub fn read_state_file(path: &Path) -> Result<AppState, AppError> {
+    if !path.is_file() {
+        return Err(AppError::NotFound(format!(
+            "State file not found: {}",
+            path.display()
+        )));
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Upper bound for the size limits. Writes are held in memory whole, so a
+/// limit beyond this would let one save exhaust it.
+pub const MAX_WRITE_BYTES_CEILING: u64 = 256 * 1024 * 1024;
+
+/// Reject an imported state whose values the setters would refuse, so an
+/// edited or foreign file can't put the app into a state it can't handle.
+pub fn validate_imported_state(state: &AppState) -> Result<(), AppError> {
+    use crate::helpers::normalize_registry_url;
+    use crate::tools::{built_in_tools, resolve_skills_paths, tools_overlapping_root};
+
+    if !matches!(state.skill_editor_default_mode.as_str(), "view" | "edit") {
+        return Err(AppError::Validation(format!(
+            "Invalid editor mode in state file: {}",
+            state.skill_editor_default_mode
+        )));
+    }
+    if !matches!(state.skill_merge_mode.as_str(), "none" | "name" | "slug") {
+        return Err(AppError::Validation(format!(
+            "Invalid merge mode in state file: {}",
+            state.skill_merge_mode
+        )));
+    }
+    let limits = [state.max_write_bytes, state.max_skill_md_bytes];
+    if limits.iter().any(|&bytes| bytes == 0 || bytes > MAX_WRITE_BYTES_CEILING) {
+        return Err(AppError::Validation(format!(
+            "Size limits in state file must be between 1 and {MAX_WRITE_BYTES_CEILING} bytes"
+        )));
+    }
+    if !(1..=8).contains(&state.editor_settings.tab_size) {
+        return Err(AppError::Validation(format!(
+            "Invalid tab size in state file: {}",
+            state.editor_settings.tab_size
+        )));
+    }
+    normalize_registry_url(&state.registry_url)?;
+    if let Some((tool_id, _)) = state.context_budgets.iter().find(|(_, &bytes)| bytes == 0) {
+        return Err(AppError::Validation(format!(
+            "Context budget for {tool_id} in state file must be greater than zero"
+        )));
+    }
+    let built_ins = built_in_tools();
+    for tool in &state.custom_tools {
+        if tool.id.trim().is_empty() || built_ins.iter().any(|builtin| builtin.id == tool.id) {
+            return Err(AppError::Validation(format!(
+                "Invalid custom tool id in state file: '{}'",
+                tool.id
+            )));
+        }
+        for root in resolve_skills_paths(&tool.skills_path, &state.project_roots)? {
+            let root = root.canonicalize().unwrap_or(root);
+            if let Some(other) = tools_overlapping_root(state, &root, &tool.id)?.first() {
+                return Err(AppError::Validation(format!(
+                    "Skills path {} of {} in state file overlaps a skills path of {other}",
+                    root.display(),
+                    tool.name
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Merge an imported state into `current`. Imported settings win; maps and
+/// id-keyed lists (custom tools, templates, projects) are merged entry by
+/// entry, pins and disabled skills are unioned, and a missing token or
+/// tool order keeps the current one.
+pub fn merge_states(current: AppState, imported: AppState) -> AppState {
+    let mut merged = current;
+    merged.tool_toggles.extend(imported.tool_toggles);
+    merged.tool_cli_overrides.extend(imported.tool_cli_overrides);
+    merged.context_budgets.extend(imported.context_budgets);
+    for tool in imported.custom_tools {
+        match merged.custom_tools.iter_mut().find(|t| t.id == tool.id) {
+            Some(existing) => *existing = tool,
+            None => merged.custom_tools.push(tool),
+        }
+    }
+    for template in imported.skill_templates {
+        match merged.skill_templates.iter_mut().find(|t| t.id == template.id) {
+            Some(existing) => *existing = template,
+            None => merged.skill_templates.push(template),
+        }
+    }
+    for project in imported.project_roots {
+        match merged.project_roots.iter_mut().find(|p| p.id == project.id) {
+            Some(existing) => *existing = project,
+            None => merged.project_roots.push(project),
+        }
+    }
+    if !imported.tool_order.is_empty() {
+        merged.tool_order = imported.tool_order;
+    }
+    if imported.github_token.is_some() {
+        merged.github_token = imported.github_token;
+    }
+    merged.pinned_skills.extend(imported.pinned_skills);
+    merged.disabled_skills.extend(imported.disabled_skills);
+    merged.skill_editor_default_mode = imported.skill_editor_default_mode;
+    merged.editor_settings = imported.editor_settings;
+    merged.skill_merge_mode = imported.skill_merge_mode;
+    merged.max_write_bytes = imported.max_write_bytes;
+    merged.max_skill_md_bytes = imported.max_skill_md_bytes;
+    merged.default_install_tool_id = imported.default_install_tool_id;
+    merged.registry_url = imported.registry_url;
+    merged
+}
+
+/// Describe what replacing `current` with `backup` would change.
+pub fn diff_states(current: &AppState, backup: &AppState) -> StateDiff {
+    let find = |state: &AppState, id: &str| state.custom_tools.iter().find(|t| t.id == id).cloned();
+
+    let mut diff = StateDiff::default();
+    for tool in &backup.custom_tools {
+        match find(current, &tool.id) {
+            None => diff.added_tools.push(tool.id.clone()),
+            Some(existing) => {
+                if existing.name != tool.name
+                    || existing.config_path != tool.config_path
+                    || existing.skills_path != tool.skills_path
+                {
+                    diff.changed_tools.push(tool.id.clone());
+                }
+            }
+        }
+    }
+    for tool in &current.custom_tools {
+        if find(backup, &tool.id).is_none() {
+            diff.removed_tools.push(tool.id.clone());
+        }
+    }
+    let has_project = |state: &AppState, id: &str| state.project_roots.iter().any(|p| p.id == id);
+    for project in &backup.project_roots {
+        if !has_project(current, &project.id) {
+            diff.added_projects.push(project.id.clone());
+        }
+    }
+    for project in &current.project_roots {
+        if !has_project(backup, &project.id) {
+            diff.removed_projects.push(project.id.clone());
+        }
+    }
+
+    let toggle_ids: BTreeSet<&String> = current
+        .tool_toggles
+        .keys()
+        .chain(backup.tool_toggles.keys())
+        .collect();
+    for id in toggle_ids {
+        let current_value = current.tool_toggles.get(id).copied();
+        let backup_value = backup.tool_toggles.get(id).copied();
+        if current_value != backup_value {
+            diff.toggle_changes.push(ToggleChange {
+                tool_id: id.clone(),
+                current: current_value,
+                backup: backup_value,
+            });
+        }
+    }
+
+    let token = |state: &AppState| {
+        state
+            .github_token
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    };
+    diff.tool_order_changed = current.tool_order != backup.tool_order;
+    diff.github_token_changed = token(current) != token(backup);
+    diff
+}
+
 pub fn save_state(app: &tauri::AppHandle, state: &AppState) -> Result<(), AppError> {
     let state_path = app_state_path(app)?;
     if let Some(parent) = state_path.parent() {
@@ -49,3 +307,196 @@ pub fn save_state(app: &tauri::AppHandle, state: &AppState) -> Result<(), AppErr
     fs::rename(&tmp_path, &state_path)?;
     Ok(())
 }
+
+/// Size past which `append_log_line` rotates the log into its backup.
+pub const LOG_MAX_BYTES: u64 = 2 * 1024 * 1024;
+
+pub fn log_path(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    Ok(app_data_dir(app)?.join("skillsyoga.log"))
+}
+
+/// Append `line` to the log at `path`. When that would take the file past
+/// `max_bytes` it is first moved to `<path>.1`, replacing the previous
+/// backup, so at most two files are kept.
+pub fn append_log_line(path: &Path, line: &str, max_bytes: u64) -> Result<(), AppError> {
+    if let Some(parent) = path.parent() {
+        ensure_dir(parent)?;
+    }
+    let len = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+    if len > 0 && len + line.len() as u64 + 1 > max_bytes {
+        let mut backup = path.as_os_str().to_os_string();
+        backup.push(".1");
+        fs::rename(path, backup)?;
+    }
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CustomToolInput, EditorSettings, ProjectRoot};
+
+    fn custom_tool(id: &str, skills_path: &str) -> CustomToolInput {
+        CustomToolInput {
+            id: id.to_string(),
+            name: id.to_string(),
+            config_path: String::new(),
+            skills_path: vec![skills_path.to_string()],
+            cli: false,
+        }
+    }
+
+    #[test]
+    fn diff_states_reports_tool_toggle_order_and_token_changes() {
+        let current = AppState {
+            custom_tools: vec![custom_tool("kept", "/a"), custom_tool("gone", "/b")],
+            tool_toggles: [("cursor".to_string(), true)].into(),
+            tool_order: vec!["cursor".into(), "kept".into()],
+            github_token: Some("secret".into()),
+            ..AppState::default()
+        };
+        let backup = AppState {
+            custom_tools: vec![custom_tool("kept", "/moved"), custom_tool("new", "/c")],
+            tool_toggles: [("cursor".to_string(), false), ("codex".to_string(), true)].into(),
+            tool_order: vec!["kept".into(), "cursor".into()],
+            ..AppState::default()
+        };
+
+        let diff = diff_states(&current, &backup);
+        assert_eq!(diff.added_tools, vec!["new".to_string()]);
+        assert_eq!(diff.removed_tools, vec!["gone".to_string()]);
+        assert_eq!(diff.changed_tools, vec!["kept".to_string()]);
+        assert_eq!(
+            diff.toggle_changes,
+            vec![
+                ToggleChange {
+                    tool_id: "codex".into(),
+                    current: None,
+                    backup: Some(true),
+                },
+                ToggleChange {
+                    tool_id: "cursor".into(),
+                    current: Some(true),
+                    backup: Some(false),
+                },
+            ]
+        );
+        assert!(diff.tool_order_changed);
+        assert!(diff.github_token_changed);
+        assert_eq!(diff_states(&current, &current), StateDiff::default());
+    }
+
+    #[test]
+    fn log_rotates_into_a_single_backup() {
+        let dir = std::env::temp_dir().join(format!("skillsyoga-log-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let log = dir.join("app.log");
+        let backup = dir.join("app.log.1");
+
+        append_log_line(&log, "first", 16).unwrap();
+        append_log_line(&log, "second", 16).unwrap();
+        assert_eq!(fs::read_to_string(&log).unwrap(), "first\nsecond\n");
+
+        append_log_line(&log, "third", 16).unwrap();
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "first\nsecond\n");
+        assert_eq!(fs::read_to_string(&log).unwrap(), "third\n");
+
+        append_log_line(&log, "fourth", 16).unwrap();
+        append_log_line(&log, "fifth", 16).unwrap();
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "third\nfourth\n");
+        assert_eq!(fs::read_to_string(&log).unwrap(), "fifth\n");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn merge_states_keeps_current_token_and_merges_tools() {
+        let current = AppState {
+            custom_tools: vec![custom_tool("kept", "/a"), custom_tool("mine", "/m")],
+            tool_toggles: [("cursor".to_string(), true)].into(),
+            tool_order: vec!["cursor".into()],
+            github_token: Some("secret".into()),
+            ..AppState::default()
+        };
+        let imported = AppState {
+            custom_tools: vec![custom_tool("kept", "/moved"), custom_tool("new", "/c")],
+            project_roots: vec![ProjectRoot {
+                id: "project-app".into(),
+                name: "app".into(),
+                path: "/code/app".into(),
+                skills_paths: vec!["/code/app/.claude/skills".into()],
+            }],
+            tool_toggles: [("codex".to_string(), false)].into(),
+            skill_merge_mode: "slug".into(),
+            ..AppState::default()
+        };
+        assert!(validate_imported_state(&imported).is_ok());
+
+        let merged = merge_states(current, imported);
+        let tools: Vec<(&str, &str)> = merged
+            .custom_tools
+            .iter()
+            .map(|t| (t.id.as_str(), t.skills_path[0].as_str()))
+            .collect();
+        assert_eq!(tools, [("kept", "/moved"), ("mine", "/m"), ("new", "/c")]);
+        assert_eq!(merged.tool_toggles.len(), 2);
+        assert_eq!(merged.tool_order, vec!["cursor".to_string()]);
+        assert_eq!(merged.github_token.as_deref(), Some("secret"));
+        assert_eq!(merged.skill_merge_mode, "slug");
+        assert_eq!(merged.project_roots.len(), 1);
+
+    }
+
+    #[test]
+    fn validate_imported_state_applies_the_setter_checks() {
+        let editor_settings = EditorSettings {
+            tab_size: 0,
+            ..EditorSettings::default()
+        };
+        let bad = [
+            AppState {
+                skill_merge_mode: "everything".into(),
+                ..AppState::default()
+            },
+            AppState {
+                editor_settings,
+                ..AppState::default()
+            },
+            AppState {
+                max_write_bytes: MAX_WRITE_BYTES_CEILING + 1,
+                ..AppState::default()
+            },
+            AppState {
+                registry_url: "ftp://skills.example".into(),
+                ..AppState::default()
+            },
+            AppState {
+                context_budgets: [("cursor".to_string(), 0)].into(),
+                ..AppState::default()
+            },
+            AppState {
+                custom_tools: vec![custom_tool("a", "/x/skills"), custom_tool("b", "/x/skills/b")],
+                ..AppState::default()
+            },
+        ];
+        for state in &bad {
+            assert!(validate_imported_state(state).is_err());
+        }
+    }
+
+    #[test]
+    fn custom_tool_skills_path_accepts_a_string_or_a_list() {
+        let old: CustomToolInput = serde_json::from_str(
+            r#"{"id":"a","name":"A","configPath":"~/.a","skillsPath":"~/.a/skills"}"#,
+        )
+        .unwrap();
+        assert_eq!(old.skills_path, ["~/.a/skills"]);
+
+        let new: CustomToolInput = serde_json::from_str(
+            r#"{"id":"a","name":"A","configPath":"","skillsPath":["~/a",".agents/skills"]}"#,
+        )
+        .unwrap();
+        assert_eq!(new.skills_path, ["~/a", ".agents/skills"]);
+    }
+}
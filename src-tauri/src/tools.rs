@@ -1,6 +1,8 @@
+use std::path::{Component, Path, PathBuf};
+
 use crate::error::AppError;
-use crate::helpers::expand_home;
-use crate::models::{AppState, CustomToolInput, SourceInfo, ToolInfo};
+use crate::helpers::{expand_home, find_executable};
+use crate::models::{AppState, CustomToolInput, ProjectRoot, RootOverlap, SourceInfo, ToolInfo};
 use crate::state::load_state;
 
 pub fn built_in_tools() -> Vec<CustomToolInput> {
@@ -9,115 +11,134 @@ pub fn built_in_tools() -> Vec<CustomToolInput> {
             id: "cursor".to_string(),
             name: "Cursor".to_string(),
             config_path: "~/.cursor".to_string(),
-            skills_path: "~/.cursor/skills".to_string(),
+            skills_path: vec!["~/.cursor/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "gemini".to_string(),
             name: "Gemini CLI".to_string(),
             config_path: "~/.gemini".to_string(),
-            skills_path: "~/.gemini/skills".to_string(),
+            skills_path: vec!["~/.gemini/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "antigravity".to_string(),
             name: "Antigravity".to_string(),
             config_path: "~/.gemini/antigravity".to_string(),
-            skills_path: "~/.gemini/antigravity/skills".to_string(),
+            skills_path: vec!["~/.gemini/antigravity/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "trae".to_string(),
             name: "Trae".to_string(),
             config_path: "~/.trae".to_string(),
-            skills_path: "~/.trae/skills".to_string(),
+            skills_path: vec!["~/.trae/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "claude-code".to_string(),
             name: "Claude Code".to_string(),
             config_path: "~/.claude".to_string(),
-            skills_path: "~/.claude/skills".to_string(),
+            skills_path: vec!["~/.claude/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "codex".to_string(),
             name: "Codex".to_string(),
             config_path: "~/.codex".to_string(),
-            skills_path: "~/.codex/skills".to_string(),
+            skills_path: vec!["~/.codex/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "openclaw".to_string(),
             name: "OpenClaw".to_string(),
             config_path: "~/.openclaw".to_string(),
-            skills_path: "~/.openclaw/skills".to_string(),
+            skills_path: vec!["~/.openclaw/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "opencode".to_string(),
             name: "OpenCode".to_string(),
             config_path: "~/.config/opencode".to_string(),
-            skills_path: "~/.config/opencode/skills".to_string(),
+            skills_path: vec!["~/.config/opencode/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "goose".to_string(),
             name: "Goose".to_string(),
             config_path: "~/.config/goose".to_string(),
-            skills_path: "~/.config/goose/skills".to_string(),
+            skills_path: vec!["~/.config/goose/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "letta".to_string(),
             name: "Letta".to_string(),
             config_path: "~/.letta".to_string(),
-            skills_path: "~/.letta/skills".to_string(),
+            skills_path: vec!["~/.letta/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "amp".to_string(),
             name: "Amp".to_string(),
             config_path: "~/.config/amp".to_string(),
-            skills_path: "~/.config/agents/skills".to_string(),
+            skills_path: vec!["~/.config/agents/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "github-copilot".to_string(),
             name: "GitHub Copilot".to_string(),
             config_path: "~/.copilot".to_string(),
-            skills_path: "~/.copilot/skills".to_string(),
+            skills_path: vec!["~/.copilot/skills".to_string()],
+            cli: true,
         },
         CustomToolInput {
             id: "windsurf".to_string(),
             name: "Windsurf".to_string(),
             config_path: "~/.codeium/windsurf".to_string(),
-            skills_path: "~/.codeium/windsurf/skills".to_string(),
+            skills_path: vec!["~/.codeium/windsurf/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "cline".to_string(),
             name: "Cline".to_string(),
             config_path: "~/.cline".to_string(),
-            skills_path: "~/.cline/skills".to_string(),
+            skills_path: vec!["~/.cline/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "roo-code".to_string(),
             name: "Roo Code".to_string(),
             config_path: "~/.roo".to_string(),
-            skills_path: "~/.roo/skills".to_string(),
+            skills_path: vec!["~/.roo/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "marscode".to_string(),
             name: "MarsCode".to_string(),
             config_path: "~/.marscode".to_string(),
-            skills_path: "~/.marscode/skills".to_string(),
+            skills_path: vec!["~/.marscode/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "tongyi-lingma".to_string(),
             name: "Tongyi Lingma".to_string(),
             config_path: "~/.lingma".to_string(),
-            skills_path: "~/.lingma/skills".to_string(),
+            skills_path: vec!["~/.lingma/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "baidu-comate".to_string(),
             name: "Baidu Comate".to_string(),
             config_path: "~/.comate".to_string(),
-            skills_path: "~/.comate/skills".to_string(),
+            skills_path: vec!["~/.comate/skills".to_string()],
+            cli: false,
         },
         CustomToolInput {
             id: "microclaw".to_string(),
             name: "MicroClaw".to_string(),
             config_path: "~/.microclaw".to_string(),
-            skills_path: "~/.microclaw/skills".to_string(),
+            skills_path: vec!["~/.microclaw/skills".to_string()],
+            cli: true,
         },
     ]
 }
@@ -166,14 +187,101 @@ pub fn curated_sources() -> Vec<SourceInfo> {
     sources
 }
 
+/// Files inside a built-in tool's config dir, and executables on `PATH`,
+/// that only a real installation leaves behind. Tools without any fall
+/// back to the config dir merely existing.
+fn detection_hints(tool_id: &str) -> (&'static [&'static str], &'static [&'static str]) {
+    match tool_id {
+        "cursor" => (&["argv.json", "extensions"], &["cursor"]),
+        "gemini" => (&["settings.json", "oauth_creds.json"], &["gemini"]),
+        "claude-code" => (&["settings.json", "projects"], &["claude"]),
+        "codex" => (&["config.toml", "auth.json"], &["codex"]),
+        "openclaw" => (&["openclaw.json"], &["openclaw"]),
+        "opencode" => (&["opencode.json", "opencode.jsonc"], &["opencode"]),
+        "goose" => (&["config.yaml"], &["goose"]),
+        "letta" => (&["settings.json"], &["letta"]),
+        "amp" => (&["settings.json"], &["amp"]),
+        "github-copilot" => (&["config.json"], &["copilot"]),
+        "windsurf" => (&["argv.json", "mcp_config.json"], &["windsurf"]),
+        "trae" => (&["argv.json"], &["trae"]),
+        "microclaw" => (&["config.toml"], &["microclaw"]),
+        _ => (&[], &[]),
+    }
+}
+
+/// Decide whether a tool is installed and how we know. An existing skills
+/// dir always counts; a bare config dir only counts for tools with no
+/// stronger signal, since other software creates dirs like `~/.config/goose`.
+fn detect_tool(
+    config: &Path,
+    skills: &[PathBuf],
+    markers: &[&str],
+    binaries: &[&str],
+    cli: bool,
+) -> &'static str {
+    if markers.iter().any(|marker| config.join(marker).exists()) {
+        "marker"
+    } else if skills.iter().any(|root| root.is_dir()) {
+        "skillsDir"
+    } else if cli && binaries.iter().any(|name| find_executable(name).is_some()) {
+        "binary"
+    } else if markers.is_empty() && binaries.is_empty() && config.exists() {
+        "configDir"
+    } else {
+        "none"
+    }
+}
+
+/// Expand a tool's skills paths. Absolute and `~` paths come first, as
+/// given; a relative one (`./.agents/skills`) is project-local and names
+/// that folder in every registered project, in project order.
+pub fn resolve_skills_paths(
+    paths: &[String],
+    projects: &[ProjectRoot],
+) -> Result<Vec<PathBuf>, AppError> {
+    let mut roots = vec![];
+    let mut project_local = vec![];
+    for path in paths {
+        let root = expand_home(path)?;
+        if root.is_absolute() {
+            roots.push(root);
+            continue;
+        }
+        let relative: PathBuf = root
+            .components()
+            .filter(|part| !matches!(part, Component::CurDir))
+            .collect();
+        for project in projects {
+            project_local.push(expand_home(&project.path)?.join(&relative));
+        }
+    }
+    roots.extend(project_local);
+    Ok(roots)
+}
+
 pub fn tool_input_to_info(
     tool: &CustomToolInput,
     state: &AppState,
     kind: &str,
 ) -> Result<ToolInfo, AppError> {
     let config = expand_home(&tool.config_path)?;
-    let skills = expand_home(&tool.skills_path)?;
-    let detected = config.exists() || skills.exists();
+    let skills = resolve_skills_paths(&tool.skills_path, &state.project_roots)?;
+    let skills_paths: Vec<String> = skills
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    let cli = state
+        .tool_cli_overrides
+        .get(&tool.id)
+        .copied()
+        .unwrap_or(tool.cli);
+    let (markers, binaries) = if kind == "builtin" {
+        detection_hints(&tool.id)
+    } else {
+        (&[][..], &[][..])
+    };
+    let detection_method = detect_tool(&config, &skills, markers, binaries, cli);
+    let detected = detection_method != "none";
     let enabled = state
         .tool_toggles
         .get(&tool.id)
@@ -185,12 +293,27 @@ pub fn tool_input_to_info(
         name: tool.name.clone(),
         kind: kind.to_string(),
         config_path: config.to_string_lossy().to_string(),
-        skills_path: skills.to_string_lossy().to_string(),
+        skills_path: skills_paths.first().cloned().unwrap_or_default(),
+        skills_paths,
         detected,
+        detection_method: detection_method.to_string(),
         enabled,
+        cli,
     })
 }
 
+/// The tool a registered project is listed as, with the project directory
+/// standing in for the config path.
+pub fn project_tool_input(project: &ProjectRoot) -> CustomToolInput {
+    CustomToolInput {
+        id: project.id.clone(),
+        name: project.name.clone(),
+        config_path: project.path.clone(),
+        skills_path: project.skills_paths.clone(),
+        cli: false,
+    }
+}
+
 /// Build the tool list without scanning skills (lightweight).
 pub fn resolve_tools(app: &tauri::AppHandle) -> Result<Vec<ToolInfo>, AppError> {
     let state = load_state(app)?;
@@ -204,10 +327,148 @@ pub fn resolve_tools(app: &tauri::AppHandle) -> Result<Vec<ToolInfo>, AppError>
         tools.push(tool_input_to_info(custom, &state, "custom")?);
     }
 
+    for project in &state.project_roots {
+        tools.push(tool_input_to_info(&project_tool_input(project), &state, "project")?);
+    }
+
     tools.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(tools)
 }
 
+/// Add tools missing from a persisted `tool_order` (e.g. built-ins shipped
+/// in a newer release) right after the last enabled tool in that order, so
+/// they show up where the user is looking instead of at the bottom. Missing
+/// tools are inserted in name order. Returns whether `order` changed.
+///
+/// An empty order means the user never reordered anything; it is left
+/// alone so the default alphabetical sort keeps applying.
+pub fn reconcile_tool_order(order: &mut Vec<String>, tools: &[ToolInfo]) -> bool {
+    if order.is_empty() {
+        return false;
+    }
+    let mut missing: Vec<&ToolInfo> = tools
+        .iter()
+        .filter(|tool| !order.contains(&tool.id))
+        .collect();
+    if missing.is_empty() {
+        return false;
+    }
+    missing.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let insert_at = order
+        .iter()
+        .rposition(|id| tools.iter().any(|tool| &tool.id == id && tool.enabled))
+        .map_or(0, |idx| idx + 1);
+    order.splice(insert_at..insert_at, missing.into_iter().map(|tool| tool.id.clone()));
+    true
+}
+
+/// Clean a tool order coming from the UI: unknown and repeated ids are
+/// dropped, and known tools the order leaves out are appended in name
+/// order, so the result lists every tool exactly once.
+pub fn sanitize_tool_order(order: Vec<String>, tools: &[ToolInfo]) -> Vec<String> {
+    let mut clean: Vec<String> = vec![];
+    for id in order {
+        if tools.iter().any(|tool| tool.id == id) && !clean.contains(&id) {
+            clean.push(id);
+        }
+    }
+    let mut missing: Vec<&ToolInfo> = tools
+        .iter()
+        .filter(|tool| !clean.contains(&tool.id))
+        .collect();
+    missing.sort_by(|a, b| a.name.cmp(&b.name));
+    clean.extend(missing.into_iter().map(|tool| tool.id.clone()));
+    clean
+}
+
+/// Names of the tools, other than `except_id`, with a skills path equal to
+/// `root`, inside it, or containing it.
+pub fn tools_overlapping_root(
+    state: &AppState,
+    root: &Path,
+    except_id: &str,
+) -> Result<Vec<String>, AppError> {
+    let others: Vec<CustomToolInput> = built_in_tools()
+        .into_iter()
+        .chain(state.custom_tools.iter().cloned())
+        .chain(state.project_roots.iter().map(project_tool_input))
+        .filter(|tool| tool.id != except_id)
+        .collect();
+    let mut roots = vec![(except_id.to_string(), root.to_path_buf())];
+    for other in &others {
+        for path in resolve_skills_paths(&other.skills_path, &state.project_roots)? {
+            roots.push((other.id.clone(), path.canonicalize().unwrap_or(path)));
+        }
+    }
+
+    let mut names: Vec<String> = vec![];
+    for overlap in find_root_overlaps(&roots) {
+        if !overlap.tool_ids.iter().any(|id| id == except_id) {
+            continue;
+        }
+        for other in &others {
+            if overlap.tool_ids.contains(&other.id) && !names.contains(&other.name) {
+                names.push(other.name.clone());
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Group tools that share a skills root or have one root nested inside
+/// another. `roots` pairs each tool id with its resolved (ideally
+/// canonical) skills path.
+pub fn find_root_overlaps(roots: &[(String, PathBuf)]) -> Vec<RootOverlap> {
+    let mut by_root: Vec<(&PathBuf, Vec<String>)> = vec![];
+    for (id, root) in roots {
+        match by_root.iter_mut().find(|(existing, _)| *existing == root) {
+            Some((_, ids)) => ids.push(id.clone()),
+            None => by_root.push((root, vec![id.clone()])),
+        }
+    }
+    by_root.sort_by(|a, b| a.0.cmp(b.0));
+
+    let display = |path: &Path| path.to_string_lossy().to_string();
+    let mut overlaps = vec![];
+    for (root, ids) in &by_root {
+        if ids.len() > 1 {
+            overlaps.push(RootOverlap {
+                kind: "same".to_string(),
+                root: display(root),
+                nested_root: None,
+                tool_ids: ids.clone(),
+            });
+        }
+    }
+    for (outer, outer_ids) in &by_root {
+        for (inner, inner_ids) in &by_root {
+            if inner != outer && inner.starts_with(outer) {
+                overlaps.push(RootOverlap {
+                    kind: "nested".to_string(),
+                    root: display(outer),
+                    nested_root: Some(display(inner)),
+                    tool_ids: outer_ids.iter().chain(inner_ids).cloned().collect(),
+                });
+            }
+        }
+    }
+    overlaps
+}
+
+/// Context budget used when the user hasn't set one for a tool.
+pub const DEFAULT_CONTEXT_BUDGET_BYTES: u64 = 100_000;
+
+/// Built-in context budget for a tool, in bytes of skill body. Windsurf
+/// caps its combined rules at 12,000 characters; other tools load skills
+/// on demand and get the generous default.
+pub fn default_context_budget(tool_id: &str) -> u64 {
+    match tool_id {
+        "windsurf" => 12_000,
+        _ => DEFAULT_CONTEXT_BUDGET_BYTES,
+    }
+}
+
 pub fn find_tool_by_id(app: &tauri::AppHandle, tool_id: &str) -> Result<ToolInfo, AppError> {
     let tools = resolve_tools(app)?;
     tools
@@ -215,3 +476,178 @@ pub fn find_tool_by_id(app: &tauri::AppHandle, tool_id: &str) -> Result<ToolInfo
         .find(|t| t.id == tool_id)
         .ok_or_else(|| AppError::NotFound(format!("Tool not found: {tool_id}")))
 }
+
+/// Whether `dir` is one of the tool's skills roots (rather than a skill).
+pub fn is_tool_root(tool: &ToolInfo, dir: &Path) -> bool {
+    let Ok(dir) = dir.canonicalize() else {
+        return false;
+    };
+    tool.skills_paths
+        .iter()
+        .any(|root| Path::new(root).canonicalize().is_ok_and(|root| root == dir))
+}
+
+/// Find the tool whose skills root contains `path`. When roots are nested
+/// the deepest (most specific) root wins.
+pub fn find_tool_for_path(app: &tauri::AppHandle, path: &Path) -> Result<ToolInfo, AppError> {
+    let target = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let tools = resolve_tools(app)?;
+    tools
+        .into_iter()
+        .filter_map(|tool| {
+            let depth = tool
+                .skills_paths
+                .iter()
+                .map(|root| {
+                    let root = PathBuf::from(root);
+                    root.canonicalize().unwrap_or(root)
+                })
+                .filter(|root| target.starts_with(root))
+                .map(|root| root.components().count())
+                .max()?;
+            Some((depth, tool))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, tool)| tool)
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Could not determine tool for skill path: {}",
+                path.display()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(id: &str, enabled: bool) -> ToolInfo {
+        ToolInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            kind: "builtin".to_string(),
+            config_path: String::new(),
+            skills_path: String::new(),
+            skills_paths: vec![],
+            detected: enabled,
+            detection_method: "none".to_string(),
+            enabled,
+            cli: false,
+        }
+    }
+
+    fn ids(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn resolve_skills_paths_puts_project_local_roots_in_every_project() {
+        let projects: Vec<ProjectRoot> = ["/work/app", "/work/site"]
+            .iter()
+            .map(|path| ProjectRoot {
+                id: path.to_string(),
+                name: path.to_string(),
+                path: path.to_string(),
+                skills_paths: vec![],
+            })
+            .collect();
+        let paths = ids(&["./.agents/skills", "/opt/skills"]);
+
+        let roots = resolve_skills_paths(&paths, &projects).unwrap();
+        assert_eq!(
+            roots,
+            [
+                PathBuf::from("/opt/skills"),
+                PathBuf::from("/work/app/.agents/skills"),
+                PathBuf::from("/work/site/.agents/skills"),
+            ]
+        );
+        assert_eq!(resolve_skills_paths(&paths, &[]).unwrap(), [PathBuf::from("/opt/skills")]);
+    }
+
+    #[test]
+    fn find_root_overlaps_reports_shared_and_nested_roots() {
+        let roots = vec![
+            ("cursor".to_string(), PathBuf::from("/home/u/.cursor/skills")),
+            ("mine".to_string(), PathBuf::from("/home/u/.cursor/skills")),
+            ("gemini".to_string(), PathBuf::from("/home/u/.gemini/skills")),
+            ("anti".to_string(), PathBuf::from("/home/u/.gemini/skills/antigravity")),
+            ("codex".to_string(), PathBuf::from("/home/u/.codex/skills")),
+        ];
+        let overlaps = find_root_overlaps(&roots);
+        assert_eq!(
+            overlaps,
+            vec![
+                RootOverlap {
+                    kind: "same".into(),
+                    root: "/home/u/.cursor/skills".into(),
+                    nested_root: None,
+                    tool_ids: vec!["cursor".into(), "mine".into()],
+                },
+                RootOverlap {
+                    kind: "nested".into(),
+                    root: "/home/u/.gemini/skills".into(),
+                    nested_root: Some("/home/u/.gemini/skills/antigravity".into()),
+                    tool_ids: vec!["gemini".into(), "anti".into()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reconcile_tool_order_inserts_after_last_enabled_tool() {
+        let tools = vec![
+            tool("a", true),
+            tool("b", true),
+            tool("c", false),
+            tool("y", false),
+            tool("x", false),
+        ];
+        let mut order = ids(&["b", "a", "c"]);
+        assert!(reconcile_tool_order(&mut order, &tools));
+        assert_eq!(order, ids(&["b", "a", "x", "y", "c"]));
+        assert!(!reconcile_tool_order(&mut order, &tools));
+    }
+
+    #[test]
+    fn reconcile_tool_order_without_enabled_tools_inserts_first() {
+        let tools = vec![tool("a", false), tool("new", false)];
+        let mut order = ids(&["a"]);
+        assert!(reconcile_tool_order(&mut order, &tools));
+        assert_eq!(order, ids(&["new", "a"]));
+    }
+
+    #[test]
+    fn reconcile_tool_order_leaves_empty_order_alone() {
+        let mut order = vec![];
+        assert!(!reconcile_tool_order(&mut order, &[tool("a", true)]));
+        assert!(order.is_empty());
+    }
+
+    #[test]
+    fn bare_config_dir_does_not_count_when_markers_are_known() {
+        let tmp = std::env::temp_dir().join(format!("skillsyoga-detect-{}", std::process::id()));
+        let config = tmp.join("goose");
+        let skills = config.join("skills");
+        std::fs::create_dir_all(&config).unwrap();
+        let roots = [tmp.join("elsewhere"), skills.clone()];
+        let markers = ["config.yaml"];
+        let missing = ["skillsyoga-no-such-binary"];
+
+        assert_eq!(detect_tool(&config, &roots, &markers, &missing, true), "none");
+        assert_eq!(detect_tool(&config, &roots, &[], &[], true), "configDir");
+        std::fs::write(config.join("config.yaml"), "").unwrap();
+        assert_eq!(detect_tool(&config, &roots, &markers, &missing, true), "marker");
+        std::fs::remove_file(config.join("config.yaml")).unwrap();
+        std::fs::create_dir_all(&skills).unwrap();
+        assert_eq!(detect_tool(&config, &roots, &markers, &missing, true), "skillsDir");
+        std::fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn sanitize_tool_order_drops_unknown_ids_and_appends_missing_ones() {
+        let tools = vec![tool("c", true), tool("a", true), tool("b", false)];
+        let order = ids(&["b", "gone", "c", "b"]);
+        assert_eq!(sanitize_tool_order(order, &tools), ids(&["b", "c", "a"]));
+    }
+}
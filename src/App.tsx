@@ -14,7 +14,18 @@ import {
 } from "lucide-react";
 import { toast, Toaster } from "sonner";
 import { useDashboard } from "@/hooks/use-dashboard";
-import { copySkillToTool, deleteCustomTool, reorderTools, setToolEnabled, upsertCustomTool } from "@/lib/api";
+import {
+  addProjectRoot,
+  copySkillToTool,
+  createToolFromDiscoveredRoot,
+  deleteCustomTool,
+  installFromRegistry,
+  removeProjectRoot,
+  reorderTools,
+  setToolEnabled,
+  takePendingInstallLinks,
+  upsertCustomTool,
+} from "@/lib/api";
 import type { SkillInfo, ToolInfo } from "@/types/models";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
@@ -157,6 +168,56 @@ function App() {
     setSearch("");
   }, [view]);
 
+  useEffect(() => {
+    // Deep links are queued on the Rust side; each one is confirmed via a
+    // toast before anything is installed.
+    const drainInstallLinks = async () => {
+      const requests = await takePendingInstallLinks();
+      for (const request of requests) {
+        toast(`Install "${request.skillId}" from ${request.source}?`, {
+          id: `deep-link-${request.source}-${request.skillId}`,
+          duration: Infinity,
+          action: {
+            label: "Install",
+            onClick: () => {
+              void (async () => {
+                try {
+                  const skill = await installFromRegistry(request);
+                  toast.success(`Installed ${skill.name}`);
+                  await refresh();
+                } catch (error) {
+                  toast.error(`Install failed: ${String(error)}`);
+                }
+              })();
+            },
+          },
+          cancel: { label: "Cancel", onClick: () => {} },
+        });
+      }
+    };
+
+    let disposed = false;
+    const unlisteners: Array<() => void> = [];
+    void (async () => {
+      const { listen } = await import("@tauri-apps/api/event");
+      const offInstall = await listen("deep-link-install", () => void drainInstallLinks());
+      const offError = await listen<string>("deep-link-error", (event) => {
+        toast.error(`Ignored install link: ${event.payload}`);
+      });
+      if (disposed) {
+        offInstall();
+        offError();
+        return;
+      }
+      unlisteners.push(offInstall, offError);
+      await drainInstallLinks();
+    })();
+    return () => {
+      disposed = true;
+      unlisteners.forEach((off) => off());
+    };
+  }, [refresh]);
+
   useEffect(() => {
     const onKeyDown = (event: KeyboardEvent) => {
       if ((event.metaKey || event.ctrlKey) && event.shiftKey && event.key.toLowerCase() === "r") {
@@ -180,7 +241,8 @@ function App() {
       return (
         skill.name.toLowerCase().includes(query) ||
         skill.description.toLowerCase().includes(query) ||
-        skill.enabledFor.join(" ").toLowerCase().includes(query)
+        skill.enabledFor.join(" ").toLowerCase().includes(query) ||
+        skill.tags.some((tag) => tag.toLowerCase().includes(query))
       );
     });
   }, [data, search]);
@@ -427,6 +489,7 @@ function App() {
                 installedCount={data?.stats.installedSkills ?? 0}
                 loading={loading}
                 skills={filteredSkills}
+                maxSkillMdBytes={data?.maxSkillMdBytes}
                 onEdit={requestEditSkill}
                 onSync={openSyncDialog}
               />
@@ -441,6 +504,20 @@ function App() {
                   toast.success("Custom tool removed");
                   await refresh();
                 }}
+                onAddProject={async (path) => {
+                  try {
+                    await addProjectRoot(path);
+                    toast.success("Project added");
+                    await refresh();
+                  } catch (error) {
+                    toast.error(`Failed to add project: ${String(error)}`);
+                  }
+                }}
+                onRemoveProject={async (projectId) => {
+                  await removeProjectRoot(projectId);
+                  toast.success("Project removed");
+                  await refresh();
+                }}
                 onReorder={async (toolOrder) => {
                   try {
                     await reorderTools(toolOrder);
@@ -457,6 +534,7 @@ function App() {
               <MarketplaceView
                 sources={data.sources}
                 tools={data.tools}
+                defaultInstallToolId={data.defaultInstallToolId}
                 onInstalled={async () => {
                   await refresh();
                 }}
@@ -498,6 +576,11 @@ function App() {
           toast.success("Custom tool saved");
           await refresh();
         }}
+        onCreateFromRoot={async (path, name) => {
+          await createToolFromDiscoveredRoot(path, name);
+          toast.success("Custom tool added");
+          await refresh();
+        }}
       />
 
       <Dialog
@@ -1,7 +1,7 @@
 import { useState } from "react";
 import { toast } from "sonner";
-import { discoverSkillsPaths } from "@/lib/api";
-import type { CustomToolInput, DiscoveredSkillsRoot } from "@/types/models";
+import { discoverSkillsPaths, probeCustomTool } from "@/lib/api";
+import type { CustomToolInput, CustomToolProbe, DiscoveredSkillsRoot } from "@/types/models";
 import { Button } from "@/components/ui/button";
 import { Dialog, DialogContent, DialogDescription, DialogHeader, DialogTitle } from "@/components/ui/dialog";
 import { Input } from "@/components/ui/input";
@@ -11,10 +11,12 @@ export function CustomToolDialog({
   open,
   onOpenChange,
   onSaved,
+  onCreateFromRoot,
 }: {
   open: boolean;
   onOpenChange: (open: boolean) => void;
   onSaved: (tool: CustomToolInput) => Promise<void>;
+  onCreateFromRoot: (path: string, name: string) => Promise<void>;
 }) {
   const [id, setId] = useState("");
   const [name, setName] = useState("");
@@ -23,6 +25,15 @@ export function CustomToolDialog({
   const [scanRoot, setScanRoot] = useState("");
   const [scanLoading, setScanLoading] = useState(false);
   const [scanResults, setScanResults] = useState<DiscoveredSkillsRoot[]>([]);
+  const [probe, setProbe] = useState<CustomToolProbe | null>(null);
+
+  const checkPaths = async () => {
+    try {
+      setProbe(await probeCustomTool(configPath, skillsPath, id.trim()));
+    } catch (error) {
+      toast.error(`Failed to check paths: ${String(error)}`);
+    }
+  };
 
   const inferConfigPath = (candidate: string) => {
     const normalized = candidate.replace(/[\\/]+$/, "");
@@ -59,6 +70,18 @@ export function CustomToolDialog({
     }
   };
 
+  const createFromRoot = async (path: string) => {
+    try {
+      await onCreateFromRoot(path, name.trim());
+      onOpenChange(false);
+      setName("");
+      setScanRoot("");
+      setScanResults([]);
+    } catch (error) {
+      toast.error(`Failed to add tool: ${String(error)}`);
+    }
+  };
+
   const submit = async () => {
     if (!id || !name || !skillsPath || !configPath) {
       toast.error("All fields are required");
@@ -95,11 +118,43 @@ export function CustomToolDialog({
           </div>
           <div className="space-y-1">
             <Label>Config Path</Label>
-            <Input value={configPath} onChange={(event) => setConfigPath(event.currentTarget.value)} />
+            <Input
+              value={configPath}
+              onChange={(event) => {
+                setConfigPath(event.currentTarget.value);
+                setProbe(null);
+              }}
+            />
           </div>
           <div className="space-y-1">
             <Label>Skills Path</Label>
-            <Input value={skillsPath} onChange={(event) => setSkillsPath(event.currentTarget.value)} />
+            <div className="flex gap-2">
+              <Input
+                value={skillsPath}
+                onChange={(event) => {
+                  setSkillsPath(event.currentTarget.value);
+                  setProbe(null);
+                }}
+              />
+              <Button variant="outline" onClick={() => void checkPaths()} disabled={!skillsPath.trim()}>
+                Check Paths
+              </Button>
+            </div>
+            {probe && (
+              <div className="space-y-0.5 text-xs text-muted-foreground">
+                <p>Config path {probe.configExists ? "exists" : "does not exist yet"}.</p>
+                <p>
+                  {!probe.skillsExists
+                    ? "Skills path does not exist yet."
+                    : !probe.skillsIsDir
+                      ? "Skills path is not a folder."
+                      : `Skills path holds ${probe.skillCount} skill${probe.skillCount === 1 ? "" : "s"}.`}
+                </p>
+                {probe.overlappingTools.length > 0 && (
+                  <p className="text-amber-500">Overlaps the skills path of {probe.overlappingTools.join(", ")}.</p>
+                )}
+              </div>
+            )}
           </div>
           <div className="rounded-md border border-border p-3">
             <div className="space-y-2">
@@ -117,18 +172,29 @@ export function CustomToolDialog({
               {scanResults.length > 0 && (
                 <div className="max-h-44 space-y-1 overflow-y-auto rounded-md border border-border p-2">
                   {scanResults.map((result) => (
-                    <button
-                      key={`${result.path}-${result.skillCount}`}
-                      type="button"
-                      className="flex w-full items-center justify-between rounded px-2 py-1.5 text-left text-xs hover:bg-muted/40"
-                      onClick={() => {
-                        setSkillsPath(result.path);
-                        setConfigPath(inferConfigPath(result.path));
-                      }}
-                    >
-                      <span className="truncate pr-2">{result.path}</span>
-                      <span className="shrink-0 text-muted-foreground">{result.skillCount} skill{result.skillCount > 1 ? "s" : ""}</span>
-                    </button>
+                    <div key={`${result.path}-${result.skillCount}`} className="flex items-center gap-1">
+                      <button
+                        type="button"
+                        className="flex min-w-0 flex-1 items-center justify-between rounded px-2 py-1.5 text-left text-xs hover:bg-muted/40"
+                        onClick={() => {
+                          setSkillsPath(result.path);
+                          setConfigPath(inferConfigPath(result.path));
+                          setProbe(null);
+                        }}
+                      >
+                        <span className="truncate pr-2">{result.path}</span>
+                        <span className="shrink-0 text-muted-foreground">{result.skillCount} skill{result.skillCount > 1 ? "s" : ""}</span>
+                      </button>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        className="h-7 shrink-0 text-xs"
+                        title="Create a tool for this folder, named after the Name field or the folder"
+                        onClick={() => void createFromRoot(result.path)}
+                      >
+                        Add as Tool
+                      </Button>
+                    </div>
                   ))}
                 </div>
               )}
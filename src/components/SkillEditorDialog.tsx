@@ -31,12 +31,13 @@ import {
   listSkillFiles,
   renameSkillEntry,
   readSkillEntry,
+  readSkillEntryMeta,
   revealInFinder,
   saveSkillEntry,
   saveSkillFile,
   updateSkillFromGithub,
 } from "@/lib/api";
-import type { SkillFileEntry, SkillInfo, ToolInfo } from "@/types/models";
+import type { SkillEntryContent, SkillFileEntry, SkillInfo, ToolInfo } from "@/types/models";
 import { Button } from "@/components/ui/button";
 import {
   Dialog,
@@ -138,6 +139,20 @@ function createDefaultEntries(): SkillFileEntry[] {
   return [{ relativePath: "SKILL.md", isDir: false }];
 }
 
+const IMAGE_MIME_TYPES: Record<string, string> = {
+  png: "image/png",
+  jpg: "image/jpeg",
+  jpeg: "image/jpeg",
+  gif: "image/gif",
+  webp: "image/webp",
+  svg: "image/svg+xml",
+};
+
+function imageMimeForPath(path: string): string | undefined {
+  const extension = path.toLowerCase().split(".").pop() ?? "";
+  return IMAGE_MIME_TYPES[extension];
+}
+
 function languageForPath(path: string): string {
   const lower = path.toLowerCase();
   if (lower.endsWith(".md") || lower.endsWith(".markdown")) return "markdown";
@@ -180,6 +195,7 @@ export function SkillEditorDialog({
     "SKILL.md": DEFAULT_CONTENT,
   });
   const [dirtyFiles, setDirtyFiles] = useState<Set<string>>(new Set());
+  const [binaryFiles, setBinaryFiles] = useState<Record<string, SkillEntryContent>>({});
   const [collapsedDirs, setCollapsedDirs] = useState<Set<string>>(new Set());
   const [pendingEntry, setPendingEntry] = useState<SkillFileEntry | null>(null);
   const [unsavedSwitchOpen, setUnsavedSwitchOpen] = useState(false);
@@ -214,7 +230,7 @@ export function SkillEditorDialog({
       selectedFile,
       ...data,
     });
-    void debugLog(`[SkillEditorDialog] ${payload}`).catch(() => {});
+    void debugLog("debug", `[SkillEditorDialog] ${payload}`).catch(() => {});
   };
 
   const loadSkillForEdit = async (targetSkill: SkillInfo, preferredPath?: string) => {
@@ -235,6 +251,7 @@ export function SkillEditorDialog({
     setContentByFile({ [preferred]: text });
     setSavedByFile({ [preferred]: text });
     setDirtyFiles(new Set());
+    setBinaryFiles({});
     emitDiag("open_edit_skill_loaded", {
       skillPath: targetSkill.path,
       preferredFile: preferred,
@@ -282,6 +299,7 @@ export function SkillEditorDialog({
       setContentByFile({ "SKILL.md": DEFAULT_CONTENT });
       setSavedByFile({ "SKILL.md": DEFAULT_CONTENT });
       setDirtyFiles(new Set());
+      setBinaryFiles({});
     }
     setUpdateDialogOpen(false);
     setUpdateConfirmOpen(false);
@@ -332,7 +350,15 @@ export function SkillEditorDialog({
     }
     try {
       setLoadingFile(true);
-      const text = await readSkillEntry(skill.path, relativePath);
+      const entry = await readSkillEntryMeta(skill.path, relativePath);
+      if (entry.isBinary || entry.encoding === "none") {
+        setBinaryFiles((prev) => ({ ...prev, [relativePath]: entry }));
+        setContentByFile((prev) => ({ ...prev, [relativePath]: "" }));
+        setSavedByFile((prev) => ({ ...prev, [relativePath]: "" }));
+        emitDiag("load_file_binary", { relativePath, size: entry.size });
+        return;
+      }
+      const text = entry.content;
       setContentByFile((prev) => ({ ...prev, [relativePath]: text }));
       setSavedByFile((prev) => ({ ...prev, [relativePath]: text }));
       setDirtyFiles((prev) => {
@@ -613,6 +639,11 @@ export function SkillEditorDialog({
       for (const [k, v] of Object.entries(prev)) next[remap(k)] = v;
       return next;
     });
+    setBinaryFiles((prev) => {
+      const next: Record<string, SkillEntryContent> = {};
+      for (const [k, v] of Object.entries(prev)) next[remap(k)] = v;
+      return next;
+    });
     setDirtyFiles((prev) => {
       const next = new Set<string>();
       for (const k of prev) next.add(remap(k));
@@ -925,6 +956,7 @@ export function SkillEditorDialog({
   };
 
   const selectedContent = contentByFile[selectedFile] ?? "";
+  const selectedBinary = binaryFiles[selectedFile];
   const modeLabel = editorUiMode === "view" ? "View" : "Edit";
   const headerTitle = mode === "edit" ? `${modeLabel} Skill` : "Create Skill";
   const fileStatusLabel = mode === "edit" ? `${editorUiMode === "view" ? "Viewing" : "Editing"} ${selectedFile}` : "";
@@ -1142,6 +1174,21 @@ export function SkillEditorDialog({
               <div className="min-h-0 min-w-0 flex-1 overflow-hidden rounded-md border border-border">
                 {loadingFile ? (
                   <div className="flex h-full items-center justify-center text-sm text-muted-foreground">Loading file...</div>
+                ) : selectedBinary ? (
+                  <div className="flex h-full items-center justify-center overflow-auto p-4">
+                    {selectedBinary.encoding === "base64" && imageMimeForPath(selectedFile) ? (
+                      <img
+                        alt={selectedFile}
+                        className="max-h-full max-w-full object-contain"
+                        src={`data:${imageMimeForPath(selectedFile)};base64,${selectedBinary.content}`}
+                      />
+                    ) : (
+                      <p className="text-sm text-muted-foreground">
+                        {selectedBinary.isBinary ? "Binary file" : "Too large to edit"}, not shown
+                        ({selectedBinary.size.toLocaleString()} bytes)
+                      </p>
+                    )}
+                  </div>
                 ) : isReadOnly ? (
                   <pre className="skillsyoga-view h-full overflow-auto p-4 text-sm leading-6 whitespace-pre-wrap">{selectedContent}</pre>
                 ) : (
@@ -1,17 +1,52 @@
 import { invoke } from "@tauri-apps/api/core";
 import type {
+  BudgetReport,
+  CopySkillResult,
   CopySkillToToolRequest,
   CreateGitHubGistRequest,
   CustomToolInput,
+  CustomToolProbe,
   DashboardData,
+  DashboardDelta,
+  DeleteReport,
   DiscoveredSkillsRoot,
+  EditorSettings,
+  ExternalRef,
+  GithubTokenInfo,
   InstallFromRegistryRequest,
+  InstallPreview,
+  InstallReport,
   InstallSkillRequest,
+  LocalSearchHit,
+  LogLevel,
+  MergeGroup,
+  MiscaseHit,
+  MoveReport,
+  PermissionReport,
+  ProvenanceInfo,
+  PublishBundle,
+  RootOverlap,
+  SaveSkillEntryBase64Request,
   SaveSkillEntryRequest,
   SaveSkillRequest,
+  SkillEntryChunk,
+  SkillEntryContent,
   SkillFileEntry,
-  SearchSkillResult,
+  SkillGist,
+  SearchSkillsPage,
   SkillInfo,
+  SkillIssue,
+  SkillSnapshot,
+  SkillTemplate,
+  SkillTemplateId,
+  SkillTreeNode,
+  SimilarPair,
+  SkillMergeMode,
+  SourceGuess,
+  StateDiff,
+  SyncReport,
+  ToolPathDetail,
+  ToolRescan,
   UpdateSkillFromGithubRequest,
 } from "@/types/models";
 
@@ -19,30 +54,154 @@ export async function getDashboardData() {
   return invoke<DashboardData>("get_dashboard_data");
 }
 
+export async function rescanTool(toolId: string) {
+  return invoke<ToolRescan>("rescan_tool", { toolId });
+}
+
+export async function explainMerge() {
+  return invoke<MergeGroup[]>("explain_merge");
+}
+
+export async function contextBudgetReport(toolId: string) {
+  return invoke<BudgetReport>("context_budget_report", { toolId });
+}
+
+export async function dashboardDelta(sinceToken: string) {
+  return invoke<DashboardDelta>("dashboard_delta", { sinceToken });
+}
+
+export async function recentSkills(limit: number) {
+  return invoke<SkillInfo[]>("recent_skills", { limit });
+}
+
 export async function setToolEnabled(toolId: string, enabled: boolean) {
   return invoke<void>("set_tool_enabled", { toolId, enabled });
 }
 
+export async function setToolCli(toolId: string, cli: boolean) {
+  return invoke<void>("set_tool_cli", { toolId, cli });
+}
+
 export async function readSkillFile(path: string) {
   return invoke<string>("read_skill_file", { path });
 }
 
+export async function readSkillById(skillId: string) {
+  return invoke<string>("read_skill_by_id", { skillId });
+}
+
+export async function isSkillWritable(path: string) {
+  return invoke<boolean>("is_skill_writable", { path });
+}
+
+export async function normalizeSkillPermissions(path: string) {
+  return invoke<PermissionReport>("normalize_skill_permissions", { path });
+}
+
+export async function findMiscasedManifests(toolId: string) {
+  return invoke<MiscaseHit[]>("find_miscased_manifests", { toolId });
+}
+
+export async function fixManifestCase(path: string) {
+  return invoke<string>("fix_manifest_case", { path });
+}
+
+export async function validateSkill(path: string) {
+  return invoke<SkillIssue[]>("validate_skill", { path });
+}
+
+export async function listSkillExternalRefs(path: string) {
+  return invoke<ExternalRef[]>("list_skill_external_refs", { path });
+}
+
 export async function listSkillFiles(path: string) {
   return invoke<SkillFileEntry[]>("list_skill_files", { path });
 }
 
+export async function getSkillTree(path: string) {
+  return invoke<SkillTreeNode[]>("get_skill_tree", { path });
+}
+
 export async function readSkillEntry(path: string, relativePath: string) {
   return invoke<string>("read_skill_entry", { path, relativePath });
 }
 
+export async function readSkillEntryMeta(path: string, relativePath: string) {
+  return invoke<SkillEntryContent>("read_skill_entry_meta", { path, relativePath });
+}
+
+export async function readSkillEntryRange(
+  path: string,
+  relativePath: string,
+  offset: number,
+  length: number,
+) {
+  return invoke<SkillEntryChunk>("read_skill_entry_range", { path, relativePath, offset, length });
+}
+
+export async function previewSlug(name: string) {
+  return invoke<string>("preview_slug", { name });
+}
+
 export async function saveSkillFile(request: SaveSkillRequest) {
   return invoke<SkillInfo>("save_skill_file", { request });
 }
 
+export async function snapshotSkill(path: string) {
+  return invoke<string>("snapshot_skill", { path });
+}
+
+export async function listSkillSnapshots(path: string) {
+  return invoke<SkillSnapshot[]>("list_skill_snapshots", { path });
+}
+
+export async function restoreSkillSnapshot(path: string, snapshotId: string) {
+  return invoke<void>("restore_skill_snapshot", { path, snapshotId });
+}
+
 export async function saveSkillEntry(request: SaveSkillEntryRequest) {
   return invoke<void>("save_skill_entry", { request });
 }
 
+export async function saveSkillEntryBase64(request: SaveSkillEntryBase64Request) {
+  return invoke<void>("save_skill_entry_base64", { request });
+}
+
+export async function setMaxWriteBytes(bytes: number) {
+  return invoke<void>("set_max_write_bytes", { bytes });
+}
+
+export async function setMaxSkillMdBytes(bytes: number) {
+  return invoke<void>("set_max_skill_md_bytes", { bytes });
+}
+
+export async function setRegistryUrl(registryUrl: string | null) {
+  return invoke<void>("set_registry_url", { registryUrl });
+}
+
+export async function createSkillFromTemplate(
+  targetToolId: string,
+  name: string,
+  description: string,
+  /** A built-in id or the id of one of the user's `SkillTemplate`s. */
+  templateId?: SkillTemplateId | string,
+) {
+  return invoke<SkillInfo>("create_skill_from_template", {
+    targetToolId,
+    name,
+    description,
+    templateId,
+  });
+}
+
+export async function upsertSkillTemplate(template: SkillTemplate) {
+  return invoke<DashboardData>("upsert_skill_template", { template });
+}
+
+export async function deleteSkillTemplate(templateId: string) {
+  return invoke<DashboardData>("delete_skill_template", { templateId });
+}
+
 export async function createSkillDir(path: string, relativePath: string) {
   return invoke<void>("create_skill_dir", { path, relativePath });
 }
@@ -51,6 +210,14 @@ export async function renameSkillEntry(path: string, oldRelativePath: string, ne
   return invoke<void>("rename_skill_entry", { path, oldRelativePath, newRelativePath });
 }
 
+export async function renameSkill(path: string, newName: string) {
+  return invoke<SkillInfo>("rename_skill", { path, newName });
+}
+
+export async function cleanSkillFolderName(path: string, fallbackName?: string) {
+  return invoke<SkillInfo>("clean_skill_folder_name", { path, fallbackName });
+}
+
 export async function deleteSkillEntry(path: string, relativePath: string) {
   return invoke<void>("delete_skill_entry", { path, relativePath });
 }
@@ -63,10 +230,42 @@ export async function deleteSkill(path: string) {
   return invoke<void>("delete_skill", { path });
 }
 
+export async function deleteSkills(skillPaths: string[], confirm: boolean) {
+  return invoke<DeleteReport>("delete_skills", { skillPaths, confirm });
+}
+
+export async function moveSkill(sourcePath: string, targetToolId: string) {
+  return invoke<SkillInfo>("move_skill", { sourcePath, targetToolId });
+}
+
+export async function duplicateSkill(sourcePath: string, newName?: string) {
+  return invoke<SkillInfo>("duplicate_skill", { sourcePath, newName });
+}
+
+export async function moveSkills(sourcePaths: string[], targetToolId: string) {
+  return invoke<MoveReport>("move_skills", { sourcePaths, targetToolId });
+}
+
+export async function recoverProvenance(path: string) {
+  return invoke<ProvenanceInfo | null>("recover_provenance", { path });
+}
+
 export async function installSkillFromGithub(request: InstallSkillRequest) {
   return invoke<SkillInfo>("install_skill_from_github", { request });
 }
 
+export async function installAllSkillsFromGithub(request: InstallSkillRequest) {
+  return invoke<InstallReport>("install_all_skills_from_github", { request });
+}
+
+export async function previewGithubInstall(
+  repoUrl: string,
+  skillPath?: string,
+  targetToolId?: string,
+) {
+  return invoke<InstallPreview[]>("preview_github_install", { repoUrl, skillPath, targetToolId });
+}
+
 export async function updateSkillFromGithub(request: UpdateSkillFromGithubRequest) {
   return invoke<SkillInfo>("update_skill_from_github", { request });
 }
@@ -79,42 +278,180 @@ export async function discoverSkillsPaths(scanRoot: string) {
   return invoke<DiscoveredSkillsRoot[]>("discover_skills_paths", { scanRoot });
 }
 
+/** `toolId` is the custom tool being edited, left out of `overlappingTools`. */
+export async function probeCustomTool(configPath: string, skillsPath: string, toolId?: string) {
+  return invoke<CustomToolProbe>("probe_custom_tool", { configPath, skillsPath, toolId: toolId || null });
+}
+
+/** An empty `name` is derived from the folder. */
+export async function createToolFromDiscoveredRoot(path: string, name = "") {
+  return invoke<DashboardData>("create_tool_from_discovered_root", { path, name });
+}
+
+export async function scanCommonHomeLocations() {
+  return invoke<DiscoveredSkillsRoot[]>("scan_common_home_locations");
+}
+
 export async function deleteCustomTool(toolId: string) {
   return invoke<DashboardData>("delete_custom_tool", { toolId });
 }
 
-export async function searchSkills(query: string) {
-  return invoke<SearchSkillResult[]>("search_skills", { query });
+export async function addProjectRoot(path: string) {
+  return invoke<DashboardData>("add_project_root", { path });
+}
+
+export async function removeProjectRoot(projectId: string) {
+  return invoke<DashboardData>("remove_project_root", { projectId });
+}
+
+export async function searchSkills(
+  query: string,
+  allowShort = false,
+  page: { limit?: number; offset?: number; tool?: string } = {},
+) {
+  return invoke<SearchSkillsPage>("search_skills", { query, allowShort, ...page });
+}
+
+export async function searchLocalSkills(query: string, includeFiles = false) {
+  return invoke<LocalSearchHit[]>("search_local_skills", { query, includeFiles });
 }
 
 export async function installFromRegistry(request: InstallFromRegistryRequest) {
   return invoke<SkillInfo>("install_from_registry", { request });
 }
 
+export async function installSkillFromUrl(url: string, targetToolId?: string, name?: string) {
+  return invoke<SkillInfo>("install_skill_from_url", { url, targetToolId, name });
+}
+
+export async function importSkillFromZip(zipPath: string, targetToolId: string) {
+  return invoke<SkillInfo>("import_skill_from_zip", { zipPath, targetToolId });
+}
+
+export async function takePendingInstallLinks() {
+  return invoke<InstallFromRegistryRequest[]>("take_pending_install_links");
+}
+
+export async function guessSkillSource(path: string) {
+  return invoke<SourceGuess[]>("guess_skill_source", { path });
+}
+
+export async function addTagToSkills(skillPaths: string[], tag: string) {
+  return invoke<SkillInfo[]>("add_tag_to_skills", { skillPaths, tag });
+}
+
+export async function removeTagFromSkills(skillPaths: string[], tag: string) {
+  return invoke<SkillInfo[]>("remove_tag_from_skills", { skillPaths, tag });
+}
+
+export async function detectOverlappingToolRoots() {
+  return invoke<RootOverlap[]>("detect_overlapping_tool_roots");
+}
+
+export async function toolPathDetails() {
+  return invoke<ToolPathDetail[]>("tool_path_details");
+}
+
+export async function diffStateBackup(srcPath: string) {
+  return invoke<StateDiff>("diff_state_backup", { srcPath });
+}
+
+export async function exportState(dest: string, includeToken = false) {
+  return invoke<string>("export_state", { dest, includeToken });
+}
+
+export async function importState(path: string) {
+  return invoke<DashboardData>("import_state", { path });
+}
+
 export async function reorderTools(toolOrder: string[]) {
-  return invoke<void>("reorder_tools", { toolOrder });
+  return invoke<string[]>("reorder_tools", { toolOrder });
 }
 
 export async function revealInFinder(path: string) {
   return invoke<void>("reveal_in_finder", { path });
 }
 
-export async function setGithubToken(token: string) {
-  return invoke<void>("set_github_token", { token });
+export async function openToolConfig(toolId: string) {
+  return invoke<void>("open_tool_config", { toolId });
+}
+
+export async function revealAppDataDir() {
+  return invoke<void>("reveal_app_data_dir");
+}
+
+export async function setGithubToken(token: string, verify = false) {
+  return invoke<GithubTokenInfo | null>("set_github_token", { token, verify });
 }
 
 export async function createGithubGist(request: CreateGitHubGistRequest) {
   return invoke<string>("create_github_gist", { request });
 }
 
+export async function createSkillGist(path: string) {
+  return invoke<SkillGist>("create_skill_gist", { path });
+}
+
 export async function setSkillEditorDefaultMode(mode: "view" | "edit") {
   return invoke<void>("set_skill_editor_default_mode", { mode });
 }
 
+export async function setDefaultInstallTool(toolId: string | null) {
+  return invoke<void>("set_default_install_tool", { toolId });
+}
+
+export async function setContextBudget(toolId: string, budgetBytes: number | null) {
+  return invoke<void>("set_context_budget", { toolId, budgetBytes });
+}
+
+export async function setEditorSettings(settings: EditorSettings) {
+  return invoke<void>("set_editor_settings", { settings });
+}
+
+export async function setSkillMergeMode(mode: SkillMergeMode) {
+  return invoke<void>("set_skill_merge_mode", { mode });
+}
+
+export async function setSkillEnabled(skillPath: string, enabled: boolean) {
+  return invoke<SkillInfo>("set_skill_enabled", { skillPath, enabled });
+}
+
+export async function setSkillPinned(skillPath: string, pinned: boolean) {
+  return invoke<void>("set_skill_pinned", { skillPath, pinned });
+}
+
+export async function prepareForPublish(path: string, destDir: string) {
+  return invoke<PublishBundle>("prepare_for_publish", { path, destDir });
+}
+
+export async function exportSkill(path: string, dest: string) {
+  return invoke<string>("export_skill", { path, dest });
+}
+
+export async function exportAsPlugin(skillPaths: string[], destDir: string, pluginName: string) {
+  return invoke<string>("export_as_plugin", { skillPaths, destDir, pluginName });
+}
+
+export async function findSimilarSkills(threshold: number) {
+  return invoke<SimilarPair[]>("find_similar_skills", { threshold });
+}
+
+export async function exportInventory(format: "json" | "csv") {
+  return invoke<string>("export_inventory", { format });
+}
+
 export async function copySkillToTool(request: CopySkillToToolRequest) {
-  return invoke<SkillInfo>("copy_skill_to_tool", { request });
+  return invoke<CopySkillResult>("copy_skill_to_tool", { request });
+}
+
+export async function syncSkillToAllTools(sourcePath: string) {
+  return invoke<SyncReport>("sync_skill_to_all_tools", { sourcePath });
+}
+
+export async function debugLog(level: LogLevel, message: string) {
+  return invoke<void>("debug_log", { level, message });
 }
 
-export async function debugLog(message: string) {
-  return invoke<void>("debug_log", { message });
+export async function getLogPath() {
+  return invoke<string>("get_log_path");
 }
@@ -1,13 +1,37 @@
-export type ToolKind = "builtin" | "custom";
+export type ToolKind = "builtin" | "custom" | "project";
+
+export type ToolDetectionMethod = "marker" | "binary" | "skillsDir" | "configDir" | "none";
 
 export interface ToolInfo {
   id: string;
   name: string;
   kind: ToolKind;
   configPath: string;
+  /** The first of `skillsPaths`, where new skills are installed. */
   skillsPath: string;
+  skillsPaths: string[];
   detected: boolean;
+  detectionMethod: ToolDetectionMethod;
   enabled: boolean;
+  cli: boolean;
+}
+
+export type SkillTemplateId = "basic" | "tool-use" | "workflow";
+
+export type LogLevel = "debug" | "info" | "warn" | "error";
+
+/** `hasGistScope` is null for fine-grained tokens, which report no scopes. */
+export interface GithubTokenInfo {
+  login: string;
+  scopes: string[];
+  hasGistScope: boolean | null;
+}
+
+/** A user-defined SKILL.md with `{{name}}`/`{{description}}` placeholders. */
+export interface SkillTemplate {
+  id: string;
+  name: string;
+  body: string;
 }
 
 export interface SkillInfo {
@@ -20,6 +44,14 @@ export interface SkillInfo {
   updatedAt: string;
   githubRepoUrl?: string;
   githubSkillPath?: string;
+  pinned: boolean;
+  tags: string[];
+  license?: string | null;
+  version?: string | null;
+  author?: string | null;
+  enabled: boolean;
+  skillMdBytes: number;
+  conflicts: string[];
 }
 
 export interface SourceInfo {
@@ -34,6 +66,7 @@ export interface DashboardStats {
   installedSkills: number;
   detectedTools: number;
   enabledTools: number;
+  skippedSkills: number;
 }
 
 export interface DashboardData {
@@ -44,18 +77,53 @@ export interface DashboardData {
   appDataDir: string;
   hasGithubToken: boolean;
   skillEditorDefaultMode: "view" | "edit";
+  editorSettings: EditorSettings;
+  skillMergeMode: SkillMergeMode;
+  defaultInstallToolId: string | null;
+  skillTemplates: SkillTemplate[];
+  maxSkillMdBytes: number;
+  warnings: string[];
+  token: string;
+}
+
+export interface DashboardDelta {
+  token: string;
+  reset: boolean;
+  added: SkillInfo[];
+  changed: SkillInfo[];
+  removed: string[];
+  tools: ToolInfo[];
+  removedTools: string[];
+  stats: DashboardStats;
+  warnings: string[];
+}
+
+export interface ToolRescan {
+  toolId: string;
+  skills: SkillInfo[];
+  stats: DashboardStats;
+}
+
+export type SkillMergeMode = "none" | "name" | "slug";
+
+export interface EditorSettings {
+  wordWrap: boolean;
+  tabSize: number;
+  previewByDefault: boolean;
 }
 
 export interface SaveSkillRequest {
   content: string;
   targetToolId: string;
   existingPath?: string;
+  folderName?: string;
 }
 
 export interface InstallSkillRequest {
   repoUrl: string;
   skillPath?: string;
-  targetToolId: string;
+  targetToolId?: string;
+  ref?: string;
 }
 
 export interface UpdateSkillFromGithubRequest {
@@ -68,7 +136,8 @@ export interface CustomToolInput {
   id: string;
   name: string;
   configPath: string;
-  skillsPath: string;
+  skillsPath: string | string[];
+  cli?: boolean;
 }
 
 export interface SearchSkillResult {
@@ -77,12 +146,23 @@ export interface SearchSkillResult {
   name: string;
   installs: number;
   source: string;
+  repoUrl?: string | null;
+  installedIn: string[];
+}
+
+export interface SearchSkillsPage {
+  skills: SearchSkillResult[];
+  total?: number | null;
+  limit: number;
+  offset: number;
 }
 
 export interface InstallFromRegistryRequest {
   source: string;
   skillId: string;
-  targetToolId: string;
+  targetToolId?: string;
+  ref?: string;
+  repoUrl?: string;
 }
 
 export interface SkillFileEntry {
@@ -90,12 +170,33 @@ export interface SkillFileEntry {
   isDir: boolean;
 }
 
+export interface SkillTreeNode {
+  name: string;
+  relativePath: string;
+  isDir: boolean;
+  children: SkillTreeNode[];
+}
+
+export interface InstallPreview {
+  name: string;
+  description: string;
+  skillPath?: string | null;
+  targetPath?: string | null;
+  files: SkillFileEntry[];
+}
+
 export interface SaveSkillEntryRequest {
   path: string;
   relativePath: string;
   content: string;
 }
 
+export interface SaveSkillEntryBase64Request {
+  path: string;
+  relativePath: string;
+  contentBase64: string;
+}
+
 export interface CreateGitHubGistRequest {
   skillName: string;
   skillDescription: string;
@@ -103,13 +204,231 @@ export interface CreateGitHubGistRequest {
   selectedText: string;
 }
 
+export type LinkMode = "copy" | "symlink" | "hardlink";
+
 export interface CopySkillToToolRequest {
   sourcePath: string;
   targetToolId: string;
   conflictStrategy?: "overwrite" | "timestampedCopy";
+  linkMode?: LinkMode;
+}
+
+export interface CopySkillResult {
+  skill: SkillInfo;
+  linkMode: LinkMode;
+  fallbackReason?: string;
+}
+
+export interface SyncSkip {
+  toolId: string;
+  reason: string;
+}
+
+export interface SyncReport {
+  copied: SkillInfo[];
+  skipped: SyncSkip[];
 }
 
 export interface DiscoveredSkillsRoot {
   path: string;
   skillCount: number;
 }
+
+/** Paths are reported after `~` expansion. */
+export interface CustomToolProbe {
+  configPath: string;
+  configExists: boolean;
+  skillsPath: string;
+  skillsExists: boolean;
+  skillsIsDir: boolean;
+  skillCount: number;
+  overlappingTools: string[];
+}
+
+export interface SkillIssue {
+  severity: "error" | "warning";
+  field?: string;
+  message: string;
+}
+
+export interface PublishBundle {
+  path: string;
+  skillPath: string;
+  warnings: SkillIssue[];
+}
+
+export interface SourceGuess {
+  sourceId: string;
+  sourceName: string;
+  repoUrl: string;
+  skillPath: string;
+  confidence: number;
+  reason: string;
+}
+
+export interface ToggleChange {
+  toolId: string;
+  current: boolean | null;
+  backup: boolean | null;
+}
+
+export interface StateDiff {
+  addedTools: string[];
+  removedTools: string[];
+  changedTools: string[];
+  addedProjects: string[];
+  removedProjects: string[];
+  toggleChanges: ToggleChange[];
+  toolOrderChanged: boolean;
+  githubTokenChanged: boolean;
+}
+
+export interface ToolPathDetail {
+  toolId: string;
+  name: string;
+  kind: ToolKind;
+  defaultSkillsPath: string | null;
+  skillsPath: string;
+  exists: boolean;
+}
+
+export interface PathFailure {
+  path: string;
+  error: string;
+}
+
+export interface DeleteReport {
+  deleted: string[];
+  failed: PathFailure[];
+}
+
+export interface MoveReport {
+  moved: SkillInfo[];
+  failed: PathFailure[];
+}
+
+export interface InstallReport {
+  installed: SkillInfo[];
+  failed: PathFailure[];
+}
+
+/** `skipped` lists the relative paths left out of the gist. */
+export interface SkillGist {
+  url: string;
+  skipped: string[];
+}
+
+export interface InventoryRow {
+  name: string;
+  description: string;
+  tags: string[];
+  tools: string[];
+  path: string;
+  wordCount: number;
+  updatedAt: string;
+}
+
+export interface RootOverlap {
+  kind: "same" | "nested";
+  root: string;
+  nestedRoot: string | null;
+  toolIds: string[];
+}
+
+export interface ProvenanceInfo {
+  repoUrl: string;
+  skillPath: string | null;
+  commit: string | null;
+}
+
+export interface PermissionChange {
+  relativePath: string;
+  from: string;
+  to: string;
+}
+
+export interface PermissionReport {
+  supported: boolean;
+  changed: PermissionChange[];
+}
+
+export interface SimilarPair {
+  aPath: string;
+  aName: string;
+  bPath: string;
+  bName: string;
+  score: number;
+}
+
+/** An installed skill with a line matching a local search (`line` is 1-based). */
+export interface LocalSearchHit {
+  skill: SkillInfo;
+  file: string;
+  line: number;
+  snippet: string;
+}
+
+export interface SkillEntryChunk {
+  content: string;
+  encoding: "utf8" | "base64";
+  offset: number;
+  length: number;
+  totalSize: number;
+  eof: boolean;
+}
+
+/** A whole skill file; binary files are base64, and any file too large to return is `none`. */
+export interface SkillEntryContent {
+  content: string;
+  encoding: "utf8" | "base64" | "none";
+  size: number;
+  modifiedAt: string | null;
+  isBinary: boolean;
+}
+
+export interface BudgetReport {
+  toolId: string;
+  budgetBytes: number;
+  totalBytes: number;
+  totalWords: number;
+  overBudget: boolean;
+  skills: BudgetEntry[];
+}
+
+export interface BudgetEntry {
+  path: string;
+  name: string;
+  bodyBytes: number;
+  words: number;
+  overBudget: boolean;
+}
+
+export interface SkillSnapshot {
+  id: string;
+  createdAt: string;
+  sizeBytes: number;
+}
+
+export interface MergeGroup {
+  key: string;
+  name: string;
+  identical: boolean;
+  members: MergeMember[];
+}
+
+export interface MergeMember {
+  path: string;
+  toolId: string;
+}
+
+export interface MiscaseHit {
+  dir: string;
+  fileName: string;
+}
+
+export interface ExternalRef {
+  relativePath: string;
+  line: number;
+  url: string;
+  text: string | null;
+}
@@ -1,7 +1,7 @@
 import { useCallback, useEffect, useRef, useState } from "react";
 import { Cable, Check, CircleAlert, Download, ExternalLink, Loader2, Search, Sparkles } from "lucide-react";
 import { toast } from "sonner";
-import { installFromRegistry, installSkillFromGithub, searchSkills } from "@/lib/api";
+import { installAllSkillsFromGithub, installFromRegistry, searchSkills } from "@/lib/api";
 import type { SearchSkillResult, SourceInfo, ToolInfo } from "@/types/models";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
@@ -28,6 +28,7 @@ type MarketplaceMode = "discover" | "import";
 const QUICK_QUERIES = ["react", "testing", "python", "devops", "prompting"];
 const MIN_INSTALLING_FEEDBACK_MS = 1200;
 const INSTALL_PROGRESS_TICK_MS = 180;
+const SEARCH_PAGE_SIZE = 20;
 
 function wait(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
@@ -36,14 +37,17 @@ function wait(ms: number): Promise<void> {
 export function MarketplaceView({
   sources,
   tools,
+  defaultInstallToolId,
   onInstalled,
 }: {
   sources: SourceInfo[];
   tools: ToolInfo[];
+  defaultInstallToolId: string | null;
   onInstalled: () => Promise<void>;
 }) {
   const [repoUrl, setRepoUrl] = useState("");
   const [skillPath, setSkillPath] = useState("");
+  const [gitRef, setGitRef] = useState("");
   const [targetToolId, setTargetToolId] = useState("");
   const [submitting, setSubmitting] = useState(false);
   const [mode, setMode] = useState<MarketplaceMode>("discover");
@@ -51,6 +55,7 @@ export function MarketplaceView({
   // Search state
   const [searchQuery, setSearchQuery] = useState("");
   const [searchResults, setSearchResults] = useState<SearchSkillResult[]>([]);
+  const [searchTotal, setSearchTotal] = useState<number | null>(null);
   const [searching, setSearching] = useState(false);
   const [hasSearched, setHasSearched] = useState(false);
   const debounceRef = useRef<ReturnType<typeof setTimeout> | null>(null);
@@ -69,12 +74,14 @@ export function MarketplaceView({
   const detectedCount = tools.filter((tool) => tool.detected).length;
   const enabledCount = tools.filter((tool) => tool.enabled).length;
   const installReady = toolOptions.length > 0;
+  const preferredToolId =
+    toolOptions.find((tool) => tool.id === defaultInstallToolId)?.id ?? toolOptions[0]?.id ?? "";
 
   useEffect(() => {
-    if (!targetToolId && toolOptions.length > 0) {
-      setTargetToolId(toolOptions[0].id);
+    if (!targetToolId && preferredToolId) {
+      setTargetToolId(preferredToolId);
     }
-  }, [targetToolId, toolOptions]);
+  }, [targetToolId, preferredToolId]);
 
   useEffect(() => {
     return () => {
@@ -110,16 +117,18 @@ export function MarketplaceView({
     }, INSTALL_PROGRESS_TICK_MS);
   };
 
-  const doSearch = useCallback(async (query: string) => {
+  const doSearch = useCallback(async (query: string, offset = 0) => {
     if (!query.trim()) {
       setSearchResults([]);
+      setSearchTotal(null);
       setHasSearched(false);
       return;
     }
     try {
       setSearching(true);
-      const results = await searchSkills(query.trim());
-      setSearchResults(results);
+      const page = await searchSkills(query.trim(), false, { limit: SEARCH_PAGE_SIZE, offset });
+      setSearchResults((prev) => (offset > 0 ? [...prev, ...page.skills] : page.skills));
+      setSearchTotal(page.total ?? null);
       setHasSearched(true);
     } catch (error) {
       toast.error(`Search failed: ${String(error)}`);
@@ -138,7 +147,7 @@ export function MarketplaceView({
   };
 
   const openSkillDetails = (result: SearchSkillResult) => {
-    const detailsUrl = `https://skills.sh/${result.source}/${result.skillId}`;
+    const detailsUrl = result.repoUrl || `https://skills.sh/${result.source}/${result.skillId}`;
     void openExternal(detailsUrl);
   };
 
@@ -152,7 +161,7 @@ export function MarketplaceView({
 
   const openInstallDialog = (result: SearchSkillResult) => {
     setDialogSkill(result);
-    setDialogToolId(toolOptions.length > 0 ? toolOptions[0].id : "");
+    setDialogToolId(preferredToolId);
     setInstallPhase("select");
     setInstallError("");
     setInstallProgress(0);
@@ -173,6 +182,7 @@ export function MarketplaceView({
         source: dialogSkill.source,
         skillId: dialogSkill.skillId,
         targetToolId: dialogToolId,
+        repoUrl: dialogSkill.repoUrl ?? undefined,
       });
       await onInstalled();
       const elapsed = Date.now() - installStartedAtRef.current;
@@ -203,12 +213,22 @@ export function MarketplaceView({
 
     try {
       setSubmitting(true);
-      await installSkillFromGithub({
+      const { installed, failed } = await installAllSkillsFromGithub({
         repoUrl,
         skillPath: skillPath || undefined,
         targetToolId,
+        ref: gitRef.trim() || undefined,
       });
-      toast.success("Skill imported from GitHub");
+      if (installed.length > 0) {
+        toast.success(
+          installed.length === 1
+            ? "Skill imported from GitHub"
+            : `${installed.length} skills imported from GitHub`,
+        );
+      }
+      for (const failure of failed) {
+        toast.error(`Failed to install ${failure.path}: ${failure.error}`);
+      }
       await onInstalled();
     } catch (error) {
       toast.error(`Install failed: ${String(error)}`);
@@ -348,6 +368,21 @@ export function MarketplaceView({
                   ))}
                 </div>
               )}
+
+              {!searching && searchTotal !== null && searchResults.length < searchTotal && (
+                <div className="flex items-center justify-between gap-2 text-xs text-muted-foreground">
+                  <span>
+                    Showing {searchResults.length} of {searchTotal}
+                  </span>
+                  <Button
+                    size="sm"
+                    variant="outline"
+                    onClick={() => void doSearch(searchQuery, searchResults.length)}
+                  >
+                    Load more
+                  </Button>
+                </div>
+              )}
             </CardContent>
           </Card>
         </div>
@@ -503,6 +538,14 @@ export function MarketplaceView({
                   placeholder="skills/skill-name"
                 />
               </div>
+              <div className="space-y-1">
+                <Label>Branch, Tag or Commit (Optional)</Label>
+                <Input
+                  value={gitRef}
+                  onChange={(event) => setGitRef(event.currentTarget.value)}
+                  placeholder="main"
+                />
+              </div>
               <div className="space-y-1">
                 <Label>Target Tool</Label>
                 <Select value={targetToolId} onValueChange={setTargetToolId}>
@@ -6,7 +6,7 @@ import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/com
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
-import { revealInFinder, setGithubToken, setSkillEditorDefaultMode } from "@/lib/api";
+import { revealAppDataDir, setGithubToken, setSkillEditorDefaultMode } from "@/lib/api";
 import { formatDisplayPath } from "@/lib/utils";
 
 export function SettingsView({
@@ -33,7 +33,7 @@ export function SettingsView({
 
   const revealPath = async () => {
     try {
-      await revealInFinder(appDataDir);
+      await revealAppDataDir();
     } catch (error) {
       toast.error(`Failed to reveal folder: ${String(error)}`);
     }
@@ -56,10 +56,13 @@ export function SettingsView({
     }
     try {
       setSavingToken(true);
-      await setGithubToken(value);
+      const info = await setGithubToken(value, true);
       setGithubTokenValue("");
       await onGithubTokenChanged();
-      toast.success("GitHub token saved");
+      toast.success(info ? `GitHub token saved for ${info.login}` : "GitHub token saved");
+      if (info?.hasGistScope === false) {
+        toast.warning("This token lacks the gist scope, so creating gists will fail");
+      }
     } catch (error) {
       toast.error(`Failed to save token: ${String(error)}`);
     } finally {
@@ -12,6 +12,14 @@ type LayoutMode = "grid" | "list";
 const STORAGE_KEY = "skills-layout";
 const FLEX_SPACERS = Array.from({ length: 6 });
 
+function conflictLabel(count: number) {
+  return count === 1 ? "Another folder uses this name" : `${count} other folders use this name`;
+}
+
+function sizeLabel(bytes: number) {
+  return `SKILL.md is ${Math.round(bytes / 1024)} KB; agents load all of it into context`;
+}
+
 function getStoredLayout(): LayoutMode {
   try {
     const v = localStorage.getItem(STORAGE_KEY);
@@ -24,12 +32,14 @@ export function SkillsView({
   installedCount,
   loading,
   skills,
+  maxSkillMdBytes,
   onEdit,
   onSync,
 }: {
   installedCount: number;
   loading: boolean;
   skills: SkillInfo[];
+  maxSkillMdBytes?: number;
   onEdit: (skill: SkillInfo) => void;
   onSync: (skill: SkillInfo) => void;
 }) {
@@ -52,14 +62,18 @@ export function SkillsView({
       });
       const descriptions = new Set(sorted.map((item) => item.description.trim()));
       const enabledFor = Array.from(new Set(sorted.flatMap((item) => item.enabledFor))).sort((a, b) => a.localeCompare(b));
+      const conflicts = Array.from(new Set(sorted.flatMap((item) => item.conflicts)));
+      const largestBytes = Math.max(...sorted.map((item) => item.skillMdBytes));
       return {
         primary: sorted[0],
         enabledFor,
+        conflicts,
+        oversizeBytes: maxSkillMdBytes && largestBytes > maxSkillMdBytes ? largestBytes : 0,
         hasDescriptionDiff: descriptions.size > 1,
         variantCount: sorted.length,
       };
     });
-  }, [skills]);
+  }, [skills, maxSkillMdBytes]);
 
   const toggleLayout = (mode: LayoutMode) => {
     setLayout(mode);
@@ -144,6 +158,14 @@ export function SkillsView({
                 {group.hasDescriptionDiff && (
                   <p className="mt-1 text-[10px] text-amber-300">Description differs across tools.</p>
                 )}
+                {group.conflicts.length > 0 && (
+                  <p className="mt-1 truncate text-[10px] text-amber-300" title={group.conflicts.join("\n")}>
+                    {conflictLabel(group.conflicts.length)}
+                  </p>
+                )}
+                {group.oversizeBytes > 0 && (
+                  <p className="mt-1 text-[10px] text-amber-300">{sizeLabel(group.oversizeBytes)}</p>
+                )}
               </div>
               <div className="flex items-end justify-between">
                 <div className="flex flex-wrap gap-1">
@@ -190,6 +212,16 @@ export function SkillsView({
               {group.hasDescriptionDiff && (
                 <span className="shrink-0 text-[10px] text-amber-300">(description differs)</span>
               )}
+              {group.conflicts.length > 0 && (
+                <span className="shrink-0 text-[10px] text-amber-300" title={group.conflicts.join("\n")}>
+                  ({conflictLabel(group.conflicts.length)})
+                </span>
+              )}
+              {group.oversizeBytes > 0 && (
+                <span className="shrink-0 text-[10px] text-amber-300" title={sizeLabel(group.oversizeBytes)}>
+                  ({Math.round(group.oversizeBytes / 1024)} KB)
+                </span>
+              )}
               <div className="flex shrink-0 items-center gap-1">
                 {group.enabledFor.map((toolId) => (
                   <Badge key={toolId} variant="secondary" className="text-[10px] leading-none font-medium">
@@ -1,5 +1,5 @@
-import { useMemo } from "react";
-import { GripVertical, Trash2, Wrench } from "lucide-react";
+import { useMemo, useState } from "react";
+import { FolderPlus, GripVertical, Trash2, Wrench } from "lucide-react";
 import {
   DndContext,
   closestCenter,
@@ -15,21 +15,32 @@ import {
   arrayMove,
 } from "@dnd-kit/sortable";
 import { CSS } from "@dnd-kit/utilities";
-import type { ToolInfo } from "@/types/models";
+import type { ToolDetectionMethod, ToolInfo } from "@/types/models";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
 import { Card } from "@/components/ui/card";
+import { Input } from "@/components/ui/input";
 import { Switch } from "@/components/ui/switch";
 import { formatDisplayPath } from "@/lib/utils";
 
+const detectionLabels: Record<ToolDetectionMethod, string> = {
+  marker: "Found the tool's own config files",
+  binary: "Found the CLI on PATH",
+  skillsDir: "Skills folder exists",
+  configDir: "Config folder exists",
+  none: "Not installed",
+};
+
 function SortableToolCard({
   tool,
   onToggle,
   onDeleteCustom,
+  onRemoveProject,
 }: {
   tool: ToolInfo;
   onToggle: (tool: ToolInfo, enabled: boolean) => void;
   onDeleteCustom: (toolId: string) => Promise<void>;
+  onRemoveProject: (projectId: string) => Promise<void>;
 }) {
   const {
     attributes,
@@ -67,13 +78,20 @@ function SortableToolCard({
             <div className="flex flex-wrap items-center gap-1.5">
               <span className="truncate text-sm font-medium">{tool.name}</span>
               {tool.detected ? (
-                <Badge className="bg-emerald-600/80 text-[10px] leading-none text-emerald-50">Detected</Badge>
+                <Badge
+                  className="bg-emerald-600/80 text-[10px] leading-none text-emerald-50"
+                  title={detectionLabels[tool.detectionMethod]}
+                >
+                  Detected
+                </Badge>
               ) : (
                 <Badge variant="secondary" className="text-[10px] leading-none">Not detected</Badge>
               )}
             </div>
             <p className="mt-1.5 truncate text-xs text-muted-foreground">{formatDisplayPath(tool.configPath)}</p>
-            <p className="truncate text-xs text-muted-foreground">{formatDisplayPath(tool.skillsPath)}</p>
+            {tool.skillsPaths.map((path) => (
+              <p key={path} className="truncate text-xs text-muted-foreground">{formatDisplayPath(path)}</p>
+            ))}
           </div>
         </div>
         <div className="flex shrink-0 items-center gap-1">
@@ -87,6 +105,11 @@ function SortableToolCard({
               <Trash2 className="size-3" />
             </Button>
           )}
+          {tool.kind === "project" && (
+            <Button variant="ghost" size="icon" className="size-6" title="Remove project" onClick={() => void onRemoveProject(tool.id)}>
+              <Trash2 className="size-3" />
+            </Button>
+          )}
         </div>
       </div>
     </Card>
@@ -100,15 +123,33 @@ export function ToolsView({
   query,
   onToggle,
   onDeleteCustom,
+  onAddProject,
+  onRemoveProject,
   onReorder,
 }: {
   tools: ToolInfo[];
   query: string;
   onToggle: (tool: ToolInfo, enabled: boolean) => void;
   onDeleteCustom: (toolId: string) => Promise<void>;
+  onAddProject: (path: string) => Promise<void>;
+  onRemoveProject: (projectId: string) => Promise<void>;
   onReorder: (toolOrder: string[]) => void;
 }) {
   const isSearching = query.trim().length > 0;
+  const [projectPath, setProjectPath] = useState("");
+  const [addingProject, setAddingProject] = useState(false);
+
+  const addProject = async () => {
+    const path = projectPath.trim();
+    if (!path) return;
+    try {
+      setAddingProject(true);
+      await onAddProject(path);
+      setProjectPath("");
+    } finally {
+      setAddingProject(false);
+    }
+  };
 
   const filtered = useMemo(() => {
     if (!isSearching) return tools;
@@ -158,6 +199,21 @@ export function ToolsView({
       <div className="flex items-center gap-2 text-sm text-muted-foreground">
         <Wrench className="size-4 text-emerald-300" />
         <span>{enabledCount} enabled · {detectedCount} detected · {tools.length} total</span>
+        <div className="ml-auto flex w-full max-w-md gap-2">
+          <Input
+            className="h-8 text-xs"
+            placeholder="Add a project folder, e.g. ~/code/my-app"
+            value={projectPath}
+            onChange={(event) => setProjectPath(event.currentTarget.value)}
+            onKeyDown={(event) => {
+              if (event.key === "Enter") void addProject();
+            }}
+          />
+          <Button size="sm" variant="outline" className="h-8" disabled={addingProject || !projectPath.trim()} onClick={() => void addProject()}>
+            <FolderPlus className="size-3.5" />
+            Add Project
+          </Button>
+        </div>
       </div>
       <DndContext sensors={sensors} collisionDetection={closestCenter} onDragEnd={handleDragEnd}>
         <SortableContext items={filtered.map((t) => t.id)} strategy={rectSortingStrategy}>
@@ -168,6 +224,7 @@ export function ToolsView({
                 tool={tool}
                 onToggle={onToggle}
                 onDeleteCustom={onDeleteCustom}
+                onRemoveProject={onRemoveProject}
               />
             ))}
             {FLEX_SPACERS.map((_, i) => (